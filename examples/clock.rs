@@ -1,10 +1,9 @@
 use std::{f64::consts::PI, time::Instant};
 
-use veccentric::Fecc;
-
-mod engine;
-
-use engine::{Buffer, Color, HEIGHT, WIDTH};
+use veccentric::{
+    engine::{self, Buffer, Color, HEIGHT, WIDTH},
+    Fecc,
+};
 
 struct State {
     seconds: Fecc,
@@ -13,7 +12,7 @@ struct State {
     start: Instant,
 }
 
-fn main() -> Result<(), pixels::Error> {
+fn main() -> Result<(), engine::Error> {
     // Set up state.
     let original_s = (0.0, -25.0).into();
     let original_m = (0.0, -22.0).into();
@@ -1,11 +1,9 @@
-use veccentric::Fecc;
+use veccentric::{
+    engine::{self, Buffer, Color},
+    force::{self, Falloff},
+    integrate, Fecc,
+};
 
-mod engine;
-
-use engine::{Buffer, Color};
-
-const MAX_FORCE: f64 = 1_000_000.0;
-const MAX_VELOCITY: f64 = 1_000_000.0;
 const SUN_MASS: f64 = 10_000.0;
 const EARTH_MASS: f64 = 0.1;
 const G: f64 = 1.0;
@@ -24,13 +22,6 @@ impl Vehicle {
             mass,
         }
     }
-
-    fn step(&mut self, force: Fecc, dt: f64) {
-        let force = force.limit(MAX_FORCE);
-        let acceleration = force / self.mass;
-        self.velocity = (self.velocity + acceleration * dt).limit(MAX_VELOCITY);
-        self.position = self.position + self.velocity * dt;
-    }
 }
 
 struct State {
@@ -38,7 +29,7 @@ struct State {
     earth: Vehicle,
 }
 
-fn main() -> Result<(), pixels::Error> {
+fn main() -> Result<(), engine::Error> {
     // Set up state.
     let state = State {
         sun: Vehicle::new(32.0, 32.0, SUN_MASS),
@@ -62,11 +53,23 @@ fn main() -> Result<(), pixels::Error> {
                            ref mut earth,
                        }: &mut State,
                        dt: f64| {
-        let radius = sun.position - earth.position;
-        // F = r_hat * G * M * m / |r|^2
-        let force = radius.normalize() * G * SUN_MASS * EARTH_MASS
-            / radius.mag_squared();
-        earth.step(force, dt);
+        // a = r_hat * G * M / |r|^2
+        let gravity = |position| {
+            force::attract(
+                position,
+                sun.position,
+                G * sun.mass,
+                Falloff::InverseSquare { softening: 0.0 },
+            )
+        };
+
+        // Velocity Verlet keeps the orbit closed.
+        integrate::velocity_verlet(
+            &mut earth.position,
+            &mut earth.velocity,
+            gravity,
+            dt,
+        );
     };
 
     // Run the main loop.
@@ -1,17 +1,16 @@
 use std::time::Instant;
 
-use veccentric::Fecc;
-
-mod engine;
-
-use engine::{Buffer, Color, HEIGHT, WIDTH};
+use veccentric::{
+    engine::{self, Buffer, Color, HEIGHT, WIDTH},
+    Fecc,
+};
 
 struct State {
     point: Fecc,
     start: Instant,
 }
 
-fn main() -> Result<(), pixels::Error> {
+fn main() -> Result<(), engine::Error> {
     // Set up state.
     let original = (25.0, 0.0).into();
     let center = Fecc::new(WIDTH as f64 / 2.0, HEIGHT as f64 / 2.0);
@@ -1,11 +1,10 @@
-use veccentric::Fecc;
+use veccentric::{
+    engine::{self, Buffer, Color},
+    Fecc,
+};
 
 use std::time::Instant;
 
-mod engine;
-
-use engine::{Buffer, Color};
-
 const MAX_FORCE: f64 = 100.0;
 const MAX_VELOCITY: f64 = 20.0;
 const MASS: f64 = 1.0;
@@ -27,7 +26,7 @@ impl Vehicle {
         let force = force.limit(MAX_FORCE);
         let acceleration = force / MASS;
         self.velocity = (self.velocity + acceleration * dt).limit(MAX_VELOCITY);
-        self.position = self.position + self.velocity * dt;
+        self.position += self.velocity * dt;
     }
 }
 
@@ -37,7 +36,7 @@ struct State {
     start: Instant,
 }
 
-fn main() -> Result<(), pixels::Error> {
+fn main() -> Result<(), engine::Error> {
     // Set up state.
     let state = State {
         a: Vehicle::new(50.0, 50.0),
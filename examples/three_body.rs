@@ -1,8 +1,7 @@
-use veccentric::Fecc;
-
-mod engine;
-
-use engine::{Buffer, Color};
+use veccentric::{
+    engine::{self, Buffer, Color},
+    Fecc,
+};
 
 // Play with the params.
 const MAX_FORCE: f64 = 100.0;
@@ -28,7 +27,7 @@ impl Vehicle {
         let force = force.limit(MAX_FORCE);
         let acceleration = force / self.mass;
         self.velocity = (self.velocity + acceleration * dt).limit(MAX_VELOCITY);
-        self.position = self.position + self.velocity * dt;
+        self.position += self.velocity * dt;
     }
 }
 
@@ -38,7 +37,7 @@ struct State {
     c: Vehicle,
 }
 
-fn main() -> Result<(), pixels::Error> {
+fn main() -> Result<(), engine::Error> {
     // Set up state.
     let state = State {
         a: Vehicle::new(16.0, 16.0, 10.0),
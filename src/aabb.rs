@@ -0,0 +1,164 @@
+//! Axis-aligned bounding box.
+
+use crate::{size::Size, Fecc};
+
+/// Axis-aligned bounding box described by its minimal and maximal corners.
+///
+/// Point containment is provided by the
+/// [`Contains`](crate::shape::Contains) trait.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{Aabb, Fecc};
+///
+/// let aabb = Aabb::new(Fecc::new(10.0, 0.0), Fecc::new(0.0, 5.0));
+///
+/// assert_approx_eq!(f64, aabb.min.x, 0.0);
+/// assert_approx_eq!(f64, aabb.max.x, 10.0);
+/// assert_approx_eq!(f64, aabb.width(), 10.0);
+/// assert_approx_eq!(f64, aabb.height(), 5.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Aabb {
+    /// The corner with the smallest coordinates.
+    pub min: Fecc,
+
+    /// The corner with the largest coordinates.
+    pub max: Fecc,
+}
+
+impl Aabb {
+    /// Constructs a new box spanning two opposite corners. The corners may be
+    /// passed in any order.
+    pub fn new(a: Fecc, b: Fecc) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Constructs a new box from its minimal corner and its size.
+    pub fn from_origin_size(origin: Fecc, size: Size<f64>) -> Self {
+        Self::new(origin, origin + Fecc::from(size))
+    }
+
+    /// Returns the extent of the box along the X axis.
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    /// Returns the extent of the box along the Y axis.
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+
+    /// Returns the width and the height of the box.
+    pub fn size(&self) -> Size<f64> {
+        Size::from(self.max - self.min)
+    }
+
+    /// Returns the center of the box.
+    pub fn center(&self) -> Fecc {
+        (self.min + self.max) / 2.0
+    }
+
+    /// Returns the corners of the box, counterclockwise starting at
+    /// [`min`](crate::aabb::Aabb::min).
+    pub fn corners(&self) -> [Fecc; 4] {
+        let Aabb { min, max } = *self;
+
+        [min, Fecc::new(max.x, min.y), max, Fecc::new(min.x, max.y)]
+    }
+
+    /// Returns the smallest box containing both boxes.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Returns the overlap of two boxes or `None` if they don't
+    /// [intersect](crate::aabb::Aabb::intersects).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{Aabb, Fecc};
+    ///
+    /// let a = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(2.0, 2.0));
+    /// let b = Aabb::new(Fecc::new(1.0, 1.0), Fecc::new(3.0, 3.0));
+    ///
+    /// let overlap = a.intersection(&b).unwrap();
+    /// assert_eq!(overlap.min, Fecc::new(1.0, 1.0));
+    /// assert_eq!(overlap.max, Fecc::new(2.0, 2.0));
+    ///
+    /// let union = a.union(&b);
+    /// assert_eq!(union.min, Fecc::new(0.0, 0.0));
+    /// assert_eq!(union.max, Fecc::new(3.0, 3.0));
+    /// ```
+    pub fn intersection(&self, other: &Aabb) -> Option<Aabb> {
+        if self.intersects(other) {
+            Some(Aabb {
+                min: self.min.max(other.min),
+                max: self.max.min(other.max),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Grows the box by `margin` on each side. A negative margin shrinks it,
+    /// down to its center at most.
+    pub fn expand(&self, margin: f64) -> Aabb {
+        let center = self.center();
+        let margin = Fecc::new(margin, margin);
+
+        Aabb {
+            min: (self.min - margin).min(center),
+            max: (self.max + margin).max(center),
+        }
+    }
+
+    /// Returns the point of the box closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{Aabb, Fecc};
+    ///
+    /// let screen = Aabb::new(Fecc::zero(), Fecc::new(64.0, 48.0));
+    ///
+    /// assert_eq!(
+    ///     screen.clamp_point(Fecc::new(70.0, 10.0)),
+    ///     Fecc::new(64.0, 10.0)
+    /// );
+    /// ```
+    pub fn clamp_point(&self, point: Fecc) -> Fecc {
+        point.clamp(self.min, self.max)
+    }
+
+    /// Checks whether two boxes overlap. Boxes which only touch are
+    /// considered overlapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{Aabb, Fecc};
+    ///
+    /// let a = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(2.0, 2.0));
+    /// let b = Aabb::new(Fecc::new(1.0, 1.0), Fecc::new(3.0, 3.0));
+    /// let c = Aabb::new(Fecc::new(5.0, 0.0), Fecc::new(6.0, 1.0));
+    ///
+    /// assert!(a.intersects(&b));
+    /// assert!(!a.intersects(&c));
+    /// ```
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+}
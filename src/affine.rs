@@ -0,0 +1,195 @@
+//! Thin wrappers over [`Vecc`](crate::vecc::Vecc) distinguishing points
+//! from vectors.
+//!
+//! Subtracting two points gives the vector between them, adding a vector to
+//! a point moves the point. Points can't be added together or scaled —
+//! that's almost always a bug, e.g. a position added to a velocity instead
+//! of a displacement. Vectors support the usual linear operations. The
+//! wrapped [`Vecc`](crate::vecc::Vecc) is always accessible through the
+//! public field.
+//!
+//! See [`kinematics`](crate::kinematics) for wrappers distinguishing
+//! physical quantities.
+//!
+//! # Examples
+//!
+//! ```
+//! use veccentric::{
+//!     affine::{Point2, Vector2},
+//!     Vecc,
+//! };
+//!
+//! let start = Point2(Vecc::new(1, 2));
+//! let end = Point2(Vecc::new(4, 6));
+//! let offset: Vector2<i32> = end - start;
+//!
+//! assert_eq!(offset, Vector2(Vecc::new(3, 4)));
+//! assert_eq!(start + offset * 2, Point2(Vecc::new(7, 10)));
+//! ```
+//!
+//! ```compile_fail
+//! use veccentric::{affine::Point2, Vecc};
+//!
+//! let _ = Point2(Vecc::new(1, 2)) + Point2(Vecc::new(3, 4));
+//! ```
+
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::{Float, Vecc};
+
+/// A location.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Hash, Debug)]
+pub struct Point2<T>(pub Vecc<T>);
+
+/// A direction and a magnitude — the difference between two points.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Hash, Debug)]
+pub struct Vector2<T>(pub Vecc<T>);
+
+impl<T> Point2<T>
+where
+    T: Float,
+{
+    /// Returns the distance to another point.
+    pub fn dist(self, other: Point2<T>) -> T {
+        (self - other).mag()
+    }
+
+    /// Returns the point halfway to another point.
+    pub fn midpoint(self, other: Point2<T>) -> Point2<T> {
+        self + (other - self) / T::TWO
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Float,
+{
+    /// Returns the magnitude of the vector.
+    pub fn mag(self) -> T {
+        self.0.mag()
+    }
+}
+
+impl<T> Sub for Point2<T>
+where
+    Vecc<T>: Sub<Output = Vecc<T>>,
+{
+    type Output = Vector2<T>;
+
+    fn sub(self, rhs: Point2<T>) -> Vector2<T> {
+        Vector2(self.0 - rhs.0)
+    }
+}
+
+impl<T> Add<Vector2<T>> for Point2<T>
+where
+    Vecc<T>: Add<Output = Vecc<T>>,
+{
+    type Output = Point2<T>;
+
+    fn add(self, rhs: Vector2<T>) -> Point2<T> {
+        Point2(self.0 + rhs.0)
+    }
+}
+
+impl<T> Sub<Vector2<T>> for Point2<T>
+where
+    Vecc<T>: Sub<Output = Vecc<T>>,
+{
+    type Output = Point2<T>;
+
+    fn sub(self, rhs: Vector2<T>) -> Point2<T> {
+        Point2(self.0 - rhs.0)
+    }
+}
+
+impl<T> AddAssign<Vector2<T>> for Point2<T>
+where
+    Vecc<T>: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Vector2<T>) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<T> SubAssign<Vector2<T>> for Point2<T>
+where
+    Vecc<T>: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Vector2<T>) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<T> Add for Vector2<T>
+where
+    Vecc<T>: Add<Output = Vecc<T>>,
+{
+    type Output = Vector2<T>;
+
+    fn add(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2(self.0 + rhs.0)
+    }
+}
+
+impl<T> Sub for Vector2<T>
+where
+    Vecc<T>: Sub<Output = Vecc<T>>,
+{
+    type Output = Vector2<T>;
+
+    fn sub(self, rhs: Vector2<T>) -> Vector2<T> {
+        Vector2(self.0 - rhs.0)
+    }
+}
+
+impl<T> Neg for Vector2<T>
+where
+    Vecc<T>: Neg<Output = Vecc<T>>,
+{
+    type Output = Vector2<T>;
+
+    fn neg(self) -> Vector2<T> {
+        Vector2(-self.0)
+    }
+}
+
+impl<T> Mul<T> for Vector2<T>
+where
+    Vecc<T>: Mul<T, Output = Vecc<T>>,
+{
+    type Output = Vector2<T>;
+
+    fn mul(self, rhs: T) -> Vector2<T> {
+        Vector2(self.0 * rhs)
+    }
+}
+
+impl<T> Div<T> for Vector2<T>
+where
+    Vecc<T>: Div<T, Output = Vecc<T>>,
+{
+    type Output = Vector2<T>;
+
+    fn div(self, rhs: T) -> Vector2<T> {
+        Vector2(self.0 / rhs)
+    }
+}
+
+impl<T> AddAssign for Vector2<T>
+where
+    Vecc<T>: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Vector2<T>) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<T> SubAssign for Vector2<T>
+where
+    Vecc<T>: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Vector2<T>) {
+        self.0 -= rhs.0;
+    }
+}
@@ -0,0 +1,118 @@
+//! Circular arc.
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::{Angle, Fecc};
+
+/// Circular arc starting at angle `start` and spanning `sweep` radians.
+/// Positive `sweep` means counterclockwise.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// # use std::f64::consts::PI;
+/// use veccentric::{Angular, Arc, Fecc};
+///
+/// let arc = Arc {
+///     center: Fecc::zero(),
+///     radius: 2.0,
+///     start: 0.0.rad(),
+///     sweep: 90.deg(),
+/// };
+///
+/// assert_approx_eq!(f64, arc.length(), PI);
+///
+/// let end = arc.point_at(1.0);
+/// assert_approx_eq!(f64, end.x, 0.0, epsilon = 1e-9);
+/// assert_approx_eq!(f64, end.y, 2.0);
+///
+/// let tangent = arc.tangent_at(1.0);
+/// assert_approx_eq!(f64, tangent.x, -1.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Arc {
+    /// The center of the circle.
+    pub center: Fecc,
+
+    /// The radius of the circle.
+    pub radius: f64,
+
+    /// The angle at which the arc starts.
+    pub start: Angle,
+
+    /// The angular extent of the arc.
+    pub sweep: Angle,
+}
+
+impl Arc {
+    /// Constructs the arc of the specified radius which a vehicle at
+    /// `position`, heading in direction `from`, follows to turn in direction
+    /// `to`. The vehicle turns the shorter way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{Angular, Arc, Fecc};
+    ///
+    /// // Heading right, turning up.
+    /// let arc = Arc::joining(Fecc::zero(), 0.deg(), 90.deg(), 5.0);
+    ///
+    /// let end = arc.point_at(1.0);
+    /// assert_approx_eq!(f64, end.x, 5.0);
+    /// assert_approx_eq!(f64, end.y, 5.0);
+    ///
+    /// let heading = arc.tangent_at(1.0);
+    /// assert_approx_eq!(f64, heading.x, 0.0, epsilon = 1e-9);
+    /// assert_approx_eq!(f64, heading.y, 1.0);
+    /// ```
+    pub fn joining<A, B>(position: Fecc, from: A, to: B, radius: f64) -> Self
+    where
+        A: Into<Angle>,
+        B: Into<Angle>,
+    {
+        let from = *from.into();
+        let sweep = (*to.into() - from + PI).rem_euclid(2.0 * PI) - PI;
+        let side = if sweep >= 0.0 { FRAC_PI_2 } else { -FRAC_PI_2 };
+        let center = position + Fecc::from_angle(from + side) * radius;
+
+        Self {
+            center,
+            radius,
+            start: Angle::from(from - side),
+            sweep: Angle::from(sweep),
+        }
+    }
+
+    /// Returns the length of the arc.
+    pub fn length(&self) -> f64 {
+        self.radius * self.sweep.abs()
+    }
+
+    /// Returns the angle at which the arc ends.
+    pub fn end(&self) -> Angle {
+        self.start + self.sweep
+    }
+
+    /// Returns the point at the specified fraction of the arc's length.
+    pub fn point_at(&self, t: f64) -> Fecc {
+        self.center + Fecc::from_angle(self.angle_at(t)) * self.radius
+    }
+
+    /// Returns the unit tangent (pointing in the direction of the sweep) at
+    /// the specified fraction of the arc's length.
+    pub fn tangent_at(&self, t: f64) -> Fecc {
+        let side = if *self.sweep >= 0.0 {
+            FRAC_PI_2
+        } else {
+            -FRAC_PI_2
+        };
+
+        Fecc::from_angle(*self.angle_at(t) + side)
+    }
+
+    fn angle_at(&self, t: f64) -> Angle {
+        self.start + self.sweep * t
+    }
+}
@@ -0,0 +1,201 @@
+//! Point-mass and rigid bodies.
+
+use crate::{Angle, Fecc};
+
+/// Point mass moving on the plane.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{Body2, Fecc};
+///
+/// let mut body = Body2::new(Fecc::zero(), 2.0);
+///
+/// body.apply_force(Fecc::new(4.0, 0.0), 1.0);
+/// body.step(0.5);
+///
+/// assert_approx_eq!(f64, body.velocity.x, 2.0);
+/// assert_approx_eq!(f64, body.position.x, 1.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Body2 {
+    /// The position of the body.
+    pub position: Fecc,
+
+    /// The velocity of the body.
+    pub velocity: Fecc,
+
+    /// The mass of the body.
+    pub mass: f64,
+}
+
+impl Body2 {
+    /// Constructs a new body at rest.
+    pub fn new(position: Fecc, mass: f64) -> Self {
+        Self {
+            position,
+            velocity: Fecc::zero(),
+            mass,
+        }
+    }
+
+    /// Applies a force for `dt` seconds, changing the body's velocity.
+    pub fn apply_force(&mut self, force: Fecc, dt: f64) {
+        self.velocity += force / self.mass * dt;
+    }
+
+    /// Moves the body with its current velocity for `dt` seconds.
+    pub fn step(&mut self, dt: f64) {
+        self.position += self.velocity * dt;
+    }
+}
+
+/// Rigid body which can rotate as well as move.
+///
+/// # Examples
+///
+/// Pushing a box off-center makes it spin.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{body::RigidBody2, Fecc};
+///
+/// let inertia = RigidBody2::box_inertia(1.0, 2.0, 2.0);
+/// let mut body = RigidBody2::new(Fecc::zero(), 1.0, inertia);
+///
+/// // Push the top edge to the right.
+/// body.apply_force_at_point(Fecc::new(1.0, 0.0), Fecc::new(0.0, 1.0), 1.0);
+/// body.step(0.1);
+///
+/// assert_approx_eq!(f64, body.velocity.x, 1.0);
+/// assert!(body.angular_velocity < 0.0);
+/// assert!(*body.orientation < 0.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RigidBody2 {
+    /// The position of the center of mass.
+    pub position: Fecc,
+
+    /// The rotation of the body.
+    pub orientation: Angle,
+
+    /// The velocity of the center of mass.
+    pub velocity: Fecc,
+
+    /// The angular velocity in radians per second. Positive values mean
+    /// counterclockwise rotation.
+    pub angular_velocity: f64,
+
+    /// The mass of the body.
+    pub mass: f64,
+
+    /// The moment of inertia about the center of mass.
+    pub inertia: f64,
+}
+
+impl RigidBody2 {
+    /// Constructs a new body at rest with zero orientation.
+    pub fn new(position: Fecc, mass: f64, inertia: f64) -> Self {
+        Self {
+            position,
+            orientation: Angle::default(),
+            velocity: Fecc::zero(),
+            angular_velocity: 0.0,
+            mass,
+            inertia,
+        }
+    }
+
+    /// Returns the moment of inertia of a solid box.
+    pub fn box_inertia(mass: f64, width: f64, height: f64) -> f64 {
+        mass * (width * width + height * height) / 12.0
+    }
+
+    /// Returns the moment of inertia of a solid disc.
+    pub fn disc_inertia(mass: f64, radius: f64) -> f64 {
+        mass * radius * radius / 2.0
+    }
+
+    /// Applies a force at the center of mass for `dt` seconds.
+    pub fn apply_force(&mut self, force: Fecc, dt: f64) {
+        self.velocity += force / self.mass * dt;
+    }
+
+    /// Applies a force at the specified point (in world coordinates) for
+    /// `dt` seconds. Unless the force points at the center of mass, it also
+    /// makes the body spin.
+    pub fn apply_force_at_point(&mut self, force: Fecc, point: Fecc, dt: f64) {
+        self.apply_force(force, dt);
+        self.apply_torque((point - self.position).cross(force), dt);
+    }
+
+    /// Applies a torque for `dt` seconds. Positive values spin the body
+    /// counterclockwise.
+    pub fn apply_torque(&mut self, torque: f64, dt: f64) {
+        self.angular_velocity += torque / self.inertia * dt;
+    }
+
+    /// Returns the velocity of the point of the body at the specified world
+    /// position.
+    pub fn velocity_at(&self, point: Fecc) -> Fecc {
+        let arm = point - self.position;
+
+        self.velocity + Fecc::new(-arm.y, arm.x) * self.angular_velocity
+    }
+
+    /// Moves and rotates the body with its current velocities for `dt`
+    /// seconds.
+    pub fn step(&mut self, dt: f64) {
+        self.position += self.velocity * dt;
+        self.orientation += Angle::from(self.angular_velocity * dt);
+    }
+}
+
+/// Returns an infinite iterator over the future positions of the body, one
+/// per time step. The body is copied, so the real simulation state isn't
+/// affected.
+///
+/// `force` is called with the position and velocity of the body at each
+/// step and returns the total force acting on it. The body is integrated the
+/// same way as with [`Body2::apply_force`](crate::body::Body2::apply_force)
+/// followed by [`Body2::step`](crate::body::Body2::step), so the prediction
+/// matches the simulation if it uses the same time step.
+///
+/// # Examples
+///
+/// Drawing the arc of a thrown ball.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{body, Body2, Fecc};
+///
+/// let mut ball = Body2::new(Fecc::zero(), 1.0);
+/// ball.velocity = Fecc::new(2.0, 10.0);
+///
+/// let gravity = |_position, _velocity| Fecc::new(0.0, -10.0);
+/// let arc: Vec<Fecc> = body::predict(ball, gravity, 0.1)
+///     .take_while(|position| position.y >= 0.0)
+///     .collect();
+///
+/// assert_eq!(arc.len(), 19);
+/// assert_approx_eq!(f64, arc[0].y, 0.9);
+/// assert!(ball.position.is_zero());
+/// ```
+pub fn predict<F>(
+    body: Body2,
+    mut force: F,
+    dt: f64,
+) -> impl Iterator<Item = Fecc>
+where
+    F: FnMut(Fecc, Fecc) -> Fecc,
+{
+    let mut body = body;
+
+    std::iter::repeat_with(move || {
+        body.apply_force(force(body.position, body.velocity), dt);
+        body.step(dt);
+
+        body.position
+    })
+}
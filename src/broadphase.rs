@@ -0,0 +1,133 @@
+//! Broad-phase collision detection.
+//!
+//! A broad phase cheaply discards pairs of bodies which can't collide, so
+//! that the exact (and more expensive) tests only run on the few candidate
+//! pairs left.
+
+use crate::{collision::CollisionFilter, Aabb};
+
+/// [Sweep-and-prune](https://en.wikipedia.org/wiki/Sweep_and_prune)
+/// broad phase.
+///
+/// The boxes are sorted along the X axis and swept, so only the boxes
+/// overlapping on that axis are compared. The order is kept between calls to
+/// [`update`](crate::broadphase::SweepAndPrune::update) — bodies usually
+/// move only a little each frame, so re-sorting is close to linear.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{broadphase::SweepAndPrune, Aabb, Circle, Fecc};
+///
+/// let mut bodies = vec![
+///     Circle::new(Fecc::new(0.0, 0.0), 1.0),
+///     Circle::new(Fecc::new(1.5, 0.0), 1.0),
+///     Circle::new(Fecc::new(10.0, 0.0), 1.0),
+/// ];
+/// let mut broadphase = SweepAndPrune::new();
+///
+/// let boxes: Vec<Aabb> = bodies.iter().map(Circle::aabb).collect();
+/// assert_eq!(broadphase.update(&boxes), &[(0, 1)]);
+///
+/// // The third body moves next to the first one.
+/// bodies[2].center = Fecc::new(-1.5, 0.0);
+///
+/// let boxes: Vec<Aabb> = bodies.iter().map(Circle::aabb).collect();
+/// assert_eq!(broadphase.update(&boxes), &[(0, 1), (0, 2)]);
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct SweepAndPrune {
+    order: Vec<usize>,
+    pairs: Vec<(usize, usize)>,
+}
+
+impl SweepAndPrune {
+    /// Constructs a new broad phase.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the pairs of indices of overlapping boxes. The first index in
+    /// each pair is the smaller one and the pairs are sorted.
+    ///
+    /// The boxes should be passed in the same order every frame. If their
+    /// number changes, the sort order is rebuilt from scratch.
+    pub fn update(&mut self, boxes: &[Aabb]) -> &[(usize, usize)] {
+        self.sweep(boxes, |_, _| true)
+    }
+
+    /// Returns the pairs of indices of overlapping boxes whose
+    /// [filters](crate::collision::CollisionFilter) allow them to collide,
+    /// like [`update`](crate::broadphase::SweepAndPrune::update).
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer filters than boxes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{
+    ///     broadphase::SweepAndPrune, collision::CollisionFilter, Aabb, Fecc,
+    /// };
+    ///
+    /// let boxes = vec![Aabb::new(Fecc::zero(), Fecc::new(1.0, 1.0)); 3];
+    /// let bullet = CollisionFilter::new(0b10, 0b01);
+    /// let filters = [CollisionFilter::default(), bullet, bullet];
+    ///
+    /// let mut broadphase = SweepAndPrune::new();
+    ///
+    /// assert_eq!(
+    ///     broadphase.update_filtered(&boxes, &filters),
+    ///     &[(0, 1), (0, 2)]
+    /// );
+    /// ```
+    pub fn update_filtered(
+        &mut self,
+        boxes: &[Aabb],
+        filters: &[CollisionFilter],
+    ) -> &[(usize, usize)] {
+        assert!(filters.len() >= boxes.len(), "missing filters");
+
+        self.sweep(boxes, |a, b| filters[a].can_collide(&filters[b]))
+    }
+
+    fn sweep<F>(&mut self, boxes: &[Aabb], accept: F) -> &[(usize, usize)]
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        if self.order.len() != boxes.len() {
+            self.order = (0..boxes.len()).collect();
+        }
+
+        // Insertion sort, fast on nearly sorted input.
+        for i in 1..self.order.len() {
+            let mut j = i;
+
+            while j > 0
+                && boxes[self.order[j - 1]].min.x > boxes[self.order[j]].min.x
+            {
+                self.order.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+
+        self.pairs.clear();
+
+        for (i, &a) in self.order.iter().enumerate() {
+            for &b in &self.order[(i + 1)..] {
+                if boxes[b].min.x > boxes[a].max.x {
+                    break;
+                }
+
+                if boxes[a].intersects(&boxes[b]) && accept(a, b) {
+                    self.pairs.push((a.min(b), a.max(b)));
+                }
+            }
+        }
+
+        self.pairs.sort_unstable();
+
+        &self.pairs
+    }
+}
@@ -0,0 +1,93 @@
+//! Implementation of [`BVecc`](crate::bvecc::BVecc) - the alias for
+//! [`Vecc<bool>`](crate::vecc::Vecc).
+
+use std::ops::{BitAnd, BitOr, BitXor};
+
+use crate::Vecc;
+
+/// Vector with two [`bool`](bool) components — a mask returned by
+/// component-wise comparisons like [`cmp_lt`](crate::vecc::Vecc::cmp_lt).
+///
+/// Together with [`Vecc::select`](crate::vecc::Vecc::select) it allows
+/// handling each axis separately without branching.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{BVecc, Fecc};
+///
+/// let size = Fecc::new(100.0, 50.0);
+/// let position = Fecc::new(120.0, 30.0);
+/// let velocity = Fecc::new(5.0, 2.0);
+///
+/// // Bounce only off the walls that were hit.
+/// let hit = position.cmp_lt(Fecc::zero()) | position.cmp_gt(size);
+/// let velocity = Fecc::select(hit, -velocity, velocity);
+///
+/// assert_eq!(hit, BVecc::new(true, false));
+/// assert_eq!(velocity, Fecc::new(-5.0, 2.0));
+/// ```
+pub type BVecc = Vecc<bool>;
+
+impl BVecc {
+    /// Checks whether any component is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::BVecc;
+    ///
+    /// assert!(BVecc::new(false, true).any());
+    /// assert!(!BVecc::new(false, false).any());
+    /// ```
+    pub fn any(&self) -> bool {
+        self.x || self.y
+    }
+
+    /// Checks whether both components are `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::BVecc;
+    ///
+    /// assert!(BVecc::new(true, true).all());
+    /// assert!(!BVecc::new(false, true).all());
+    /// ```
+    pub fn all(&self) -> bool {
+        self.x && self.y
+    }
+}
+
+impl BitAnd for BVecc {
+    type Output = BVecc;
+
+    fn bitand(self, rhs: BVecc) -> BVecc {
+        Vecc {
+            x: self.x & rhs.x,
+            y: self.y & rhs.y,
+        }
+    }
+}
+
+impl BitOr for BVecc {
+    type Output = BVecc;
+
+    fn bitor(self, rhs: BVecc) -> BVecc {
+        Vecc {
+            x: self.x | rhs.x,
+            y: self.y | rhs.y,
+        }
+    }
+}
+
+impl BitXor for BVecc {
+    type Output = BVecc;
+
+    fn bitxor(self, rhs: BVecc) -> BVecc {
+        Vecc {
+            x: self.x ^ rhs.x,
+            y: self.y ^ rhs.y,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Fixed binary layouts of vectors.
+//!
+//! Vectors of [`f64`](f64), [`f32`](f32), [`i64`](i64) and [`i32`](i32) can
+//! be converted to and from bytes without any serialization framework, e.g.
+//! for network protocols or replay files. The layout is always the same,
+//! regardless of the platform:
+//!
+//! * `x` comes first, then `y`, with no padding in between,
+//! * each component is stored in little-endian byte order using its type's
+//!   `to_le_bytes`,
+//! * so a vector takes exactly twice the size of its component type (16
+//!   bytes for [`Fecc`](crate::fecc::Fecc) and `Vecc<i64>`, 8 bytes for
+//!   `Vecc<f32>` and `Vecc<i32>`).
+//!
+//! Floats are stored bit for bit, so NaNs and signed zeros survive the round
+//! trip.
+//!
+//! # Examples
+//!
+//! ```
+//! use veccentric::{Fecc, Vecc};
+//!
+//! let position = Fecc::new(1.5, -2.0);
+//! let bytes = position.to_le_bytes();
+//!
+//! assert_eq!(bytes[..8], 1.5_f64.to_le_bytes());
+//! assert_eq!(bytes[8..], (-2.0_f64).to_le_bytes());
+//! assert_eq!(Fecc::from_le_bytes(bytes), position);
+//!
+//! let tile = Vecc::<i32>::new(3, -1);
+//!
+//! assert_eq!(tile.to_le_bytes(), [3, 0, 0, 0, 255, 255, 255, 255]);
+//! ```
+
+use std::convert::TryInto;
+
+use crate::Vecc;
+
+macro_rules! impl_bytes {
+    ($t:ty, $size:expr) => {
+        impl Vecc<$t> {
+            #[doc = concat!(
+                        "Returns the vector as ",
+                        stringify!($size),
+                        " bytes, see [the layout](crate::bytes).",
+                    )]
+            pub fn to_le_bytes(&self) -> [u8; $size] {
+                let mut bytes = [0; $size];
+                let (x, y) = bytes.split_at_mut($size / 2);
+
+                x.copy_from_slice(&self.x.to_le_bytes());
+                y.copy_from_slice(&self.y.to_le_bytes());
+
+                bytes
+            }
+
+            #[doc = concat!(
+                        "Constructs a vector from ",
+                        stringify!($size),
+                        " bytes, see [the layout](crate::bytes).",
+                    )]
+            pub fn from_le_bytes(bytes: [u8; $size]) -> Self {
+                let (x, y) = bytes.split_at($size / 2);
+
+                Self {
+                    x: <$t>::from_le_bytes(x.try_into().unwrap()),
+                    y: <$t>::from_le_bytes(y.try_into().unwrap()),
+                }
+            }
+        }
+    };
+}
+
+impl_bytes!(f64, 16);
+impl_bytes!(f32, 8);
+impl_bytes!(i64, 16);
+impl_bytes!(i32, 8);
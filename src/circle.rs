@@ -0,0 +1,35 @@
+//! Circle.
+
+use crate::{Aabb, Fecc};
+
+/// Circle described by its center and radius.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{Circle, Fecc};
+///
+/// let circle = Circle::new(Fecc::new(1.0, 2.0), 3.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Circle {
+    /// The center of the circle.
+    pub center: Fecc,
+
+    /// The radius of the circle.
+    pub radius: f64,
+}
+
+impl Circle {
+    /// Constructs a new circle.
+    pub fn new(center: Fecc, radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns the smallest box containing the circle.
+    pub fn aabb(&self) -> Aabb {
+        let extent = Fecc::new(self.radius, self.radius);
+
+        Aabb::new(self.center - extent, self.center + extent)
+    }
+}
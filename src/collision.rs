@@ -0,0 +1,560 @@
+//! Collision detection and resolution.
+//!
+//! The `mtv_*` functions return the minimum translation vector — the
+//! shortest vector by which the first shape has to be moved so that it no
+//! longer overlaps the second one. Shapes which only touch don't overlap.
+
+use crate::{Aabb, Circle, Fecc, Polygon, Segment};
+
+/// Time of impact of a moving shape.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Toi {
+    /// The fraction of the motion, in `[0.0, 1.0]`, after which the shapes
+    /// touch.
+    pub time: f64,
+
+    /// The point of contact.
+    pub point: Fecc,
+
+    /// The unit normal of the obstacle's surface at the point of contact,
+    /// facing the moving shape.
+    pub normal: Fecc,
+}
+
+/// Bitmask-based filter deciding which bodies can collide.
+///
+/// Each bit is a layer. A body belongs to the layers in `layer` and collides
+/// with the layers in `mask`. Two bodies collide only if each of them is in
+/// one of the layers the other one collides with. The default filter is in
+/// the first layer and collides with everything.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::collision::CollisionFilter;
+///
+/// const WALLS: u32 = 1 << 0;
+/// const PLAYER: u32 = 1 << 1;
+/// const BULLETS: u32 = 1 << 2;
+///
+/// let wall = CollisionFilter::new(WALLS, u32::MAX);
+/// let player = CollisionFilter::new(PLAYER, WALLS | BULLETS);
+/// let bullet = CollisionFilter::new(BULLETS, WALLS | PLAYER);
+///
+/// assert!(bullet.can_collide(&player));
+/// assert!(bullet.can_collide(&wall));
+/// assert!(!bullet.can_collide(&bullet));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct CollisionFilter {
+    /// The layers the body belongs to.
+    pub layer: u32,
+
+    /// The layers the body collides with.
+    pub mask: u32,
+}
+
+impl CollisionFilter {
+    /// Constructs a new filter.
+    pub fn new(layer: u32, mask: u32) -> Self {
+        Self { layer, mask }
+    }
+
+    /// Returns `true` if the bodies with the filters should collide.
+    pub fn can_collide(&self, other: &CollisionFilter) -> bool {
+        self.layer & other.mask != 0 && other.layer & self.mask != 0
+    }
+}
+
+impl Default for CollisionFilter {
+    fn default() -> Self {
+        Self::new(1, u32::MAX)
+    }
+}
+
+/// Sweeps a circle along `motion` and returns the first moment it touches
+/// the segment, or `None` if it doesn't touch it during the motion.
+///
+/// If the circle already overlaps the segment, the time of impact is `0.0`.
+/// If its center lies on the segment, the normal is perpendicular to the
+/// segment, pointing against the motion. The result is `None` if the segment
+/// is a single point and the circle's center coincides with it, since there's
+/// no direction to push the circle out.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{collision, Fecc, Segment};
+///
+/// let ground = Segment(Fecc::new(-5.0, 0.0), Fecc::new(5.0, 0.0));
+///
+/// let toi = collision::sweep_circle_vs_segment(
+///     Fecc::new(0.0, 5.0),
+///     1.0,
+///     Fecc::new(0.0, -10.0),
+///     &ground,
+/// )
+/// .unwrap();
+///
+/// assert_approx_eq!(f64, toi.time, 0.4);
+/// assert_approx_eq!(f64, toi.normal.y, 1.0);
+///
+/// // Moving past the end of the segment.
+/// let toi = collision::sweep_circle_vs_segment(
+///     Fecc::new(7.0, 5.0),
+///     1.0,
+///     Fecc::new(0.0, -10.0),
+///     &ground,
+/// );
+///
+/// assert!(toi.is_none());
+///
+/// // Resting with the center on the segment.
+/// let toi = collision::sweep_circle_vs_segment(
+///     Fecc::new(1.0, 0.0),
+///     1.0,
+///     Fecc::zero(),
+///     &ground,
+/// )
+/// .unwrap();
+///
+/// assert_approx_eq!(f64, toi.normal.mag(), 1.0);
+/// assert_approx_eq!(f64, toi.normal.x, 0.0);
+/// ```
+pub fn sweep_circle_vs_segment(
+    center: Fecc,
+    radius: f64,
+    motion: Fecc,
+    segment: &Segment,
+) -> Option<Toi> {
+    let Segment(a, b) = *segment;
+    let closest = segment.closest_point(center);
+
+    if closest.dist_squared(center) <= radius * radius {
+        let normal = if center == closest {
+            // The center lies on the segment, push the circle out sideways.
+            let side = Fecc::new(a.y - b.y, b.x - a.x).normalize();
+
+            if side.is_zero() {
+                -motion.normalize()
+            } else if side.dot(motion) > 0.0 {
+                -side
+            } else {
+                side
+            }
+        } else {
+            (center - closest).normalize()
+        };
+
+        if normal.is_zero() {
+            return None;
+        }
+
+        return Some(Toi {
+            time: 0.0,
+            point: closest,
+            normal,
+        });
+    }
+
+    let mut first: Option<Toi> = None;
+    let mut record = |toi: Toi| {
+        if first.is_none_or(|first| toi.time < first.time) {
+            first = Some(toi);
+        }
+    };
+
+    // The flat side of the swept capsule.
+    let along = b - a;
+
+    if !along.is_zero() {
+        let normal = Fecc::new(-along.y, along.x).normalize();
+        let normal = if normal.dot(center - a) < 0.0 {
+            -normal
+        } else {
+            normal
+        };
+        let dist = (center - a).dot(normal);
+        let speed = motion.dot(normal);
+
+        if speed < 0.0 {
+            let time = (dist - radius) / -speed;
+            let position = center + motion * time;
+            let s = (position - a).dot(along) / along.mag_squared();
+
+            if (0.0..=1.0).contains(&time) && (0.0..=1.0).contains(&s) {
+                record(Toi {
+                    time,
+                    point: a + along * s,
+                    normal,
+                });
+            }
+        }
+    }
+
+    // The rounded ends of the swept capsule.
+    for end in [a, b] {
+        if let Some(time) = sweep_circle_vs_point(center, radius, motion, end) {
+            record(Toi {
+                time,
+                point: end,
+                normal: (center + motion * time - end).normalize(),
+            });
+        }
+    }
+
+    first
+}
+
+/// Returns the fraction of the motion after which the circle touches the
+/// point. The circle must not contain the point initially.
+fn sweep_circle_vs_point(
+    center: Fecc,
+    radius: f64,
+    motion: Fecc,
+    point: Fecc,
+) -> Option<f64> {
+    let offset = center - point;
+    let a = motion.mag_squared();
+    let b = 2.0 * motion.dot(offset);
+    let c = offset.mag_squared() - radius * radius;
+    let discriminant = b * b - 4.0 * a * c;
+
+    if a == 0.0 || discriminant < 0.0 {
+        return None;
+    }
+
+    let time = (-b - discriminant.sqrt()) / (2.0 * a);
+
+    (0.0..=1.0).contains(&time).then_some(time)
+}
+
+/// Contact of a moving shape with one of the obstacles.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Contact {
+    /// The index of the obstacle.
+    pub collider: usize,
+
+    /// The point of contact.
+    pub point: Fecc,
+
+    /// The unit normal of the obstacle's surface at the point of contact,
+    /// facing the moving shape.
+    pub normal: Fecc,
+}
+
+/// Moves a circle along `motion`, sliding along the segments it hits
+/// instead of stopping. Returns the final position and the contacts in the
+/// order they happened.
+///
+/// Each hit uses up one iteration, the final unobstructed move doesn't. If
+/// the iterations run out (e.g. in a narrow corner), the rest of the motion
+/// is dropped, so with `max_iterations == 0` the circle only moves if nothing
+/// is in its way. The circle is kept a tiny distance away from the surfaces
+/// it touches, so that it doesn't get stuck in them in the next call.
+///
+/// # Examples
+///
+/// A circle moving diagonally into a corner of a floor and a wall.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{collision, Fecc, Segment};
+///
+/// let level = [
+///     Segment(Fecc::new(-10.0, 0.0), Fecc::new(10.0, 0.0)),
+///     Segment(Fecc::new(3.0, 0.0), Fecc::new(3.0, 10.0)),
+/// ];
+///
+/// let (position, contacts) = collision::move_and_slide(
+///     Fecc::new(0.0, 2.0),
+///     1.0,
+///     Fecc::new(1.0, -4.0),
+///     &level,
+///     4,
+/// );
+///
+/// // Landed on the floor and slid along it.
+/// assert_approx_eq!(f64, position.x, 1.0, epsilon = 1e-6);
+/// assert_approx_eq!(f64, position.y, 1.0, epsilon = 1e-6);
+/// assert_eq!(contacts.len(), 1);
+/// assert_eq!(contacts[0].collider, 0);
+///
+/// let motion = Fecc::new(5.0, -1.0);
+/// let (position, contacts) =
+///     collision::move_and_slide(position, 1.0, motion, &level, 4);
+///
+/// // Slid into the wall.
+/// assert_approx_eq!(f64, position.x, 2.0, epsilon = 1e-6);
+/// assert_approx_eq!(f64, position.y, 1.0, epsilon = 1e-6);
+/// assert_eq!(contacts.last().unwrap().collider, 1);
+///
+/// // Moving away from the obstacles doesn't need any iterations.
+/// let motion = Fecc::new(-1.0, 1.0);
+/// let (position, contacts) =
+///     collision::move_and_slide(position, 1.0, motion, &level, 0);
+///
+/// assert_approx_eq!(f64, position.x, 1.0, epsilon = 1e-6);
+/// assert_approx_eq!(f64, position.y, 2.0, epsilon = 1e-6);
+/// assert!(contacts.is_empty());
+/// ```
+pub fn move_and_slide(
+    position: Fecc,
+    radius: f64,
+    motion: Fecc,
+    colliders: &[Segment],
+    max_iterations: usize,
+) -> (Fecc, Vec<Contact>) {
+    // The distance kept from the surfaces.
+    const SKIN: f64 = 1e-9;
+
+    let mut position = position;
+    let mut motion = motion;
+    let mut contacts = vec![];
+
+    while !motion.is_zero() {
+        // Surfaces the circle touches, but moves along or away from, are
+        // not obstacles.
+        let hit = colliders
+            .iter()
+            .enumerate()
+            .filter_map(|(ix, segment)| {
+                sweep_circle_vs_segment(position, radius, motion, segment)
+                    .map(|toi| (ix, toi))
+            })
+            .filter(|(_, toi)| motion.dot(toi.normal) < 0.0)
+            .min_by(|(_, a), (_, b)| a.time.total_cmp(&b.time));
+
+        let (collider, toi) = match hit {
+            Some(hit) if contacts.len() < max_iterations => hit,
+            Some(_) => break,
+            None => {
+                position += motion;
+
+                break;
+            }
+        };
+
+        position += motion * toi.time + toi.normal * SKIN;
+        motion = (motion * (1.0 - toi.time)).slide(toi.normal);
+        contacts.push(Contact {
+            collider,
+            point: toi.point,
+            normal: toi.normal,
+        });
+    }
+
+    (position, contacts)
+}
+
+/// Returns the minimum translation vector separating two circles.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{collision, Circle, Fecc};
+///
+/// let mut a = Circle::new(Fecc::new(0.0, 0.0), 2.0);
+/// let b = Circle::new(Fecc::new(3.0, 0.0), 2.0);
+///
+/// a.center += collision::mtv_circle_circle(&a, &b).unwrap();
+///
+/// assert_approx_eq!(f64, a.center.x, -1.0);
+/// ```
+pub fn mtv_circle_circle(a: &Circle, b: &Circle) -> Option<Fecc> {
+    let offset = a.center - b.center;
+    let depth = a.radius + b.radius - offset.mag();
+
+    if depth <= 0.0 {
+        None
+    } else if offset.is_zero() {
+        Some(Fecc::new(depth, 0.0))
+    } else {
+        Some(offset.resize(depth))
+    }
+}
+
+/// Returns the minimum translation vector separating a circle from a box.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{collision, Aabb, Circle, Fecc};
+///
+/// let floor = Aabb::new(Fecc::new(-10.0, -5.0), Fecc::new(10.0, 0.0));
+/// let ball = Circle::new(Fecc::new(0.0, 0.5), 1.0);
+///
+/// let mtv = collision::mtv_circle_aabb(&ball, &floor).unwrap();
+///
+/// assert_approx_eq!(f64, mtv.x, 0.0);
+/// assert_approx_eq!(f64, mtv.y, 0.5);
+/// ```
+pub fn mtv_circle_aabb(circle: &Circle, aabb: &Aabb) -> Option<Fecc> {
+    let center = circle.center;
+    let closest = center.clamp(aabb.min, aabb.max);
+
+    if closest != center {
+        let offset = center - closest;
+        let depth = circle.radius - offset.mag();
+
+        return (depth > 0.0).then(|| offset.resize(depth));
+    }
+
+    // The center is inside the box — push it out through the nearest side.
+    let sides = [
+        (center.x - aabb.min.x, Fecc::new(-1.0, 0.0)),
+        (aabb.max.x - center.x, Fecc::new(1.0, 0.0)),
+        (center.y - aabb.min.y, Fecc::new(0.0, -1.0)),
+        (aabb.max.y - center.y, Fecc::new(0.0, 1.0)),
+    ];
+    let (dist, direction) = sides
+        .iter()
+        .copied()
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .unwrap();
+
+    Some(direction * (dist + circle.radius))
+}
+
+/// Returns the minimum translation vector separating two boxes.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{collision, Aabb, Fecc};
+///
+/// let a = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(2.0, 2.0));
+/// let b = Aabb::new(Fecc::new(1.5, -5.0), Fecc::new(5.0, 5.0));
+///
+/// let mtv = collision::mtv_aabb_aabb(&a, &b).unwrap();
+///
+/// assert_approx_eq!(f64, mtv.x, -0.5);
+/// assert_approx_eq!(f64, mtv.y, 0.0);
+///
+/// // A box inside another one is pushed out through the nearest side.
+/// let inner = Aabb::new(Fecc::new(1.0, 2.0), Fecc::new(3.0, 4.0));
+/// let outer = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(10.0, 10.0));
+///
+/// let mtv = collision::mtv_aabb_aabb(&inner, &outer).unwrap();
+///
+/// assert_approx_eq!(f64, mtv.x, -3.0);
+/// assert_approx_eq!(f64, mtv.y, 0.0);
+///
+/// let moved = Aabb::new(inner.min + mtv, inner.max + mtv);
+/// assert!(collision::mtv_aabb_aabb(&moved, &outer).is_none());
+/// ```
+pub fn mtv_aabb_aabb(a: &Aabb, b: &Aabb) -> Option<Fecc> {
+    let x = interval_push((a.min.x, a.max.x), (b.min.x, b.max.x))?;
+    let y = interval_push((a.min.y, a.max.y), (b.min.y, b.max.y))?;
+
+    if x.abs() < y.abs() {
+        Some(Fecc::new(x, 0.0))
+    } else {
+        Some(Fecc::new(0.0, y))
+    }
+}
+
+/// Returns the minimum translation vector separating two convex polygons,
+/// using the [separating axis
+/// theorem](https://en.wikipedia.org/wiki/Hyperplane_separation_theorem).
+///
+/// The result is unspecified if either polygon isn't convex.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{collision, Fecc, Polygon};
+///
+/// let square = |x: f64, y: f64| -> Polygon {
+///     vec![
+///         Fecc::new(x, y),
+///         Fecc::new(x + 2.0, y),
+///         Fecc::new(x + 2.0, y + 2.0),
+///         Fecc::new(x, y + 2.0),
+///     ]
+///     .into()
+/// };
+///
+/// let mtv = collision::mtv_convex(&square(0.0, 0.0), &square(1.0, 0.5))
+///     .unwrap();
+///
+/// assert_approx_eq!(f64, mtv.x, -1.0);
+/// assert_approx_eq!(f64, mtv.y, 0.0);
+///
+/// assert!(collision::mtv_convex(&square(0.0, 0.0), &square(3.0, 0.0))
+///     .is_none());
+///
+/// // A polygon inside another one is pushed out through the nearest side.
+/// let outer: Polygon = vec![
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(10.0, 0.0),
+///     Fecc::new(10.0, 10.0),
+///     Fecc::new(0.0, 10.0),
+/// ]
+/// .into();
+///
+/// let mtv = collision::mtv_convex(&square(1.0, 3.0), &outer).unwrap();
+///
+/// assert_approx_eq!(f64, mtv.x, -3.0);
+/// assert_approx_eq!(f64, mtv.y, 0.0);
+/// assert!(collision::mtv_convex(&square(-2.0, 3.0), &outer).is_none());
+/// ```
+pub fn mtv_convex(a: &Polygon, b: &Polygon) -> Option<Fecc> {
+    if a.0.is_empty() || b.0.is_empty() {
+        return None;
+    }
+
+    let project = |polygon: &Polygon, axis: Fecc| {
+        polygon
+            .0
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(min, max), v| {
+                let p = v.dot(axis);
+
+                (min.min(p), max.max(p))
+            })
+    };
+    let mut best: Option<(f64, Fecc)> = None;
+
+    for Segment(start, end) in a.edges().chain(b.edges()) {
+        let along = end - start;
+
+        if along.is_zero() {
+            continue;
+        }
+
+        let axis = Fecc::new(-along.y, along.x).normalize();
+        let push = interval_push(project(a, axis), project(b, axis))?;
+
+        if best.is_none_or(|(best, _)| push.abs() < best.abs()) {
+            best = Some((push, axis));
+        }
+    }
+
+    let (push, axis) = best?;
+
+    Some(axis * push)
+}
+
+/// Returns the signed distance the interval `a` has to move to stop
+/// overlapping the interval `b`, choosing the shorter of the two ways out, or
+/// `None` if they don't overlap.
+fn interval_push(
+    (a_min, a_max): (f64, f64),
+    (b_min, b_max): (f64, f64),
+) -> Option<f64> {
+    let (down, up) = (a_max - b_min, b_max - a_min);
+
+    if down <= 0.0 || up <= 0.0 {
+        None
+    } else if down < up {
+        Some(-down)
+    } else {
+        Some(up)
+    }
+}
@@ -0,0 +1,158 @@
+//! Parametric curves for plotters and generative art.
+//!
+//! Closed curves are sampled at `samples` evenly spaced parameter values,
+//! without repeating the first point at the end.
+
+use std::{f64::consts::TAU, ops::RangeInclusive};
+
+use crate::{Fecc, Polyline};
+
+/// Samples the [superellipse](https://en.wikipedia.org/wiki/Superellipse)
+/// `|x / a|ⁿ + |y / b|ⁿ = 1` around `center`. `n = 2.0` gives an ellipse,
+/// larger values a rounded rectangle (a squircle for `n = 4.0`), values
+/// between `0.0` and `1.0` a star with concave sides.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{curves, Fecc};
+///
+/// let squircle = curves::superellipse(Fecc::zero(), 2.0, 1.0, 4.0, 64)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(squircle.len(), 64);
+/// assert_approx_eq!(f64, squircle[0].x, 2.0);
+///
+/// for point in squircle {
+///     let sum = (point.x / 2.0).powi(4) + point.y.powi(4);
+///     assert_approx_eq!(f64, sum, 1.0, epsilon = 1e-9);
+/// }
+/// ```
+pub fn superellipse(
+    center: Fecc,
+    a: f64,
+    b: f64,
+    n: f64,
+    samples: usize,
+) -> impl Iterator<Item = Fecc> {
+    let power = move |value: f64| value.signum() * value.abs().powf(2.0 / n);
+
+    parameters(samples).map(move |t| {
+        center + Fecc::new(a * power(t.cos()), b * power(t.sin()))
+    })
+}
+
+/// Samples the [Lissajous curve](https://en.wikipedia.org/wiki/Lissajous_curve)
+/// `(sin(a t + δ), sin(b t))` for `t` in `[0, 2π)`. The curve lies in the
+/// square from `(-1, -1)` to `(1, 1)` and is closed for integer
+/// frequencies `a` and `b`.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// # use std::f64::consts::FRAC_PI_2;
+/// use veccentric::curves;
+///
+/// // A figure eight.
+/// let eight = curves::lissajous(1.0, 2.0, FRAC_PI_2, 100)
+///     .collect::<Vec<_>>();
+///
+/// assert_approx_eq!(f64, eight[0].x, 1.0);
+/// assert_approx_eq!(f64, eight[0].y, 0.0);
+/// assert!(eight.iter().all(|point| point.x.abs() <= 1.0));
+/// ```
+pub fn lissajous(
+    a: f64,
+    b: f64,
+    delta: f64,
+    samples: usize,
+) -> impl Iterator<Item = Fecc> {
+    parameters(samples)
+        .map(move |t| Fecc::new((a * t + delta).sin(), (b * t).sin()))
+}
+
+/// Samples the
+/// [Archimedean spiral](https://en.wikipedia.org/wiki/Archimedean_spiral)
+/// `r = a + bθ` around `center`, for `θ` (in radians) from the start to the
+/// end of `theta_range`. The turns are `2πb` apart.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// # use std::f64::consts::TAU;
+/// use veccentric::{curves, Fecc};
+///
+/// let spiral =
+///     curves::spiral_archimedean(Fecc::zero(), 0.0, 1.0, 0.0..=TAU, 50);
+///
+/// assert_eq!(spiral.0.len(), 50);
+/// assert_approx_eq!(f64, spiral.0[49].x, TAU);
+/// ```
+pub fn spiral_archimedean(
+    center: Fecc,
+    a: f64,
+    b: f64,
+    theta_range: RangeInclusive<f64>,
+    samples: usize,
+) -> Polyline {
+    spiral(center, theta_range, samples, |theta| a + b * theta)
+}
+
+/// Samples the
+/// [logarithmic spiral](https://en.wikipedia.org/wiki/Logarithmic_spiral)
+/// `r = a·e^(bθ)` around `center`, for `θ` (in radians) from the start to
+/// the end of `theta_range`. Each turn is `e^(2πb)` times farther from the
+/// center than the previous one.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// # use std::f64::consts::TAU;
+/// use veccentric::{curves, Fecc};
+///
+/// let turns = 0.0..=2.0 * TAU;
+/// let shell = curves::spiral_logarithmic(Fecc::zero(), 1.0, 0.2, turns, 3);
+///
+/// assert_approx_eq!(f64, shell.0[0].x, 1.0);
+/// assert_approx_eq!(f64, shell.0[2].x / shell.0[1].x, (0.2 * TAU).exp());
+/// ```
+pub fn spiral_logarithmic(
+    center: Fecc,
+    a: f64,
+    b: f64,
+    theta_range: RangeInclusive<f64>,
+    samples: usize,
+) -> Polyline {
+    spiral(center, theta_range, samples, |theta| a * (b * theta).exp())
+}
+
+/// Samples the polar curve `r = radius(θ)` for `θ` in `theta_range`.
+fn spiral<F>(
+    center: Fecc,
+    theta_range: RangeInclusive<f64>,
+    samples: usize,
+    radius: F,
+) -> Polyline
+where
+    F: Fn(f64) -> f64,
+{
+    let (start, end) = theta_range.into_inner();
+    let theta = |i: usize| match samples {
+        1 => start,
+        _ => start + (end - start) * i as f64 / (samples - 1) as f64,
+    };
+
+    (0..samples)
+        .map(theta)
+        .map(|theta| center + Fecc::from_angle(theta) * radius(theta))
+        .collect()
+}
+
+/// Returns `samples` evenly spaced values in `[0, 2π)`.
+fn parameters(samples: usize) -> impl Iterator<Item = f64> {
+    (0..samples).map(move |i| TAU * i as f64 / samples as f64)
+}
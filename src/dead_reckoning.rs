@@ -0,0 +1,150 @@
+//! Dead reckoning — predicting the motion of remote objects between network
+//! updates.
+
+use crate::Fecc;
+
+/// Returns the position after `t` seconds of motion with constant
+/// acceleration.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{dead_reckoning, Fecc};
+///
+/// let position = dead_reckoning::extrapolate(
+///     Fecc::new(0.0, 10.0),
+///     Fecc::new(2.0, 0.0),
+///     Fecc::new(0.0, -10.0),
+///     1.0,
+/// );
+///
+/// assert_approx_eq!(f64, position.x, 2.0);
+/// assert_approx_eq!(f64, position.y, 5.0);
+/// ```
+pub fn extrapolate(
+    position: Fecc,
+    velocity: Fecc,
+    acceleration: Fecc,
+    t: f64,
+) -> Fecc {
+    position + velocity * t + acceleration * (t * t / 2.0)
+}
+
+/// Smooths the position of a remote object towards authoritative snapshots
+/// with projective velocity blending, as described by Curtiss Murphy in
+/// *Believable Dead Reckoning for Networked Games* (Game Engine Gems 2).
+///
+/// Between snapshots the object is extrapolated. When a snapshot arrives,
+/// the object doesn't jump to it — instead, over
+/// [`blend_time`](crate::dead_reckoning::DeadReckoner::blend_time) seconds,
+/// the extrapolation from the previously displayed state is blended into the
+/// extrapolation from the snapshot.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{dead_reckoning::DeadReckoner, Fecc};
+///
+/// let mut remote = DeadReckoner::new(Fecc::zero(), Fecc::new(1.0, 0.0), 0.5);
+///
+/// remote.step(1.0);
+/// assert_approx_eq!(f64, remote.position().x, 1.0);
+///
+/// // The server says the object has actually turned.
+/// remote.snapshot(Fecc::new(1.0, 0.0), Fecc::new(0.0, 1.0), Fecc::zero());
+///
+/// // Halfway through the blend.
+/// remote.step(0.25);
+/// assert!(remote.position().x > 1.0);
+///
+/// // After the blend it follows the snapshot exactly.
+/// remote.step(0.25);
+/// assert_approx_eq!(f64, remote.position().x, 1.0);
+/// assert_approx_eq!(f64, remote.position().y, 0.5);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DeadReckoner {
+    /// How long it takes to converge to a new snapshot, in seconds.
+    pub blend_time: f64,
+
+    /// The displayed state when the last snapshot arrived.
+    start: (Fecc, Fecc),
+
+    /// The last snapshot's position, velocity and acceleration.
+    snapshot: (Fecc, Fecc, Fecc),
+
+    /// The time since the last snapshot.
+    elapsed: f64,
+}
+
+impl DeadReckoner {
+    /// Constructs a new dead reckoner starting at the specified state.
+    pub fn new(position: Fecc, velocity: Fecc, blend_time: f64) -> Self {
+        Self {
+            blend_time,
+            start: (position, velocity),
+            snapshot: (position, velocity, Fecc::zero()),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Starts converging to a new authoritative state.
+    pub fn snapshot(
+        &mut self,
+        position: Fecc,
+        velocity: Fecc,
+        acceleration: Fecc,
+    ) {
+        self.start = (self.position(), self.velocity());
+        self.snapshot = (position, velocity, acceleration);
+        self.elapsed = 0.0;
+    }
+
+    /// Advances the time by `dt` seconds.
+    pub fn step(&mut self, dt: f64) {
+        self.elapsed += dt;
+    }
+
+    /// Returns the displayed position.
+    pub fn position(&self) -> Fecc {
+        let (start_position, _) = self.start;
+        let (position, velocity, acceleration) = self.snapshot;
+        let (t, blend) = (self.elapsed, self.blend());
+
+        let projected = extrapolate(
+            start_position,
+            self.blended_velocity(),
+            acceleration,
+            t,
+        );
+        let authoritative = extrapolate(position, velocity, acceleration, t);
+
+        projected.lerp(authoritative, blend)
+    }
+
+    /// Returns the displayed velocity.
+    pub fn velocity(&self) -> Fecc {
+        let (_, _, acceleration) = self.snapshot;
+
+        self.blended_velocity() + acceleration * self.elapsed
+    }
+
+    /// Returns the velocity blended from the displayed one to the snapshot's.
+    fn blended_velocity(&self) -> Fecc {
+        let (_, start_velocity) = self.start;
+        let (_, velocity, _) = self.snapshot;
+
+        start_velocity.lerp(velocity, self.blend())
+    }
+
+    /// Returns the progress of the blend, from `0.0` to `1.0`.
+    fn blend(&self) -> f64 {
+        if self.blend_time > 0.0 {
+            (self.elapsed / self.blend_time).min(1.0)
+        } else {
+            1.0
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! [Diffusion-limited aggregation](https://en.wikipedia.org/wiki/Diffusion-limited_aggregation)
+//! — a cluster growing from particles wandering randomly until they touch
+//! it.
+
+use std::f64::consts::TAU;
+
+use rand::Rng;
+
+use crate::{spatial::SpatialHash, Fecc};
+
+/// Growing cluster of particles.
+///
+/// Walkers are released one at a time from a circle around the cluster
+/// and wander randomly until they touch a particle of the cluster. Walkers
+/// straying too far are released again.
+///
+/// # Examples
+///
+/// ```
+/// use rand::{rngs::SmallRng, SeedableRng};
+/// use veccentric::{dla::Dla, Fecc};
+///
+/// let mut rng = SmallRng::from_seed([0xdd; 32]);
+/// let mut cluster = Dla::new(Fecc::zero(), 1.0).with_stickiness(0.5);
+///
+/// for _ in 0..100 {
+///     let particle = cluster.grow(&mut rng);
+///
+///     // Every particle touches an older one.
+///     let touching = cluster.points()[..cluster.points().len() - 1]
+///         .iter()
+///         .any(|other| other.dist(particle) <= 2.0);
+///     assert!(touching);
+/// }
+///
+/// assert_eq!(cluster.points().len(), 101);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Dla {
+    /// The radius of the particles.
+    pub particle_radius: f64,
+
+    /// The probability that a walker sticks to the cluster when touching
+    /// it. Lower values make the cluster denser. It must be positive,
+    /// otherwise [`grow`](crate::dla::Dla::grow) never returns.
+    pub stickiness: f64,
+
+    /// How far from the cluster the walkers are released.
+    pub spawn_margin: f64,
+
+    center: Fecc,
+    radius: f64,
+    points: Vec<Fecc>,
+    hash: SpatialHash,
+}
+
+impl Dla {
+    /// Constructs a new cluster made of a single particle. Walkers always
+    /// stick and are released `10` particle radii away from the cluster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `particle_radius` isn't positive.
+    pub fn new(seed: Fecc, particle_radius: f64) -> Self {
+        let mut hash = SpatialHash::new(2.0 * particle_radius);
+        hash.insert(0, seed);
+
+        Self {
+            particle_radius,
+            stickiness: 1.0,
+            spawn_margin: 10.0 * particle_radius,
+            center: seed,
+            radius: 0.0,
+            points: vec![seed],
+            hash,
+        }
+    }
+
+    /// Sets the probability that a walker sticks to the cluster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stickiness` isn't in the range `(0, 1]`, since walkers
+    /// would never stick with zero stickiness.
+    pub fn with_stickiness(mut self, stickiness: f64) -> Self {
+        assert!(
+            stickiness > 0.0 && stickiness <= 1.0,
+            "the stickiness must be in the range (0, 1]"
+        );
+
+        self.stickiness = stickiness;
+
+        self
+    }
+
+    /// Sets how far from the cluster the walkers are released.
+    pub fn with_spawn_margin(mut self, spawn_margin: f64) -> Self {
+        self.spawn_margin = spawn_margin;
+
+        self
+    }
+
+    /// Returns the particles in the order in which they joined the cluster.
+    pub fn points(&self) -> &[Fecc] {
+        &self.points
+    }
+
+    /// Returns the distance from the seed to the farthest particle.
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Releases walkers until one of them sticks to the cluster and returns
+    /// its position.
+    pub fn grow<R>(&mut self, rng: &mut R) -> Fecc
+    where
+        R: Rng,
+    {
+        let contact = 2.0 * self.particle_radius;
+        let spawn_radius = self.radius + contact + self.spawn_margin;
+        let spawn = |rng: &mut R| {
+            self.center
+                + Fecc::from_angle(rng.gen::<f64>() * TAU) * spawn_radius
+        };
+        let mut walker = spawn(rng);
+
+        loop {
+            let dist = walker.dist(self.center);
+
+            if dist > 2.0 * spawn_radius {
+                walker = spawn(rng);
+                continue;
+            }
+
+            if self.hash.query(walker, contact).next().is_some()
+                && rng.gen::<f64>() < self.stickiness
+            {
+                break;
+            }
+
+            // Far from the cluster the walker can take longer steps.
+            let step =
+                (dist - self.radius - 2.0 * contact).max(self.particle_radius);
+            walker += Fecc::from_angle(rng.gen::<f64>() * TAU) * step;
+        }
+
+        self.hash.insert(self.points.len(), walker);
+        self.points.push(walker);
+        self.radius = self.radius.max(walker.dist(self.center));
+
+        walker
+    }
+}
@@ -0,0 +1,261 @@
+//! Double-double precision vectors.
+//!
+//! A [`DoubleDouble`](crate::double_double::DoubleDouble) represents a
+//! number as the unevaluated sum of two [`f64`](f64)s, which gives about
+//! 106 bits of precision (roughly 32 decimal digits) instead of 53. It's
+//! several times slower than `f64`, but useful when the accumulated rounding
+//! error of `f64` becomes the limiting factor, e.g. in long-horizon orbital
+//! mechanics. The exponent range is the same as `f64`'s.
+//!
+//! [`DdFecc`](crate::double_double::DdFecc) supports the same operators as
+//! any other [`Vecc`](crate::vecc::Vecc) and converts to and from
+//! [`Fecc`](crate::fecc::Fecc).
+//!
+//! # Examples
+//!
+//! ```
+//! use veccentric::{double_double::DdFecc, Fecc};
+//!
+//! let step = DdFecc::from(Fecc::new(0.1, 0.0));
+//! let mut position = DdFecc::from(Fecc::new(1e9, 0.0));
+//! let mut naive = Fecc::new(1e9, 0.0);
+//!
+//! for _ in 0..1_000 {
+//!     position += step;
+//!     naive += Fecc::new(0.1, 0.0);
+//! }
+//!
+//! let position = Fecc::from(position - DdFecc::from(Fecc::new(1e9, 0.0)));
+//!
+//! assert!((position.x - 100.0).abs() < 1e-12);
+//! assert!((naive.x - 1e9 - 100.0).abs() > 1e-7);
+//! ```
+
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+use crate::{Fecc, Vecc};
+
+/// Vector with two [`DoubleDouble`](crate::double_double::DoubleDouble)
+/// components.
+pub type DdFecc = Vecc<DoubleDouble>;
+
+/// Number represented as the unevaluated sum of two non-overlapping
+/// [`f64`](f64)s.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Default, Debug)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+/// Returns the rounded sum and its rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Like [`two_sum`], but requires `|a| >= |b|`.
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+
+    (sum, b - (sum - a))
+}
+
+/// Returns the rounded product and its rounding error.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+
+    (product, a.mul_add(b, -product))
+}
+
+impl DoubleDouble {
+    /// Constructs a new number equal to `hi + lo`, computed exactly.
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+
+        Self { hi, lo }
+    }
+
+    /// Returns the leading part, i.e. the value rounded to [`f64`](f64).
+    pub fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the trailing part, i.e. the rounding error of
+    /// [`hi`](crate::double_double::DoubleDouble::hi).
+    pub fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Returns the value rounded to [`f64`](f64).
+    pub fn to_f64(&self) -> f64 {
+        self.hi + self.lo
+    }
+
+    /// Returns the square root.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::double_double::DoubleDouble;
+    ///
+    /// let two = DoubleDouble::from(2.0);
+    /// let root = two.sqrt();
+    /// let error = root * root - two;
+    ///
+    /// assert!(error.to_f64().abs() < 1e-30);
+    /// ```
+    pub fn sqrt(self) -> Self {
+        if self.hi <= 0.0 {
+            return Self::from(self.hi.sqrt());
+        }
+
+        // One Newton step on top of the `f64` estimate doubles the precision.
+        let root = self.hi.sqrt();
+        let (square, error) = two_product(root, root);
+        let remainder = ((self.hi - square) - error + self.lo) / (2.0 * root);
+        let (hi, lo) = quick_two_sum(root, remainder);
+
+        Self { hi, lo }
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(value: f64) -> Self {
+        Self { hi: value, lo: 0.0 }
+    }
+}
+
+impl From<Fecc> for DdFecc {
+    fn from(vector: Fecc) -> Self {
+        Vecc {
+            x: vector.x.into(),
+            y: vector.y.into(),
+        }
+    }
+}
+
+impl From<DdFecc> for Fecc {
+    fn from(vector: DdFecc) -> Self {
+        vector.to_fecc()
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn neg(self) -> DoubleDouble {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn add(self, rhs: DoubleDouble) -> DoubleDouble {
+        let (hi, lo) = two_sum(self.hi, rhs.hi);
+        let (lo_sum, lo_error) = two_sum(self.lo, rhs.lo);
+        let (hi, lo) = quick_two_sum(hi, lo + lo_sum);
+        let (hi, lo) = quick_two_sum(hi, lo + lo_error);
+
+        Self { hi, lo }
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn sub(self, rhs: DoubleDouble) -> DoubleDouble {
+        self + -rhs
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn mul(self, rhs: DoubleDouble) -> DoubleDouble {
+        let (hi, lo) = two_product(self.hi, rhs.hi);
+        let lo = lo + (self.hi * rhs.lo + self.lo * rhs.hi);
+        let (hi, lo) = quick_two_sum(hi, lo);
+
+        Self { hi, lo }
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = DoubleDouble;
+
+    fn div(self, rhs: DoubleDouble) -> DoubleDouble {
+        // Long division, one `f64` digit at a time.
+        let first = self.hi / rhs.hi;
+        let remainder = self - rhs * Self::from(first);
+        let second = remainder.hi / rhs.hi;
+        let remainder = remainder - rhs * Self::from(second);
+        let third = remainder.hi / rhs.hi;
+        let (hi, lo) = quick_two_sum(first, second);
+
+        Self { hi, lo } + Self::from(third)
+    }
+}
+
+impl AddAssign for DoubleDouble {
+    fn add_assign(&mut self, rhs: DoubleDouble) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for DoubleDouble {
+    fn sub_assign(&mut self, rhs: DoubleDouble) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for DoubleDouble {
+    fn mul_assign(&mut self, rhs: DoubleDouble) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for DoubleDouble {
+    fn div_assign(&mut self, rhs: DoubleDouble) {
+        *self = *self / rhs;
+    }
+}
+
+impl DdFecc {
+    /// Returns the squared magnitude of the vector.
+    pub fn mag_squared(&self) -> DoubleDouble {
+        self.dot(*self)
+    }
+
+    /// Returns the magnitude of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{double_double::DdFecc, Fecc};
+    ///
+    /// let a = DdFecc::from(Fecc::new(3.0, 4.0));
+    ///
+    /// assert_eq!(a.mag().to_f64(), 5.0);
+    /// ```
+    pub fn mag(&self) -> DoubleDouble {
+        self.mag_squared().sqrt()
+    }
+
+    /// Converts the vector to [`Fecc`](crate::fecc::Fecc), rounding the
+    /// components.
+    pub fn to_fecc(&self) -> Fecc {
+        Fecc {
+            x: self.x.to_f64(),
+            y: self.y.to_f64(),
+        }
+    }
+}
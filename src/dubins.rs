@@ -0,0 +1,336 @@
+//! Shortest paths for vehicles with a minimum turning radius.
+//!
+//! A [Dubins path](https://en.wikipedia.org/wiki/Dubins_path) is the shortest
+//! path between two poses (positions with headings) for a vehicle which can
+//! only move forward and can't turn tighter than a given radius. It always
+//! consists of at most three pieces — arcs of that radius and a straight
+//! segment.
+
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use crate::{Angle, Arc, Fecc, Polyline, Segment};
+
+/// Sweeps closer than this to a full turn are considered zero.
+const FULL_TURN_TOLERANCE: f64 = 1e-9;
+
+/// A piece of a [`Path`](crate::dubins::Path).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Piece {
+    /// Turning along an arc.
+    Arc(Arc),
+
+    /// Moving straight along a segment.
+    Straight(Segment),
+}
+
+impl Piece {
+    /// Returns the length of the piece.
+    pub fn length(&self) -> f64 {
+        match self {
+            Piece::Arc(arc) => arc.length(),
+            Piece::Straight(Segment(start, end)) => start.dist(*end),
+        }
+    }
+
+    /// Returns the point at the specified fraction of the piece's length.
+    pub fn point_at(&self, t: f64) -> Fecc {
+        match self {
+            Piece::Arc(arc) => arc.point_at(t),
+            Piece::Straight(Segment(start, end)) => start.lerp(*end, t),
+        }
+    }
+
+    /// Returns the unit tangent at the specified fraction of the piece's
+    /// length.
+    pub fn tangent_at(&self, t: f64) -> Fecc {
+        match self {
+            Piece::Arc(arc) => arc.tangent_at(t),
+            Piece::Straight(Segment(start, end)) => (*end - *start).normalize(),
+        }
+    }
+}
+
+/// Path made of arcs and straight segments, returned by
+/// [`dubins_path`](crate::dubins::dubins_path).
+///
+/// Like [`Polyline`](crate::polyline::Polyline)'s, the parametric methods
+/// are parametrized by arc length.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct Path {
+    /// The pieces in the order in which they're traversed.
+    pub pieces: Vec<Piece>,
+}
+
+impl Path {
+    /// Returns the total length of the path.
+    pub fn length(&self) -> f64 {
+        self.pieces.iter().map(Piece::length).sum()
+    }
+
+    /// Returns the point at the specified fraction of the path's length. `t`
+    /// is clamped to `[0.0, 1.0]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path has no pieces.
+    pub fn point_at(&self, t: f64) -> Fecc {
+        let (piece, t) = self.locate(t);
+
+        piece.point_at(t)
+    }
+
+    /// Returns the unit tangent (the heading of the vehicle) at the specified
+    /// fraction of the path's length. `t` is clamped to `[0.0, 1.0]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path has no pieces.
+    pub fn tangent_at(&self, t: f64) -> Fecc {
+        let (piece, t) = self.locate(t);
+
+        piece.tangent_at(t)
+    }
+
+    /// Samples `n` points evenly spaced along the path (including both
+    /// ends).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path has no pieces.
+    pub fn to_polyline(&self, n: usize) -> Polyline {
+        match n {
+            0 => Polyline(vec![]),
+            1 => Polyline(vec![self.point_at(0.0)]),
+            _ => (0..n)
+                .map(|i| self.point_at(i as f64 / (n - 1) as f64))
+                .collect(),
+        }
+    }
+
+    /// Returns the piece containing the point at the specified fraction of
+    /// the path's length and the fraction of that piece's length.
+    fn locate(&self, t: f64) -> (&Piece, f64) {
+        assert!(!self.pieces.is_empty(), "the path has no pieces");
+
+        let mut remaining = t.clamp(0.0, 1.0) * self.length();
+
+        for piece in &self.pieces {
+            let length = piece.length();
+
+            if remaining <= length && length > 0.0 {
+                return (piece, remaining / length);
+            }
+
+            remaining -= length;
+        }
+
+        (self.pieces.last().unwrap(), 1.0)
+    }
+}
+
+/// A vehicle's pose.
+#[derive(Copy, Clone)]
+struct Pose {
+    position: Fecc,
+    heading: f64,
+}
+
+impl Pose {
+    /// Returns the center of the turning circle. `direction` is `1.0` for
+    /// turning left and `-1.0` for turning right.
+    fn circle(&self, direction: f64, radius: f64) -> Fecc {
+        self.position
+            + Fecc::from_angle(self.heading + direction * FRAC_PI_2) * radius
+    }
+}
+
+/// Returns the angle by which a vehicle turning in `direction` has to turn
+/// to change its heading from `from` to `to`.
+fn sweep(from: f64, to: f64, direction: f64) -> f64 {
+    let sweep = (direction * (to - from)).rem_euclid(TAU);
+
+    if TAU - sweep < FULL_TURN_TOLERANCE {
+        0.0
+    } else {
+        direction * sweep
+    }
+}
+
+/// Returns the arc turning around `center` from `point`, with the heading
+/// changing from `from` to `to`.
+fn arc(
+    center: Fecc,
+    radius: f64,
+    point: Fecc,
+    (from, to): (f64, f64),
+    direction: f64,
+) -> Piece {
+    Piece::Arc(Arc {
+        center,
+        radius,
+        start: Angle::from((point - center).angle()),
+        sweep: Angle::from(sweep(from, to, direction)),
+    })
+}
+
+/// Turn, straight, turn.
+fn csc(
+    start: Pose,
+    end: Pose,
+    radius: f64,
+    (first, last): (f64, f64),
+) -> Option<Path> {
+    let (c1, c2) = (start.circle(first, radius), end.circle(last, radius));
+    let offset = c2 - c1;
+
+    // Same circle — a single turn.
+    if offset.is_zero() {
+        return (first == last).then(|| Path {
+            pieces: vec![arc(
+                c1,
+                radius,
+                start.position,
+                (start.heading, end.heading),
+                first,
+            )],
+        });
+    }
+
+    let heading = if first == last {
+        offset.angle()
+    } else {
+        let dist = offset.mag();
+
+        if dist < 2.0 * radius {
+            return None;
+        }
+
+        let straight = (dist * dist - 4.0 * radius * radius).sqrt();
+
+        offset.angle() + first * (2.0 * radius).atan2(straight)
+    };
+
+    let t1 = c1 + Fecc::from_angle(heading - first * FRAC_PI_2) * radius;
+    let t2 = c2 + Fecc::from_angle(heading - last * FRAC_PI_2) * radius;
+
+    Some(Path {
+        pieces: vec![
+            arc(c1, radius, start.position, (start.heading, heading), first),
+            Piece::Straight(Segment(t1, t2)),
+            arc(c2, radius, t2, (heading, end.heading), last),
+        ],
+    })
+}
+
+/// Turn, turn the other way, turn. `side` picks one of the two possible
+/// middle circles.
+fn ccc(
+    start: Pose,
+    end: Pose,
+    radius: f64,
+    direction: f64,
+    side: f64,
+) -> Option<Path> {
+    let (c1, c2) = (
+        start.circle(direction, radius),
+        end.circle(direction, radius),
+    );
+    let offset = c2 - c1;
+    let dist = offset.mag();
+
+    if offset.is_zero() || dist > 4.0 * radius {
+        return None;
+    }
+
+    let height = (4.0 * radius * radius - dist * dist / 4.0).sqrt();
+    let c3 =
+        (c1 + c2) / 2.0 + Fecc::new(-offset.y, offset.x) / dist * height * side;
+    let (p1, p2) = ((c1 + c3) / 2.0, (c2 + c3) / 2.0);
+
+    let heading_at = |point: Fecc, center: Fecc, direction: f64| {
+        (point - center).angle() + direction * FRAC_PI_2
+    };
+    let h1 = heading_at(p1, c1, direction);
+    let h2 = heading_at(p2, c2, direction);
+
+    Some(Path {
+        pieces: vec![
+            arc(c1, radius, start.position, (start.heading, h1), direction),
+            arc(c3, radius, p1, (h1, h2), -direction),
+            arc(c2, radius, p2, (h2, end.heading), direction),
+        ],
+    })
+}
+
+/// Returns the shortest path from `start` (heading in direction
+/// `start_heading`) to `end` (heading in direction `end_heading`) for a
+/// vehicle moving forward with the minimum turning radius `radius`.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// # use std::f64::consts::PI;
+/// use veccentric::{dubins, Angular, Fecc};
+///
+/// // A U-turn.
+/// let path = dubins::dubins_path(
+///     Fecc::new(0.0, 0.0),
+///     0.deg(),
+///     Fecc::new(0.0, 2.0),
+///     180.deg(),
+///     1.0,
+/// );
+///
+/// assert_approx_eq!(f64, path.length(), PI);
+///
+/// let end = path.point_at(1.0);
+/// assert_approx_eq!(f64, end.x, 0.0, epsilon = 1e-9);
+/// assert_approx_eq!(f64, end.y, 2.0);
+///
+/// let heading = path.tangent_at(1.0);
+/// assert_approx_eq!(f64, heading.x, -1.0);
+///
+/// let polyline = path.to_polyline(20);
+/// assert_eq!(polyline.0.len(), 20);
+/// ```
+pub fn dubins_path<A, B>(
+    start: Fecc,
+    start_heading: A,
+    end: Fecc,
+    end_heading: B,
+    radius: f64,
+) -> Path
+where
+    A: Into<Angle>,
+    B: Into<Angle>,
+{
+    let start = Pose {
+        position: start,
+        heading: *start_heading.into(),
+    };
+    let end = Pose {
+        position: end,
+        heading: *end_heading.into(),
+    };
+    let (left, right) = (1.0, -1.0);
+
+    let candidates = [
+        csc(start, end, radius, (left, left)),
+        csc(start, end, radius, (right, right)),
+        csc(start, end, radius, (left, right)),
+        csc(start, end, radius, (right, left)),
+        ccc(start, end, radius, left, left),
+        ccc(start, end, radius, left, right),
+        ccc(start, end, radius, right, left),
+        ccc(start, end, radius, right, right),
+    ];
+
+    // There's always at least one outer tangent.
+    candidates
+        .iter()
+        .flatten()
+        .min_by(|a, b| a.length().total_cmp(&b.length()))
+        .cloned()
+        .unwrap()
+}
@@ -0,0 +1,296 @@
+//! Conserved quantities of systems of point masses.
+//!
+//! The functions accept any iterator of bodies convertible into
+//! `(position, velocity, mass)` tuples, including
+//! [`Body2`](crate::body::Body2).
+//!
+//! # Examples
+//!
+//! Checking that momentum is conserved.
+//!
+//! ```
+//! # use float_cmp::assert_approx_eq;
+//! use veccentric::{dynamics, Body2, Fecc};
+//!
+//! let mut a = Body2::new(Fecc::new(0.0, 0.0), 1.0);
+//! let mut b = Body2::new(Fecc::new(10.0, 0.0), 3.0);
+//! a.velocity = Fecc::new(3.0, 0.0);
+//!
+//! let before = dynamics::momentum(vec![a, b]);
+//!
+//! // Equal and opposite forces.
+//! let force = Fecc::new(2.0, 1.0);
+//! a.apply_force(force, 0.1);
+//! b.apply_force(-force, 0.1);
+//!
+//! let after = dynamics::momentum(vec![a, b]);
+//!
+//! assert_approx_eq!(f64, before.x, after.x);
+//! assert_approx_eq!(f64, before.y, after.y);
+//! ```
+
+use crate::{Body2, Fecc};
+
+impl From<Body2> for (Fecc, Fecc, f64) {
+    fn from(body: Body2) -> Self {
+        (body.position, body.velocity, body.mass)
+    }
+}
+
+/// Returns the total momentum, `Σ m v`.
+pub fn momentum<I>(bodies: I) -> Fecc
+where
+    I: IntoIterator,
+    I::Item: Into<(Fecc, Fecc, f64)>,
+{
+    bodies
+        .into_iter()
+        .map(Into::into)
+        .fold(Fecc::zero(), |sum, (_, velocity, mass)| {
+            sum + velocity * mass
+        })
+}
+
+/// Returns the center of mass or `None` if the total mass is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{dynamics, Fecc};
+///
+/// let bodies = [
+///     (Fecc::new(0.0, 0.0), Fecc::zero(), 3.0),
+///     (Fecc::new(4.0, 0.0), Fecc::zero(), 1.0),
+/// ];
+/// let center = dynamics::center_of_mass(bodies.iter().copied()).unwrap();
+///
+/// assert_approx_eq!(f64, center.x, 1.0);
+/// ```
+pub fn center_of_mass<I>(bodies: I) -> Option<Fecc>
+where
+    I: IntoIterator,
+    I::Item: Into<(Fecc, Fecc, f64)>,
+{
+    let (sum, total) = bodies.into_iter().map(Into::into).fold(
+        (Fecc::zero(), 0.0),
+        |(sum, total), (position, _, mass)| {
+            (sum + position * mass, total + mass)
+        },
+    );
+
+    (total != 0.0).then(|| sum / total)
+}
+
+/// Returns the velocity of the center of mass or `None` if the total mass
+/// is zero.
+pub fn center_of_mass_velocity<I>(bodies: I) -> Option<Fecc>
+where
+    I: IntoIterator,
+    I::Item: Into<(Fecc, Fecc, f64)>,
+{
+    let (sum, total) = bodies.into_iter().map(Into::into).fold(
+        (Fecc::zero(), 0.0),
+        |(sum, total), (_, velocity, mass)| {
+            (sum + velocity * mass, total + mass)
+        },
+    );
+
+    (total != 0.0).then(|| sum / total)
+}
+
+/// Returns the total angular momentum about `point`, `Σ m (r - point) × v`.
+/// Positive values mean counterclockwise rotation.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{dynamics, Fecc};
+///
+/// // Moving counterclockwise around the origin.
+/// let body = (Fecc::new(2.0, 0.0), Fecc::new(0.0, 3.0), 1.5);
+///
+/// let l = dynamics::angular_momentum(vec![body], Fecc::zero());
+///
+/// assert_approx_eq!(f64, l, 9.0);
+/// ```
+pub fn angular_momentum<I>(bodies: I, point: Fecc) -> f64
+where
+    I: IntoIterator,
+    I::Item: Into<(Fecc, Fecc, f64)>,
+{
+    bodies
+        .into_iter()
+        .map(Into::into)
+        .map(|(position, velocity, mass)| {
+            (position - point).cross(velocity) * mass
+        })
+        .sum()
+}
+
+/// Returns the kinetic energy of a body, `m |v|² / 2`.
+pub fn kinetic_energy(velocity: Fecc, mass: f64) -> f64 {
+    mass * velocity.mag_squared() / 2.0
+}
+
+/// Returns the gravitational potential energy of the system,
+/// `-Σ g mᵢ mⱼ / rᵢⱼ` summed over all pairs. Coincident bodies are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{dynamics, Fecc};
+///
+/// let bodies = [
+///     (Fecc::new(0.0, 0.0), Fecc::zero(), 2.0),
+///     (Fecc::new(4.0, 0.0), Fecc::zero(), 3.0),
+/// ];
+/// let potential =
+///     dynamics::gravitational_potential(bodies.iter().copied(), 2.0);
+///
+/// assert_approx_eq!(f64, potential, -3.0);
+/// ```
+pub fn gravitational_potential<I>(bodies: I, g: f64) -> f64
+where
+    I: IntoIterator,
+    I::Item: Into<(Fecc, Fecc, f64)>,
+{
+    let bodies: Vec<(Fecc, Fecc, f64)> =
+        bodies.into_iter().map(Into::into).collect();
+    let mut potential = 0.0;
+
+    for (i, &(a, _, m_a)) in bodies.iter().enumerate() {
+        for &(b, _, m_b) in &bodies[(i + 1)..] {
+            let dist = a.dist(b);
+
+            if dist > 0.0 {
+                potential -= g * m_a * m_b / dist;
+            }
+        }
+    }
+
+    potential
+}
+
+/// Returns the sum of the kinetic energies of the bodies and their
+/// gravitational potential energy.
+pub fn total_energy<I>(bodies: I, g: f64) -> f64
+where
+    I: IntoIterator,
+    I::Item: Into<(Fecc, Fecc, f64)>,
+{
+    let bodies: Vec<(Fecc, Fecc, f64)> =
+        bodies.into_iter().map(Into::into).collect();
+    let kinetic: f64 = bodies
+        .iter()
+        .map(|&(_, velocity, mass)| kinetic_energy(velocity, mass))
+        .sum();
+
+    kinetic + gravitational_potential(bodies, g)
+}
+
+/// Tracks how the energy of a simulation drifts from its initial value.
+///
+/// Sample the energy after every step, e.g. with
+/// [`total_energy`](crate::dynamics::total_energy).
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{
+///     dynamics::{self, Diagnostics},
+///     force::{self, Falloff},
+///     Body2, Fecc,
+/// };
+///
+/// let mut sun = Body2::new(Fecc::zero(), 1000.0);
+/// let mut earth = Body2::new(Fecc::new(10.0, 0.0), 1.0);
+/// earth.velocity = Fecc::new(0.0, 10.0);
+///
+/// let mut diagnostics = Diagnostics::new();
+///
+/// for _ in 0..1000 {
+///     let gravity = force::attract(
+///         earth.position,
+///         sun.position,
+///         sun.mass * earth.mass,
+///         Falloff::InverseSquare { softening: 0.0 },
+///     );
+///     earth.apply_force(gravity, 0.001);
+///     sun.apply_force(-gravity, 0.001);
+///     earth.step(0.001);
+///     sun.step(0.001);
+///
+///     diagnostics.sample(dynamics::total_energy(vec![sun, earth], 1.0));
+/// }
+///
+/// assert_eq!(diagnostics.samples(), 1000);
+/// assert!(diagnostics.max_relative_drift() < 1e-3);
+/// ```
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Diagnostics {
+    initial: Option<f64>,
+    last: f64,
+    max_drift: f64,
+    samples: usize,
+}
+
+impl Diagnostics {
+    /// Constructs a new accumulator without samples.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the energy at the current step. The first sample is the
+    /// reference value.
+    pub fn sample(&mut self, energy: f64) {
+        let initial = *self.initial.get_or_insert(energy);
+
+        self.last = energy;
+        self.max_drift = self.max_drift.max((energy - initial).abs());
+        self.samples += 1;
+    }
+
+    /// Returns the number of samples.
+    pub fn samples(&self) -> usize {
+        self.samples
+    }
+
+    /// Returns the first sample or `None` if there are no samples.
+    pub fn initial(&self) -> Option<f64> {
+        self.initial
+    }
+
+    /// Returns the difference between the last and the first sample.
+    pub fn drift(&self) -> f64 {
+        self.initial.map_or(0.0, |initial| self.last - initial)
+    }
+
+    /// Returns the largest absolute difference between any sample and the
+    /// first one.
+    pub fn max_drift(&self) -> f64 {
+        self.max_drift
+    }
+
+    /// Returns [`drift`](crate::dynamics::Diagnostics::drift) relative to
+    /// the magnitude of the first sample. If the first sample is zero, the
+    /// absolute drift is returned.
+    pub fn relative_drift(&self) -> f64 {
+        self.relative(self.drift())
+    }
+
+    /// Returns [`max_drift`](crate::dynamics::Diagnostics::max_drift)
+    /// relative to the magnitude of the first sample.
+    pub fn max_relative_drift(&self) -> f64 {
+        self.relative(self.max_drift)
+    }
+
+    fn relative(&self, drift: f64) -> f64 {
+        match self.initial {
+            Some(initial) if initial != 0.0 => drift / initial.abs(),
+            _ => drift,
+        }
+    }
+}
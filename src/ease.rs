@@ -0,0 +1,270 @@
+//! Easing functions.
+//!
+//! Each function maps `t` in `[0.0, 1.0]` to the eased progress, with `0.0`
+//! mapped to `0.0` and `1.0` mapped to `1.0`. Some of them (`back`,
+//! `elastic`) overshoot in between. The formulas follow
+//! [easings.net](https://easings.net/).
+//!
+//! # Examples
+//!
+//! Easing the motion between two points.
+//!
+//! ```
+//! # use float_cmp::assert_approx_eq;
+//! use veccentric::{ease, Fecc};
+//!
+//! let a = Fecc::new(0.0, 0.0);
+//! let b = Fecc::new(10.0, 0.0);
+//!
+//! let start = a.lerp(b, ease::in_out_cubic(0.1));
+//! let middle = a.lerp(b, ease::in_out_cubic(0.5));
+//!
+//! assert!(start.x < 1.0);
+//! assert_approx_eq!(f64, middle.x, 5.0);
+//! ```
+
+use std::f64::consts::PI;
+
+/// No easing.
+pub fn linear(t: f64) -> f64 {
+    t
+}
+
+/// Quadratic ease-in.
+pub fn in_quad(t: f64) -> f64 {
+    t * t
+}
+
+/// Quadratic ease-out.
+pub fn out_quad(t: f64) -> f64 {
+    1.0 - (1.0 - t) * (1.0 - t)
+}
+
+/// Quadratic ease-in-out.
+pub fn in_out_quad(t: f64) -> f64 {
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+    }
+}
+
+/// Cubic ease-in.
+pub fn in_cubic(t: f64) -> f64 {
+    t * t * t
+}
+
+/// Cubic ease-out.
+pub fn out_cubic(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(3)
+}
+
+/// Cubic ease-in-out.
+pub fn in_out_cubic(t: f64) -> f64 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+/// Quartic ease-in.
+pub fn in_quart(t: f64) -> f64 {
+    t.powi(4)
+}
+
+/// Quartic ease-out.
+pub fn out_quart(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(4)
+}
+
+/// Quartic ease-in-out.
+pub fn in_out_quart(t: f64) -> f64 {
+    if t < 0.5 {
+        8.0 * t.powi(4)
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(4) / 2.0
+    }
+}
+
+/// Sinusoidal ease-in.
+pub fn in_sine(t: f64) -> f64 {
+    1.0 - (t * PI / 2.0).cos()
+}
+
+/// Sinusoidal ease-out.
+pub fn out_sine(t: f64) -> f64 {
+    (t * PI / 2.0).sin()
+}
+
+/// Sinusoidal ease-in-out.
+pub fn in_out_sine(t: f64) -> f64 {
+    -((PI * t).cos() - 1.0) / 2.0
+}
+
+/// Exponential ease-in.
+pub fn in_expo(t: f64) -> f64 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        2.0_f64.powf(10.0 * t - 10.0)
+    }
+}
+
+/// Exponential ease-out.
+pub fn out_expo(t: f64) -> f64 {
+    if t >= 1.0 {
+        1.0
+    } else {
+        1.0 - 2.0_f64.powf(-10.0 * t)
+    }
+}
+
+/// Exponential ease-in-out.
+pub fn in_out_expo(t: f64) -> f64 {
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else if t < 0.5 {
+        2.0_f64.powf(20.0 * t - 10.0) / 2.0
+    } else {
+        (2.0 - 2.0_f64.powf(-20.0 * t + 10.0)) / 2.0
+    }
+}
+
+/// Circular ease-in.
+pub fn in_circ(t: f64) -> f64 {
+    1.0 - (1.0 - t * t).sqrt()
+}
+
+/// Circular ease-out.
+pub fn out_circ(t: f64) -> f64 {
+    (1.0 - (t - 1.0).powi(2)).sqrt()
+}
+
+/// Circular ease-in-out.
+pub fn in_out_circ(t: f64) -> f64 {
+    if t < 0.5 {
+        (1.0 - (1.0 - (2.0 * t).powi(2)).sqrt()) / 2.0
+    } else {
+        ((1.0 - (-2.0 * t + 2.0).powi(2)).sqrt() + 1.0) / 2.0
+    }
+}
+
+const BACK_C1: f64 = 1.70158;
+const BACK_C2: f64 = BACK_C1 * 1.525;
+const BACK_C3: f64 = BACK_C1 + 1.0;
+
+/// Ease-in which backs off slightly before moving forward.
+pub fn in_back(t: f64) -> f64 {
+    BACK_C3 * t * t * t - BACK_C1 * t * t
+}
+
+/// Ease-out which overshoots slightly before settling.
+pub fn out_back(t: f64) -> f64 {
+    1.0 + BACK_C3 * (t - 1.0).powi(3) + BACK_C1 * (t - 1.0).powi(2)
+}
+
+/// Combination of [`in_back`](crate::ease::in_back) and
+/// [`out_back`](crate::ease::out_back).
+pub fn in_out_back(t: f64) -> f64 {
+    if t < 0.5 {
+        (2.0 * t).powi(2) * ((BACK_C2 + 1.0) * 2.0 * t - BACK_C2) / 2.0
+    } else {
+        ((2.0 * t - 2.0).powi(2) * ((BACK_C2 + 1.0) * (t * 2.0 - 2.0) + BACK_C2)
+            + 2.0)
+            / 2.0
+    }
+}
+
+/// Ease-in oscillating like a spring.
+pub fn in_elastic(t: f64) -> f64 {
+    const C4: f64 = 2.0 * PI / 3.0;
+
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else {
+        -(2.0_f64.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * C4).sin()
+    }
+}
+
+/// Ease-out oscillating like a spring.
+pub fn out_elastic(t: f64) -> f64 {
+    const C4: f64 = 2.0 * PI / 3.0;
+
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else {
+        2.0_f64.powf(-10.0 * t) * ((t * 10.0 - 0.75) * C4).sin() + 1.0
+    }
+}
+
+/// Combination of [`in_elastic`](crate::ease::in_elastic) and
+/// [`out_elastic`](crate::ease::out_elastic).
+pub fn in_out_elastic(t: f64) -> f64 {
+    const C5: f64 = 2.0 * PI / 4.5;
+
+    if t <= 0.0 {
+        0.0
+    } else if t >= 1.0 {
+        1.0
+    } else if t < 0.5 {
+        -(2.0_f64.powf(20.0 * t - 10.0) * ((20.0 * t - 11.125) * C5).sin())
+            / 2.0
+    } else {
+        2.0_f64.powf(-20.0 * t + 10.0) * ((20.0 * t - 11.125) * C5).sin() / 2.0
+            + 1.0
+    }
+}
+
+/// Ease-in bouncing off the start.
+pub fn in_bounce(t: f64) -> f64 {
+    1.0 - out_bounce(1.0 - t)
+}
+
+/// Ease-out bouncing off the end.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::ease;
+///
+/// assert_approx_eq!(f64, ease::out_bounce(0.0), 0.0);
+/// assert_approx_eq!(f64, ease::out_bounce(1.0), 1.0);
+/// ```
+pub fn out_bounce(t: f64) -> f64 {
+    const N1: f64 = 7.5625;
+    const D1: f64 = 2.75;
+
+    if t < 1.0 / D1 {
+        N1 * t * t
+    } else if t < 2.0 / D1 {
+        let t = t - 1.5 / D1;
+
+        N1 * t * t + 0.75
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+
+        N1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D1;
+
+        N1 * t * t + 0.984375
+    }
+}
+
+/// Combination of [`in_bounce`](crate::ease::in_bounce) and
+/// [`out_bounce`](crate::ease::out_bounce).
+pub fn in_out_bounce(t: f64) -> f64 {
+    if t < 0.5 {
+        (1.0 - out_bounce(1.0 - 2.0 * t)) / 2.0
+    } else {
+        (1.0 + out_bounce(2.0 * t - 1.0)) / 2.0
+    }
+}
@@ -0,0 +1,246 @@
+//! Minimal pixel engine used by the examples.
+//!
+//! It opens a window with a [`WIDTH`](crate::engine::WIDTH) ×
+//! [`HEIGHT`](crate::engine::HEIGHT) canvas and repeatedly calls the user's
+//! `update` and `draw` callbacks. [`Buffer`](crate::engine::Buffer) takes
+//! [`Fecc`](crate::fecc::Fecc) positions directly, so simulation code doesn't
+//! have to convert them to pixel indices by hand.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use veccentric::{
+//!     engine::{self, Buffer, Color},
+//!     Fecc,
+//! };
+//!
+//! struct State {
+//!     position: Fecc,
+//! }
+//!
+//! fn main() -> Result<(), engine::Error> {
+//!     let state = State {
+//!         position: Fecc::new(10.0, 10.0),
+//!     };
+//!
+//!     let update = |state: &mut State, dt: f64| {
+//!         state.position += Fecc::new(1.0, 1.0) * dt;
+//!     };
+//!     let draw = |state: &State, buffer: &mut Buffer| {
+//!         buffer.draw_point(state.position, Color::white());
+//!     };
+//!
+//!     engine::run(state, update, draw, Color::black())
+//! }
+//! ```
+
+use std::time::Instant;
+
+use pixels::{Pixels, SurfaceTexture};
+use winit::{
+    dpi::LogicalSize,
+    event::{Event, VirtualKeyCode},
+    event_loop::{ControlFlow, EventLoop},
+    window::WindowBuilder,
+};
+use winit_input_helper::WinitInputHelper;
+
+use crate::{pixel, raster, Aabb, Fecc, Vecc};
+
+pub use pixels::Error;
+
+/// Width of the canvas in pixels.
+pub const WIDTH: u32 = 64;
+
+/// Height of the canvas in pixels.
+pub const HEIGHT: u32 = 64;
+
+const SCALE: f64 = 5.0;
+
+/// RGB color.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    /// Red.
+    pub fn red() -> Self {
+        Color(0xff, 0x00, 0x00)
+    }
+
+    /// Green.
+    pub fn green() -> Self {
+        Color(0x00, 0xff, 0x00)
+    }
+
+    /// Blue.
+    pub fn blue() -> Self {
+        Color(0x00, 0x00, 0xff)
+    }
+
+    /// White.
+    pub fn white() -> Self {
+        Color(0xff, 0xff, 0xff)
+    }
+
+    /// Black.
+    pub fn black() -> Self {
+        Color(0x00, 0x00, 0x00)
+    }
+}
+
+/// Opens a window and runs the main loop.
+///
+/// On every frame `update` is called with the time elapsed since the previous
+/// frame (in seconds), then the canvas is cleared with `background` and
+/// `draw` is called. The loop exits when the window is closed or `Escape` is
+/// pressed.
+pub fn run<S, U, D>(
+    mut state: S,
+    mut update: U,
+    mut draw: D,
+    background: Color,
+) -> Result<(), Error>
+where
+    S: 'static,
+    U: FnMut(&mut S, f64) + 'static,
+    D: FnMut(&S, &mut Buffer) + 'static,
+{
+    let event_loop = EventLoop::new();
+    let mut input = WinitInputHelper::new();
+
+    let window = {
+        let size =
+            LogicalSize::new(WIDTH as f64 * SCALE, HEIGHT as f64 * SCALE);
+
+        WindowBuilder::new()
+            .with_title(get_exec_name())
+            .with_inner_size(size)
+            .with_min_inner_size(size)
+            .build(&event_loop)
+            .unwrap()
+    };
+
+    let mut pixels = {
+        let window_size = window.inner_size();
+        let surface_texture =
+            SurfaceTexture::new(window_size.width, window_size.height, &window);
+
+        Pixels::new(WIDTH, HEIGHT, surface_texture)?
+    };
+    let mut dt = Instant::now();
+
+    event_loop.run(move |event, _, control_flow| {
+        if let Event::RedrawRequested(_) = event {
+            let mut buffer = Buffer::new(pixels.get_frame());
+            update(&mut state, dt.elapsed().as_secs_f64());
+            buffer.clear(background);
+            draw(&state, &mut buffer);
+            dt = Instant::now();
+
+            if pixels.render().is_err() {
+                *control_flow = ControlFlow::Exit;
+
+                return;
+            }
+        }
+
+        if input.update(&event) {
+            if input.key_pressed(VirtualKeyCode::Escape) || input.quit() {
+                *control_flow = ControlFlow::Exit;
+
+                return;
+            }
+
+            if let Some(size) = input.window_resized() {
+                pixels.resize_surface(size.width, size.height);
+            }
+
+            window.request_redraw();
+        }
+    });
+}
+
+/// Canvas passed to the `draw` callback.
+///
+/// Pixel `(x, y)` covers the area `[x, x + 1) × [y, y + 1)`, so positions are
+/// [`floor`](crate::fecc::Fecc::floor)ed before drawing. Anything outside the
+/// canvas is silently skipped.
+pub struct Buffer<'a> {
+    pixels: &'a mut [u8],
+}
+
+impl<'a> Buffer<'a> {
+    fn new(pixels: &'a mut [u8]) -> Self {
+        Self { pixels }
+    }
+
+    /// Colors the pixel containing `position`.
+    pub fn draw_point(&mut self, position: Fecc, color: Color) {
+        self.put_pixel(position.floor(), color);
+    }
+
+    /// Draws a line segment between two points.
+    pub fn draw_line(&mut self, start: Fecc, end: Fecc, color: Color) {
+        for pixel in raster::line(start.floor(), end.floor()) {
+            self.put_pixel(pixel, color);
+        }
+    }
+
+    /// Draws the outline of a circle.
+    pub fn draw_circle(&mut self, center: Fecc, radius: f64, color: Color) {
+        for pixel in raster::circle(center.floor(), radius.round() as i64) {
+            self.put_pixel(pixel, color);
+        }
+    }
+
+    /// Draws the outline of a box.
+    pub fn draw_rect(&mut self, aabb: Aabb, color: Color) {
+        let corners = aabb.corners();
+
+        for (i, &corner) in corners.iter().enumerate() {
+            self.draw_line(corner, corners[(i + 1) % 4], color);
+        }
+    }
+
+    /// Draws an arrow representing `vector` with its tail at `origin`.
+    pub fn draw_arrow(&mut self, origin: Fecc, vector: Fecc, color: Color) {
+        const HEAD_ANGLE: f64 = 5.0 * std::f64::consts::PI / 6.0;
+
+        let tip = origin + vector;
+
+        self.draw_line(origin, tip, color);
+
+        if !vector.is_zero() {
+            // The head is a third of the arrow's length, but at most 3 px.
+            let head = vector.resize(vector.mag().min(9.0) / 3.0);
+
+            self.draw_line(tip, tip + head.rotate(HEAD_ANGLE), color);
+            self.draw_line(tip, tip + head.rotate(-HEAD_ANGLE), color);
+        }
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn put_pixel(&mut self, pixel: Vecc<i64>, Color(r, g, b): Color) {
+        if let Some(ix) = Self::ix(pixel) {
+            self.pixels[ix..(ix + 4)].copy_from_slice(&[r, g, b, 0xff]);
+        }
+    }
+
+    fn clear(&mut self, Color(r, g, b): Color) {
+        for pixel in self.pixels.chunks_exact_mut(4) {
+            pixel.copy_from_slice(&[r, g, b, 0xff]);
+        }
+    }
+
+    fn ix(pixel: Vecc<i64>) -> Option<usize> {
+        pixel::index(pixel, WIDTH as usize, HEIGHT as usize).map(|ix| ix * 4)
+    }
+}
+
+fn get_exec_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|pb| pb.file_name().map(|s| s.to_os_string()))
+        .and_then(|s| s.into_string().ok())
+        .unwrap_or_else(|| "veccentric".to_string())
+}
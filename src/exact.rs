@@ -0,0 +1,110 @@
+//! Exact geometric predicates.
+//!
+//! Every finite [`f64`](f64) is a rational number, so the predicates in
+//! this module convert the coordinates to arbitrary-precision rationals and
+//! compute the result without any rounding. They're much slower than the
+//! float versions, but always consistent — e.g. three points can't be
+//! reported as both collinear and not collinear depending on their order.
+//!
+//! # Panics
+//!
+//! The predicates panic if any coordinate is infinite or NaN.
+
+use num_rational::BigRational;
+use num_traits::Signed;
+
+use crate::{Fecc, Segment};
+
+/// The side of a directed line a point lies on.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Side {
+    /// The point lies to the left, i.e. the points make a counterclockwise
+    /// turn.
+    Left,
+
+    /// The point lies to the right, i.e. the points make a clockwise turn.
+    Right,
+
+    /// The point lies on the line.
+    On,
+}
+
+fn rational(value: f64) -> BigRational {
+    BigRational::from_float(value).expect("the coordinate is not finite")
+}
+
+/// Returns the side of the directed line from `a` to `b` which `point` lies
+/// on.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{
+///     exact::{self, Side},
+///     Fecc,
+/// };
+///
+/// let a = Fecc::new(0.1, 0.3);
+/// let b = Fecc::new(0.7, 2.1);
+/// let point = Fecc::new(0.3, 0.9);
+///
+/// // The rounded cross product isn't zero...
+/// assert_ne!((b - a).cross(point - a), 0.0);
+///
+/// // ...but the points are exactly collinear.
+/// assert_eq!(exact::side_of(a, b, point), Side::On);
+/// ```
+pub fn side_of(a: Fecc, b: Fecc, point: Fecc) -> Side {
+    let (ax, ay) = (rational(a.x), rational(a.y));
+    let cross = (rational(b.x) - &ax) * (rational(point.y) - &ay)
+        - (rational(b.y) - &ay) * (rational(point.x) - &ax);
+
+    if cross.is_positive() {
+        Side::Left
+    } else if cross.is_negative() {
+        Side::Right
+    } else {
+        Side::On
+    }
+}
+
+/// Checks whether two segments intersect. Segments which only touch or
+/// overlap along a line are considered intersecting.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{exact, Fecc, Segment};
+///
+/// let a = Segment(Fecc::new(0.1, 0.3), Fecc::new(0.3, 0.9));
+/// let b = Segment(Fecc::new(0.3, 0.9), Fecc::new(0.7, 2.1));
+/// let c = Segment(Fecc::new(0.0, 1.0), Fecc::new(1.0, 0.0));
+///
+/// assert!(exact::segments_intersect(&a, &b));
+/// assert!(exact::segments_intersect(&a, &c));
+/// assert!(!exact::segments_intersect(&b, &c));
+/// ```
+pub fn segments_intersect(first: &Segment, second: &Segment) -> bool {
+    let Segment(a, b) = *first;
+    let Segment(c, d) = *second;
+
+    let (abc, abd) = (side_of(a, b, c), side_of(a, b, d));
+    let (cda, cdb) = (side_of(c, d, a), side_of(c, d, b));
+
+    if abc != abd && cda != cdb {
+        return true;
+    }
+
+    // Collinear points within the other segment's bounds lie on it.
+    let within = |Segment(start, end): Segment, point: Fecc| {
+        point.x >= start.x.min(end.x)
+            && point.x <= start.x.max(end.x)
+            && point.y >= start.y.min(end.y)
+            && point.y <= start.y.max(end.y)
+    };
+
+    (abc == Side::On && within(*first, c))
+        || (abd == Side::On && within(*first, d))
+        || (cda == Side::On && within(*second, a))
+        || (cdb == Side::On && within(*second, b))
+}
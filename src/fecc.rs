@@ -1,14 +1,14 @@
 //! Implementation of [`Fecc`](crate::fecc::Fecc) - the alias for
 //! [`Vecc<f64>`](crate::vecc::Vecc).
+//!
+//! The extended API is generic over [`Float`](crate::float::Float), so
+//! [`Vecc<f32>`](crate::vecc::Vecc) implements it as well.
 
 #[cfg(feature = "random")]
 use rand::{Rng, SeedableRng};
-use std::{
-    f64::consts::PI,
-    ops::{Rem, RemAssign},
-};
+use std::ops::{Rem, RemAssign};
 
-use crate::{Angle, Vecc};
+use crate::{Angle, Float, Vecc};
 
 /// Vector with two [`f64`](f64) components.
 ///
@@ -17,8 +17,11 @@ use crate::{Angle, Vecc};
 /// [`Fecc`](Fecc) is [`Copy`](std::marker::Copy) none of the
 /// methods mutates the vector, they may only return a new one.
 ///
-/// Note that [`Fecc`](Fecc)'s implementations of [`Rem`](std::ops::Rem)
-/// and [`RemAssign`](std::ops::RemAssign) use
+/// All of the methods are also implemented for [`Vecc<f32>`](Vecc) (and any
+/// other `Vecc<T>` where `T:` [`Float`](crate::float::Float)).
+///
+/// Note that [`Fecc`](Fecc)'s (and [`Vecc<f32>`](Vecc)'s) implementations of
+/// [`Rem`](std::ops::Rem) and [`RemAssign`](std::ops::RemAssign) use
 /// [`f64::rem_euclid`](f64::rem_euclid), not [`f64::rem`](Rem::rem).
 /// (TODO: Link `f64::rem` directly after [#74563](https://github.com/rust-lang/rust/issues/74563)
 /// is resolved.) This kind of modulo operation is expected to be more useful
@@ -40,9 +43,46 @@ use crate::{Angle, Vecc};
 ///
 /// assert_approx_eq!(f64, e.mag(), 20.0);
 /// ```
+///
+/// The same API with [`f32`](f32) components.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// # use std::f32::consts::PI;
+/// use veccentric::Vecc;
+///
+/// let a: Vecc<f32> = (3.0, 4.0).into();
+/// let b = a.limit(2.5).rotate(PI);
+///
+/// assert_approx_eq!(f32, b.x, -1.5, epsilon = 1e-6);
+/// assert_approx_eq!(f32, b.y, -2.0, epsilon = 1e-6);
+/// assert_approx_eq!(f32, (b % 2.0).x, 0.5, epsilon = 1e-6);
+/// ```
+///
+/// The modulo of [`Vecc<f32>`](Vecc) is Euclidean too. (Up to version
+/// `0.3.1` it kept the sign of the dividend.)
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::Vecc;
+///
+/// let mut a = Vecc::<f32>::new(-1.5, 2.0) % Vecc::new(1.0, 1.0);
+///
+/// assert_approx_eq!(f32, a.x, 0.5); // Was -0.5.
+/// assert_approx_eq!(f32, a.y, 0.0);
+///
+/// a -= Vecc::new(1.0, 1.0);
+/// a %= 1.0;
+///
+/// assert_approx_eq!(f32, a.x, 0.5);
+/// assert_approx_eq!(f32, a.y, 0.0);
+/// ```
 pub type Fecc = Vecc<f64>;
 
-impl Fecc {
+impl<T> Vecc<T>
+where
+    T: Float,
+{
     /// Constructs a new vector of zero magnitude.
     ///
     /// # Examples
@@ -56,7 +96,10 @@ impl Fecc {
     /// assert_approx_eq!(f64, zero.mag(), 0.0);
     /// ```
     pub fn zero() -> Self {
-        Self { x: 0.0, y: 0.0 }
+        Self {
+            x: T::ZERO,
+            y: T::ZERO,
+        }
     }
 
     /// Constructs a new unit vector pointing in the specified direction.
@@ -80,8 +123,8 @@ impl Fecc {
         let angle = angle.into();
 
         Self {
-            x: angle.cos(),
-            y: angle.sin(),
+            x: T::from_f64(angle.cos()),
+            y: T::from_f64(angle.sin()),
         }
     }
 
@@ -108,8 +151,8 @@ impl Fecc {
         let angle = rng.gen::<f64>();
 
         Self {
-            x: angle.cos(),
-            y: angle.sin(),
+            x: T::from_f64(angle.cos()),
+            y: T::from_f64(angle.sin()),
         }
     }
 
@@ -136,8 +179,8 @@ impl Fecc {
         let angle = rng.gen::<f64>();
 
         Self {
-            x: angle.cos(),
-            y: angle.sin(),
+            x: T::from_f64(angle.cos()),
+            y: T::from_f64(angle.sin()),
         }
     }
 
@@ -164,8 +207,8 @@ impl Fecc {
         let angle = rng.gen::<f64>();
 
         Self {
-            x: angle.cos(),
-            y: angle.sin(),
+            x: T::from_f64(angle.cos()),
+            y: T::from_f64(angle.sin()),
         }
     }
 
@@ -185,7 +228,7 @@ impl Fecc {
     /// ```
     pub fn normalize(&self) -> Self {
         if self.is_zero() {
-            Fecc::zero()
+            Self::zero()
         } else {
             self / self.mag()
         }
@@ -207,7 +250,7 @@ impl Fecc {
     /// let limited_b = b.limit(10.0);
     /// assert_approx_eq!(f64, limited_b.mag(), 1.0);
     /// ```
-    pub fn limit(&self, limit: f64) -> Self {
+    pub fn limit(&self, limit: T) -> Self {
         let mag = self.mag();
 
         if mag > limit {
@@ -230,7 +273,7 @@ impl Fecc {
     ///
     /// assert_approx_eq!(f64, resized_a.mag(), 100.0);
     /// ```
-    pub fn resize(&self, mag: f64) -> Self {
+    pub fn resize(&self, mag: T) -> Self {
         *self * mag / self.mag()
     }
 
@@ -309,10 +352,11 @@ impl Fecc {
         A: Into<Angle>,
     {
         let angle = angle.into();
+        let (sin, cos) = (T::from_f64(angle.sin()), T::from_f64(angle.cos()));
 
         Self {
-            x: self.x * angle.cos() - self.y * angle.sin(),
-            y: self.x * angle.sin() + self.y * angle.cos(),
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
         }
     }
 
@@ -335,11 +379,11 @@ impl Fecc {
     /// assert_approx_eq!(f64, reflected_a.x, -a.x);
     /// assert_approx_eq!(f64, reflected_a.y, a.y);
     /// ```
-    pub fn reflect(&self, normal: Fecc) -> Self {
+    pub fn reflect(&self, normal: Self) -> Self {
         if normal.is_zero() {
             *self
         } else {
-            -(self + self.project(normal) * 2.0)
+            -(self + self.project(normal) * T::TWO)
         }
     }
 
@@ -366,6 +410,103 @@ impl Fecc {
         }
     }
 
+    /// Bounces the velocity off a surface with the specified normal.
+    /// `restitution` is the fraction of the normal speed kept after the
+    /// bounce — `1.0` is perfectly elastic, `0.0` stops the motion along the
+    /// normal completely.
+    ///
+    /// Velocities already moving away from the surface (or along it) are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::Fecc;
+    ///
+    /// let velocity = Fecc::new(3.0, -4.0);
+    /// let floor = Fecc::new(0.0, 1.0);
+    /// let bounced = velocity.bounce(floor, 0.5);
+    ///
+    /// assert_approx_eq!(f64, bounced.x, 3.0);
+    /// assert_approx_eq!(f64, bounced.y, 2.0);
+    /// ```
+    pub fn bounce(&self, normal: Self, restitution: T) -> Self {
+        if self.dot(normal) >= T::ZERO {
+            *self
+        } else {
+            self - self.project(normal) * (T::ONE + restitution)
+        }
+    }
+
+    /// Removes the component of the velocity pointing into a surface with
+    /// the specified normal, so that the velocity slides along it.
+    ///
+    /// Velocities already moving away from the surface (or along it) are
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::Fecc;
+    ///
+    /// let velocity = Fecc::new(3.0, -4.0);
+    /// let floor = Fecc::new(0.0, 1.0);
+    /// let slid = velocity.slide(floor);
+    ///
+    /// assert_approx_eq!(f64, slid.x, 3.0);
+    /// assert_approx_eq!(f64, slid.y, 0.0);
+    /// ```
+    pub fn slide(&self, normal: Self) -> Self {
+        self.bounce(normal, T::ZERO)
+    }
+
+    /// Like [`slide`](crate::fecc::Fecc::slide), but also scales the
+    /// velocity along the surface by `1.0 - friction`. Velocities moving
+    /// away from the surface aren't affected by friction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::Fecc;
+    ///
+    /// let velocity = Fecc::new(4.0, -4.0);
+    /// let floor = Fecc::new(0.0, 1.0);
+    /// let slid = velocity.slide_with_friction(floor, 0.25);
+    ///
+    /// assert_approx_eq!(f64, slid.x, 3.0);
+    /// assert_approx_eq!(f64, slid.y, 0.0);
+    /// ```
+    pub fn slide_with_friction(&self, normal: Self, friction: T) -> Self {
+        if self.dot(normal) >= T::ZERO {
+            *self
+        } else {
+            self.slide(normal) * (T::ONE - friction)
+        }
+    }
+
+    /// Linearly interpolates between two vectors. `t = 0.0` results in the
+    /// original vector and `t = 1.0` results in `other`. `t` is not clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::Fecc;
+    ///
+    /// let a = Fecc::new(0.0, 10.0);
+    /// let b = Fecc::new(10.0, 0.0);
+    /// let middle = a.lerp(b, 0.5);
+    ///
+    /// assert_approx_eq!(f64, middle.x, 5.0);
+    /// assert_approx_eq!(f64, middle.y, 5.0);
+    /// ```
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        *self + (other - *self) * t
+    }
+
     /// Returns the distance between two points (the tips of the vectors
     /// pointing from the origin).
     ///
@@ -380,10 +521,18 @@ impl Fecc {
     ///
     /// assert_approx_eq!(f64, a.dist(b), 5.0);
     /// ```
-    pub fn dist(&self, other: Self) -> f64 {
+    pub fn dist(&self, other: Self) -> T {
         (*self - other).mag()
     }
 
+    /// Returns the distance between two points like
+    /// [`dist`](crate::fecc::Fecc::dist), but without overflowing or
+    /// underflowing for very large or very small coordinates. See
+    /// [`mag_hypot`](crate::fecc::Fecc::mag_hypot).
+    pub fn dist_hypot(&self, other: Self) -> T {
+        (*self - other).mag_hypot()
+    }
+
     /// Returns the square of the distance between two points (the tips of the
     /// vectors pointing from the origin).
     ///
@@ -398,7 +547,7 @@ impl Fecc {
     ///
     /// assert_approx_eq!(f64, a.dist_squared(b), 25.0);
     /// ```
-    pub fn dist_squared(&self, other: Self) -> f64 {
+    pub fn dist_squared(&self, other: Self) -> T {
         (*self - other).mag_squared()
     }
 
@@ -416,7 +565,23 @@ impl Fecc {
     /// assert!(!one.is_zero());
     /// ```
     pub fn is_zero(&self) -> bool {
-        (self.x == 0.0) && (self.y == 0.0)
+        (self.x == T::ZERO) && (self.y == T::ZERO)
+    }
+
+    /// Checks whether both components are finite, i.e. neither infinite nor
+    /// NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::Fecc;
+    ///
+    /// assert!(Fecc::new(1.0, -2.0).all_finite());
+    /// assert!(!Fecc::new(1.0, f64::NAN).all_finite());
+    /// assert!(!(Fecc::new(1.0, 0.0) / 0.0).all_finite());
+    /// ```
+    pub fn all_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
     }
 
     /// Returns the angle between two vectors.
@@ -433,13 +598,13 @@ impl Fecc {
     ///
     /// assert_approx_eq!(f64, a.angle_to(b), PI / 2.0);
     /// ```
-    pub fn angle_to(&self, other: Self) -> f64 {
+    pub fn angle_to(&self, other: Self) -> T {
         let angle = other.angle() - self.angle();
 
-        if angle > PI {
-            angle - 2.0 * PI
-        } else if angle < -PI {
-            angle + 2.0 * PI
+        if angle > T::PI {
+            angle - T::TWO * T::PI
+        } else if angle < -T::PI {
+            angle + T::TWO * T::PI
         } else {
             angle
         }
@@ -458,7 +623,7 @@ impl Fecc {
     ///
     /// assert_approx_eq!(f64, up.angle(), PI / 2.0);
     /// ```
-    pub fn angle(&self) -> f64 {
+    pub fn angle(&self) -> T {
         self.y.atan2(self.x)
     }
 
@@ -474,10 +639,34 @@ impl Fecc {
     ///
     /// assert_approx_eq!(f64, five.mag(), 5.0);
     /// ```
-    pub fn mag(&self) -> f64 {
+    pub fn mag(&self) -> T {
         self.mag_squared().sqrt()
     }
 
+    /// Returns the magnitude of the vector using [`f64::hypot`](f64::hypot).
+    ///
+    /// Unlike [`mag`](crate::fecc::Fecc::mag), it doesn't square the
+    /// components, so it doesn't overflow to infinity (or underflow to zero)
+    /// unless the magnitude itself isn't representable. It's slower though.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::Fecc;
+    ///
+    /// let huge = Fecc::new(3e200, 4e200);
+    /// assert!(huge.mag().is_infinite());
+    /// assert_approx_eq!(f64, huge.mag_hypot(), 5e200, ulps = 2);
+    ///
+    /// let tiny = Fecc::new(3e-200, 4e-200);
+    /// assert_approx_eq!(f64, tiny.mag(), 0.0);
+    /// assert_approx_eq!(f64, tiny.mag_hypot() / 5e-200, 1.0);
+    /// ```
+    pub fn mag_hypot(&self) -> T {
+        self.x.hypot(self.y)
+    }
+
     /// Returns the square of the magnitude of the vector.
     ///
     /// # Examples
@@ -490,8 +679,8 @@ impl Fecc {
     ///
     /// assert_approx_eq!(f64, five.mag_squared(), 25.0);
     /// ```
-    pub fn mag_squared(&self) -> f64 {
-        self.x.powf(2.0) + self.y.powf(2.0)
+    pub fn mag_squared(&self) -> T {
+        self.x * self.x + self.y * self.y
     }
 
     /// Performs component-wise [`round`](f64::round) and convert the
@@ -508,12 +697,11 @@ impl Fecc {
     /// assert_eq!(rounded_a.x, 3);
     /// assert_eq!(rounded_a.y, 5);
     /// ```
-
     // TODO: Consider returning `Result` — [https://stackoverflow.com/questions/41138599/rounding-a-f64-to-nearest-i64-in-rust].
     pub fn round(&self) -> Vecc<i64> {
         Vecc {
-            x: self.x.round() as i64,
-            y: self.y.round() as i64,
+            x: self.x.round().to_f64() as i64,
+            y: self.y.round().to_f64() as i64,
         }
     }
 
@@ -531,12 +719,11 @@ impl Fecc {
     /// assert_eq!(floored_a.x, 3);
     /// assert_eq!(floored_a.y, 4);
     /// ```
-
     // TODO: Consider returning `Result` — [https://stackoverflow.com/questions/41138599/rounding-a-f64-to-nearest-i64-in-rust].
     pub fn floor(&self) -> Vecc<i64> {
         Vecc {
-            x: self.x.floor() as i64,
-            y: self.y.floor() as i64,
+            x: self.x.floor().to_f64() as i64,
+            y: self.y.floor().to_f64() as i64,
         }
     }
 
@@ -554,215 +741,223 @@ impl Fecc {
     /// assert_eq!(ceiled_a.x, 4);
     /// assert_eq!(ceiled_a.y, 5);
     /// ```
-
     // TODO: Consider returning `Result` — [https://stackoverflow.com/questions/41138599/rounding-a-f64-to-nearest-i64-in-rust].
     pub fn ceil(&self) -> Vecc<i64> {
         Vecc {
-            x: self.x.ceil() as i64,
-            y: self.y.ceil() as i64,
-        }
-    }
-
-    /// Performs element-wise [`min`](f64::min).
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// # use float_cmp::assert_approx_eq;
-    /// use veccentric::Fecc;
-    ///
-    /// let a = Fecc::new(-100.0, 100.0);
-    /// let b = Fecc::new(0.0, 0.0);
-    /// let min = a.min(b);
-    ///
-    /// assert_approx_eq!(f64, min.x, -100.0);
-    /// assert_approx_eq!(f64, min.y, 0.0);
-    /// ```
-    pub fn min(&self, other: Self) -> Self {
-        Self {
-            x: self.x.min(other.x),
-            y: self.y.min(other.y),
-        }
-    }
-
-    /// Performs element-wise [`max`](f64::max).
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// # use float_cmp::assert_approx_eq;
-    /// use veccentric::Fecc;
-    ///
-    /// let a = Fecc::new(-100.0, 100.0);
-    /// let b = Fecc::new(0.0, 0.0);
-    /// let max = a.max(b);
-    ///
-    /// assert_approx_eq!(f64, max.x, 0.0);
-    /// assert_approx_eq!(f64, max.y, 100.0);
-    /// ```
-    pub fn max(&self, other: Self) -> Self {
-        Self {
-            x: self.x.max(other.x),
-            y: self.y.max(other.y),
+            x: self.x.ceil().to_f64() as i64,
+            y: self.y.ceil().to_f64() as i64,
         }
     }
+}
 
-    /// Performs element-wise [`clamp`](f64::clamp).
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// # use float_cmp::assert_approx_eq;
-    /// use veccentric::Fecc;
-    ///
-    /// let a = Fecc::new(-100.0, 100.0);
-    /// let min = Fecc::new(0.0, 10.0);
-    /// let max = Fecc::new(0.0, 10.0);
-    /// let clamped = a.clamp(min, max);
-    ///
-    /// assert_approx_eq!(f64, clamped.x, 0.0);
-    /// assert_approx_eq!(f64, clamped.y, 10.0);
-    /// ```
-    pub fn clamp(&self, min: Self, max: Self) -> Self {
-        Self {
-            x: self.x.clamp(min.x, max.x),
-            y: self.y.clamp(min.y, max.y),
+macro_rules! impl_float_vecc {
+    ($t:ty) => {
+        impl Vecc<$t> {
+            /// Performs element-wise [`min`](f64::min).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use float_cmp::assert_approx_eq;
+            /// use veccentric::Fecc;
+            ///
+            /// let a = Fecc::new(-100.0, 100.0);
+            /// let b = Fecc::new(0.0, 0.0);
+            /// let min = a.min(b);
+            ///
+            /// assert_approx_eq!(f64, min.x, -100.0);
+            /// assert_approx_eq!(f64, min.y, 0.0);
+            /// ```
+            pub fn min(&self, other: Self) -> Self {
+                Self {
+                    x: self.x.min(other.x),
+                    y: self.y.min(other.y),
+                }
+            }
+
+            /// Performs element-wise [`max`](f64::max).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use float_cmp::assert_approx_eq;
+            /// use veccentric::Fecc;
+            ///
+            /// let a = Fecc::new(-100.0, 100.0);
+            /// let b = Fecc::new(0.0, 0.0);
+            /// let max = a.max(b);
+            ///
+            /// assert_approx_eq!(f64, max.x, 0.0);
+            /// assert_approx_eq!(f64, max.y, 100.0);
+            /// ```
+            pub fn max(&self, other: Self) -> Self {
+                Self {
+                    x: self.x.max(other.x),
+                    y: self.y.max(other.y),
+                }
+            }
+
+            /// Performs element-wise [`clamp`](f64::clamp).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use float_cmp::assert_approx_eq;
+            /// use veccentric::Fecc;
+            ///
+            /// let a = Fecc::new(-100.0, 100.0);
+            /// let min = Fecc::new(0.0, 10.0);
+            /// let max = Fecc::new(0.0, 10.0);
+            /// let clamped = a.clamp(min, max);
+            ///
+            /// assert_approx_eq!(f64, clamped.x, 0.0);
+            /// assert_approx_eq!(f64, clamped.y, 10.0);
+            /// ```
+            pub fn clamp(&self, min: Self, max: Self) -> Self {
+                Self {
+                    x: self.x.clamp(min.x, max.x),
+                    y: self.y.clamp(min.y, max.y),
+                }
+            }
         }
-    }
-}
 
-// Euclidean modulo.
+        // Euclidean modulo.
 
-// Owned & owned.
-impl Rem<Fecc> for Fecc {
-    type Output = Fecc;
+        // Owned & owned.
+        impl Rem<Vecc<$t>> for Vecc<$t> {
+            type Output = Vecc<$t>;
 
-    fn rem(self, rhs: Fecc) -> Self::Output {
-        Vecc {
-            x: self.x.rem_euclid(rhs.x),
-            y: self.y.rem_euclid(rhs.y),
+            fn rem(self, rhs: Vecc<$t>) -> Self::Output {
+                Vecc {
+                    x: self.x.rem_euclid(rhs.x),
+                    y: self.y.rem_euclid(rhs.y),
+                }
+            }
         }
-    }
-}
 
-// Owned & borrowed.
-impl Rem<&Fecc> for Fecc {
-    type Output = Fecc;
+        // Owned & borrowed.
+        impl Rem<&Vecc<$t>> for Vecc<$t> {
+            type Output = Vecc<$t>;
 
-    fn rem(self, rhs: &Fecc) -> Self::Output {
-        Vecc {
-            x: self.x.rem_euclid(rhs.x),
-            y: self.y.rem_euclid(rhs.y),
+            fn rem(self, rhs: &Vecc<$t>) -> Self::Output {
+                Vecc {
+                    x: self.x.rem_euclid(rhs.x),
+                    y: self.y.rem_euclid(rhs.y),
+                }
+            }
         }
-    }
-}
 
-// Borrowed & owned.
-impl Rem<Fecc> for &Fecc {
-    type Output = Fecc;
+        // Borrowed & owned.
+        impl Rem<Vecc<$t>> for &Vecc<$t> {
+            type Output = Vecc<$t>;
 
-    fn rem(self, rhs: Fecc) -> Self::Output {
-        Vecc {
-            x: self.x.rem_euclid(rhs.x),
-            y: self.y.rem_euclid(rhs.y),
+            fn rem(self, rhs: Vecc<$t>) -> Self::Output {
+                Vecc {
+                    x: self.x.rem_euclid(rhs.x),
+                    y: self.y.rem_euclid(rhs.y),
+                }
+            }
         }
-    }
-}
 
-// Borrowed & borrowed.
-impl Rem<&Fecc> for &Fecc {
-    type Output = Fecc;
+        // Borrowed & borrowed.
+        impl Rem<&Vecc<$t>> for &Vecc<$t> {
+            type Output = Vecc<$t>;
 
-    fn rem(self, rhs: &Fecc) -> Self::Output {
-        Vecc {
-            x: self.x.rem_euclid(rhs.x),
-            y: self.y.rem_euclid(rhs.y),
+            fn rem(self, rhs: &Vecc<$t>) -> Self::Output {
+                Vecc {
+                    x: self.x.rem_euclid(rhs.x),
+                    y: self.y.rem_euclid(rhs.y),
+                }
+            }
         }
-    }
-}
 
-// Euclidean modulo with f64.
+        // Euclidean modulo with $t.
 
-// Owned & owned.
-impl Rem<f64> for Fecc {
-    type Output = Fecc;
+        // Owned & owned.
+        impl Rem<$t> for Vecc<$t> {
+            type Output = Vecc<$t>;
 
-    fn rem(self, rhs: f64) -> Self::Output {
-        Vecc {
-            x: self.x.rem_euclid(rhs),
-            y: self.y.rem_euclid(rhs),
+            fn rem(self, rhs: $t) -> Self::Output {
+                Vecc {
+                    x: self.x.rem_euclid(rhs),
+                    y: self.y.rem_euclid(rhs),
+                }
+            }
         }
-    }
-}
 
-// Owned & borrowed.
-impl Rem<&f64> for Fecc {
-    type Output = Fecc;
+        // Owned & borrowed.
+        impl Rem<&$t> for Vecc<$t> {
+            type Output = Vecc<$t>;
 
-    fn rem(self, rhs: &f64) -> Self::Output {
-        Vecc {
-            x: self.x.rem_euclid(*rhs),
-            y: self.y.rem_euclid(*rhs),
+            fn rem(self, rhs: &$t) -> Self::Output {
+                Vecc {
+                    x: self.x.rem_euclid(*rhs),
+                    y: self.y.rem_euclid(*rhs),
+                }
+            }
         }
-    }
-}
 
-// Borrowed & owned.
-impl Rem<f64> for &Fecc {
-    type Output = Fecc;
+        // Borrowed & owned.
+        impl Rem<$t> for &Vecc<$t> {
+            type Output = Vecc<$t>;
 
-    fn rem(self, rhs: f64) -> Self::Output {
-        Vecc {
-            x: self.x.rem_euclid(rhs),
-            y: self.y.rem_euclid(rhs),
+            fn rem(self, rhs: $t) -> Self::Output {
+                Vecc {
+                    x: self.x.rem_euclid(rhs),
+                    y: self.y.rem_euclid(rhs),
+                }
+            }
         }
-    }
-}
 
-// Borrowed & borrowed.
-impl Rem<&f64> for &Fecc {
-    type Output = Fecc;
+        // Borrowed & borrowed.
+        impl Rem<&$t> for &Vecc<$t> {
+            type Output = Vecc<$t>;
 
-    fn rem(self, rhs: &f64) -> Self::Output {
-        Vecc {
-            x: self.x.rem_euclid(*rhs),
-            y: self.y.rem_euclid(*rhs),
+            fn rem(self, rhs: &$t) -> Self::Output {
+                Vecc {
+                    x: self.x.rem_euclid(*rhs),
+                    y: self.y.rem_euclid(*rhs),
+                }
+            }
         }
-    }
-}
 
-// (Euclidean modulo)Assign.
+        // (Euclidean modulo)Assign.
 
-// Owned.
-impl RemAssign<Fecc> for Fecc {
-    fn rem_assign(&mut self, rhs: Fecc) {
-        self.x = self.x.rem_euclid(rhs.x);
-        self.y = self.y.rem_euclid(rhs.y);
-    }
-}
+        // Owned.
+        impl RemAssign<Vecc<$t>> for Vecc<$t> {
+            fn rem_assign(&mut self, rhs: Vecc<$t>) {
+                self.x = self.x.rem_euclid(rhs.x);
+                self.y = self.y.rem_euclid(rhs.y);
+            }
+        }
 
-// Borrowed.
-impl RemAssign<&Fecc> for Fecc {
-    fn rem_assign(&mut self, rhs: &Fecc) {
-        self.x = self.x.rem_euclid(rhs.x);
-        self.y = self.y.rem_euclid(rhs.y);
-    }
-}
+        // Borrowed.
+        impl RemAssign<&Vecc<$t>> for Vecc<$t> {
+            fn rem_assign(&mut self, rhs: &Vecc<$t>) {
+                self.x = self.x.rem_euclid(rhs.x);
+                self.y = self.y.rem_euclid(rhs.y);
+            }
+        }
 
-// (Euclidean modulo)Assign with f64.
+        // (Euclidean modulo)Assign with $t.
 
-// Owned.
-impl RemAssign<f64> for Fecc {
-    fn rem_assign(&mut self, rhs: f64) {
-        self.x = self.x.rem_euclid(rhs);
-        self.y = self.y.rem_euclid(rhs);
-    }
-}
+        // Owned.
+        impl RemAssign<$t> for Vecc<$t> {
+            fn rem_assign(&mut self, rhs: $t) {
+                self.x = self.x.rem_euclid(rhs);
+                self.y = self.y.rem_euclid(rhs);
+            }
+        }
 
-// Borrowed.
-impl RemAssign<&f64> for Fecc {
-    fn rem_assign(&mut self, rhs: &f64) {
-        self.x = self.x.rem_euclid(*rhs);
-        self.y = self.y.rem_euclid(*rhs);
-    }
+        // Borrowed.
+        impl RemAssign<&$t> for Vecc<$t> {
+            fn rem_assign(&mut self, rhs: &$t) {
+                self.x = self.x.rem_euclid(*rhs);
+                self.y = self.y.rem_euclid(*rhs);
+            }
+        }
+    };
 }
+
+impl_float_vecc!(f32);
+impl_float_vecc!(f64);
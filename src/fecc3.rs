@@ -0,0 +1,206 @@
+//! Implementation of [`Fecc3`](crate::fecc3::Fecc3) - the alias for
+//! [`Vecc3<f64>`](crate::vecc3::Vecc3).
+//!
+//! Like the 2D API, it's generic over [`Float`](crate::float::Float), so
+//! [`Vecc3<f32>`](crate::vecc3::Vecc3) implements it as well.
+
+use crate::{Angle, Float, Vecc3};
+
+/// Vector with three [`f64`](f64) components.
+///
+/// Its API mirrors [`Fecc`](crate::fecc::Fecc)'s, except that rotations need
+/// an axis.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// # use std::f64::consts::FRAC_PI_2;
+/// use veccentric::Fecc3;
+///
+/// let a = Fecc3::new(2.0, 3.0, 6.0);
+/// assert_approx_eq!(f64, a.mag(), 7.0);
+///
+/// let b = a.limit(3.5); // (1.0, 1.5, 3.0)
+/// assert_approx_eq!(f64, b.z, 3.0);
+///
+/// // Rotating around the Z axis leaves the Z component unchanged.
+/// let c = b.rotate(Fecc3::new(0.0, 0.0, 1.0), FRAC_PI_2); // (-1.5, 1.0, 3.0)
+/// assert_approx_eq!(f64, c.x, -1.5);
+/// assert_approx_eq!(f64, c.y, 1.0);
+/// assert_approx_eq!(f64, c.z, 3.0);
+/// ```
+pub type Fecc3 = Vecc3<f64>;
+
+impl<T> Vecc3<T>
+where
+    T: Float,
+{
+    /// Constructs a new vector of zero magnitude.
+    pub fn zero() -> Self {
+        Self {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+        }
+    }
+
+    /// Normalizes the vector (construct a new **unit** vector pointing in the
+    /// same direction as the original one). Normalizing a zero vector
+    /// results in a zero vector.
+    pub fn normalize(&self) -> Self {
+        if self.is_zero() {
+            Self::zero()
+        } else {
+            self / self.mag()
+        }
+    }
+
+    /// Limits the magnitude of the vector.
+    pub fn limit(&self, limit: T) -> Self {
+        let mag = self.mag();
+
+        if mag > limit {
+            *self * (limit / mag)
+        } else {
+            *self
+        }
+    }
+
+    /// Sets the magnitude of the vector, leaving its direction unchanged.
+    pub fn resize(&self, mag: T) -> Self {
+        *self * mag / self.mag()
+    }
+
+    /// Rotates the vector around an axis, leaving its magnitude unchanged.
+    /// Positive angles mean counterclockwise rotation when looking from the
+    /// tip of the axis towards the origin. Rotation around a zero axis
+    /// results in the original vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{Angular, Fecc3};
+    ///
+    /// let a = Fecc3::new(1.0, 0.0, 0.0);
+    /// let diagonal = Fecc3::new(1.0, 1.0, 1.0);
+    ///
+    /// // A third of a turn around the diagonal swaps the axes.
+    /// let rotated = a.rotate(diagonal, 120.0.deg());
+    ///
+    /// assert_approx_eq!(f64, rotated.x, 0.0);
+    /// assert_approx_eq!(f64, rotated.y, 1.0);
+    /// assert_approx_eq!(f64, rotated.z, 0.0);
+    /// ```
+    pub fn rotate<A>(&self, axis: Self, angle: A) -> Self
+    where
+        A: Into<Angle>,
+    {
+        if axis.is_zero() {
+            return *self;
+        }
+
+        // Rodrigues' rotation formula.
+        let axis = axis.normalize();
+        let angle = angle.into();
+        let (sin, cos) = (T::from_f64(angle.sin()), T::from_f64(angle.cos()));
+
+        *self * cos
+            + axis.cross(*self) * sin
+            + axis * (axis.dot(*self) * (T::ONE - cos))
+    }
+
+    /// Projects a vector onto another. Projection onto a zero vector results in
+    /// the original vector.
+    pub fn project(&self, other: Self) -> Self {
+        if other.is_zero() {
+            *self
+        } else {
+            other * self.dot(other) / other.dot(other)
+        }
+    }
+
+    /// Reflects the vector off a surface with the normal, reversing the
+    /// component along the normal. The length is unchanged. Reflection about
+    /// a zero vector results in the original vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::Fecc3;
+    ///
+    /// let a = Fecc3::new(1.0, 1.0, 0.0);
+    /// let reflected = a.reflect(Fecc3::new(0.0, 1.0, 0.0));
+    ///
+    /// assert_approx_eq!(f64, reflected.x, 1.0);
+    /// assert_approx_eq!(f64, reflected.y, -1.0);
+    /// assert_approx_eq!(f64, reflected.z, 0.0);
+    /// assert_approx_eq!(f64, reflected.mag(), a.mag());
+    /// ```
+    pub fn reflect(&self, normal: Self) -> Self {
+        if normal.is_zero() {
+            *self
+        } else {
+            *self - self.project(normal) * T::TWO
+        }
+    }
+
+    /// Linearly interpolates between two vectors. `t = 0.0` results in the
+    /// original vector and `t = 1.0` results in `other`. `t` is not clamped.
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        *self + (other - *self) * t
+    }
+
+    /// Returns the distance between two points (the tips of the vectors
+    /// pointing from the origin).
+    pub fn dist(&self, other: Self) -> T {
+        (*self - other).mag()
+    }
+
+    /// Returns the square of the distance between two points (the tips of the
+    /// vectors pointing from the origin).
+    pub fn dist_squared(&self, other: Self) -> T {
+        (*self - other).mag_squared()
+    }
+
+    /// Checks whether the vector has zero magnitude.
+    pub fn is_zero(&self) -> bool {
+        (self.x == T::ZERO) && (self.y == T::ZERO) && (self.z == T::ZERO)
+    }
+
+    /// Checks whether all components are finite, i.e. neither infinite nor
+    /// NaN.
+    pub fn all_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Returns the (unsigned) angle between two vectors, from `0.0` to `π`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// # use std::f64::consts::FRAC_PI_2;
+    /// use veccentric::Fecc3;
+    ///
+    /// let a = Fecc3::new(1.0, 0.0, 0.0);
+    /// let b = Fecc3::new(0.0, 0.0, -2.0);
+    ///
+    /// assert_approx_eq!(f64, a.angle_to(b), FRAC_PI_2);
+    /// ```
+    pub fn angle_to(&self, other: Self) -> T {
+        self.cross(other).mag().atan2(self.dot(other))
+    }
+
+    /// Returns the magnitude of the vector.
+    pub fn mag(&self) -> T {
+        self.mag_squared().sqrt()
+    }
+
+    /// Returns the square of the magnitude of the vector.
+    pub fn mag_squared(&self) -> T {
+        self.dot(*self)
+    }
+}
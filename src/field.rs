@@ -0,0 +1,134 @@
+//! Vector fields.
+
+use crate::{generate, Aabb, Fecc, Polyline};
+
+/// A vector assigned to every point of the plane, e.g. wind, a current or
+/// gravity.
+///
+/// The trait is implemented for closures taking a position, so any function
+/// can be used as a field.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{field::VectorField, Fecc};
+///
+/// // Rotation around the origin.
+/// let vortex = |p: Fecc| Fecc::new(-p.y, p.x);
+///
+/// assert_eq!(vortex.sample(Fecc::new(1.0, 0.0)), Fecc::new(0.0, 1.0));
+/// ```
+pub trait VectorField {
+    /// Returns the vector at the specified position.
+    fn sample(&self, position: Fecc) -> Fecc;
+
+    /// Samples the field on a square lattice covering the box (see
+    /// [`generate::grid_points`](crate::generate::grid_points)), returning
+    /// the positions with their vectors, e.g. for drawing an arrow plot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `spacing` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{field::VectorField, Aabb, Fecc};
+    ///
+    /// let vortex = |p: Fecc| Fecc::new(-p.y, p.x);
+    /// let aabb = Aabb::new(Fecc::new(-1.0, -1.0), Fecc::new(1.0, 1.0));
+    /// let arrows = vortex.sample_grid(&aabb, 0.5);
+    ///
+    /// assert_eq!(arrows.len(), 25);
+    /// assert_eq!(arrows[0], (Fecc::new(-1.0, -1.0), Fecc::new(1.0, -1.0)));
+    /// ```
+    fn sample_grid(&self, aabb: &Aabb, spacing: f64) -> Vec<(Fecc, Fecc)> {
+        generate::grid_points(aabb, spacing)
+            .map(|position| (position, self.sample(position)))
+            .collect()
+    }
+
+    /// Traces the curve following the field from `start`, in steps of
+    /// length `step`, until another step would make it longer than `max_len`
+    /// or it reaches a point where the field is zero. The steps are
+    /// integrated with the midpoint method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{field::VectorField, Fecc};
+    ///
+    /// let vortex = |p: Fecc| Fecc::new(-p.y, p.x);
+    /// let streamline = vortex.streamline(Fecc::new(1.0, 0.0), 0.01, 3.0);
+    ///
+    /// // Half of the circle around the origin.
+    /// assert_eq!(streamline.0.len(), 301);
+    /// assert_approx_eq!(f64, streamline.length(), 3.0, epsilon = 1e-9);
+    ///
+    /// for point in &streamline.0 {
+    ///     assert_approx_eq!(f64, point.mag(), 1.0, epsilon = 1e-4);
+    /// }
+    /// ```
+    fn streamline(&self, start: Fecc, step: f64, max_len: f64) -> Polyline {
+        let direction = |position: Fecc| {
+            let vector = self.sample(position);
+
+            (!vector.is_zero() && vector.all_finite())
+                .then(|| vector.normalize())
+        };
+        let steps = if step > 0.0 {
+            // Tolerate rounding, e.g. 0.3 / 0.1 = 2.9999999999999996.
+            (max_len / step + 1e-9).floor() as usize
+        } else {
+            0
+        };
+        let mut points = vec![start];
+
+        for _ in 0..steps {
+            let position = points[points.len() - 1];
+            let next = direction(position)
+                .and_then(|d| direction(position + d * (step / 2.0)))
+                .map(|d| position + d * step);
+
+            match next {
+                Some(next) => points.push(next),
+                None => break,
+            }
+        }
+
+        Polyline(points)
+    }
+}
+
+impl<F> VectorField for F
+where
+    F: Fn(Fecc) -> Fecc,
+{
+    fn sample(&self, position: Fecc) -> Fecc {
+        self(position)
+    }
+}
+
+/// Field with the same vector everywhere.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{
+///     field::{Uniform, VectorField},
+///     Fecc,
+/// };
+///
+/// let gravity = Uniform(Fecc::new(0.0, -9.81));
+///
+/// assert_eq!(gravity.sample(Fecc::new(3.0, 4.0)), Fecc::new(0.0, -9.81));
+/// ```
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Uniform(pub Fecc);
+
+impl VectorField for Uniform {
+    fn sample(&self, _position: Fecc) -> Fecc {
+        self.0
+    }
+}
@@ -0,0 +1,299 @@
+//! Vectors with [fixed-point](https://docs.rs/fixed) components.
+//!
+//! Floating-point results may differ between platforms, compilers and
+//! optimization levels, which breaks lockstep multiplayer games relying on
+//! every peer computing exactly the same state. `Vecc<I32F32>` only uses
+//! integer arithmetic, so its results are the same everywhere.
+//!
+//! Angles are `I32F32` radians. Trigonometric functions are computed with
+//! [CORDIC](https://en.wikipedia.org/wiki/CORDIC) and square roots with the
+//! `fixed` crate's integer implementation. Intermediate results are kept as
+//! `I64F64` and rounded to the nearest `I32F32` at the end, so the results
+//! are accurate to a few units in the last place.
+//!
+//! Like with integers, overflowing arithmetic panics in debug builds.
+//!
+//! # Examples
+//!
+//! ```
+//! use fixed::types::I32F32;
+//! use veccentric::Vecc;
+//!
+//! let a = Vecc::new(I32F32::from_num(3), I32F32::from_num(4));
+//! let b = a.rotate(I32F32::FRAC_PI_2);
+//!
+//! let close = |a: I32F32, b: f64| (a - I32F32::from_num(b)).abs() < 1e-8;
+//!
+//! assert!(close(a.mag(), 5.0));
+//! assert!(close(b.x, -4.0));
+//! assert!(close(b.y, 3.0));
+//! ```
+
+use ::fixed::types::{I32F32, I64F64, U64F64};
+
+use crate::Vecc;
+
+/// `atan(2^-i)` as `I64F64` bits.
+const ATAN: [u64; 40] = [
+    14488038916154245685,
+    8552788783625223587,
+    4519058702220769989,
+    2293944758691655110,
+    1151423813380928739,
+    576273212193474858,
+    288206923339027287,
+    144112256152217971,
+    72057227537407417,
+    36028751206084335,
+    18014392782862199,
+    9007198538913212,
+    4503599537892014,
+    2251799802500437,
+    1125899905444523,
+    562949953246549,
+    281474976688811,
+    140737488352597,
+    70368744177323,
+    35184372088789,
+    17592186044411,
+    8796093022207,
+    4398046511104,
+    2199023255552,
+    1099511627776,
+    549755813888,
+    274877906944,
+    137438953472,
+    68719476736,
+    34359738368,
+    17179869184,
+    8589934592,
+    4294967296,
+    2147483648,
+    1073741824,
+    536870912,
+    268435456,
+    134217728,
+    67108864,
+    33554432,
+];
+
+/// The reciprocal of CORDIC's gain as `I64F64` bits.
+const GAIN: u64 = 11201839480117811816;
+
+fn widen(value: I32F32) -> I64F64 {
+    I64F64::from_num(value)
+}
+
+fn narrow(value: I64F64) -> I32F32 {
+    // Round to nearest instead of truncating.
+    I32F32::saturating_from_num(
+        value.saturating_add(I64F64::from_bits(1 << 31)),
+    )
+}
+
+/// Rotates `(x, y)` by `angle`, which must be in `[-π/2, π/2]`.
+fn cordic_rotate(
+    mut x: I64F64,
+    mut y: I64F64,
+    mut angle: I64F64,
+) -> (I64F64, I64F64) {
+    for (i, &atan) in ATAN.iter().enumerate() {
+        let atan = I64F64::from_bits(atan as i128);
+        let (dx, dy) = (y >> i as u32, x >> i as u32);
+
+        if angle >= 0 {
+            x -= dx;
+            y += dy;
+            angle -= atan;
+        } else {
+            x += dx;
+            y -= dy;
+            angle += atan;
+        }
+    }
+
+    let gain = I64F64::from_bits(GAIN as i128);
+
+    (x * gain, y * gain)
+}
+
+/// Returns the angle of `(x, y)`, which must have a non-negative `x`.
+fn cordic_angle(mut x: I64F64, mut y: I64F64) -> I64F64 {
+    let mut angle = I64F64::ZERO;
+
+    for (i, &atan) in ATAN.iter().enumerate() {
+        let atan = I64F64::from_bits(atan as i128);
+        let (dx, dy) = (y >> i as u32, x >> i as u32);
+
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            angle += atan;
+        } else {
+            x -= dx;
+            y += dy;
+            angle -= atan;
+        }
+    }
+
+    angle
+}
+
+impl Vecc<I32F32> {
+    /// Constructs a new vector of zero magnitude.
+    pub fn zero() -> Self {
+        Self::new(I32F32::ZERO, I32F32::ZERO)
+    }
+
+    /// Constructs a unit vector pointing in the direction of `angle`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed::types::I32F32;
+    /// use veccentric::Vecc;
+    ///
+    /// let a = Vecc::<I32F32>::from_angle(I32F32::PI);
+    ///
+    /// assert!((a.x + I32F32::ONE).abs() < 1e-8);
+    /// assert!(a.y.abs() < 1e-8);
+    /// ```
+    pub fn from_angle(angle: I32F32) -> Self {
+        Self::new(I32F32::ONE, I32F32::ZERO).rotate(angle)
+    }
+
+    /// Checks whether both components are zero.
+    pub fn is_zero(&self) -> bool {
+        self.x == 0 && self.y == 0
+    }
+
+    /// Returns the squared magnitude.
+    pub fn mag_squared(&self) -> I32F32 {
+        self.x * self.x + self.y * self.y
+    }
+
+    /// Returns the magnitude, saturating at `I32F32::MAX`.
+    pub fn mag(&self) -> I32F32 {
+        narrow(self.mag_wide())
+    }
+
+    fn mag_wide(&self) -> I64F64 {
+        let (x, y) = (widen(self.x), widen(self.y));
+        let squared = U64F64::from_num(x * x) + U64F64::from_num(y * y);
+
+        I64F64::from_num(squared.sqrt())
+    }
+
+    /// Returns the distance to `other`.
+    pub fn dist(&self, other: Self) -> I32F32 {
+        (*self - other).mag()
+    }
+
+    /// Returns the squared distance to `other`.
+    pub fn dist_squared(&self, other: Self) -> I32F32 {
+        (*self - other).mag_squared()
+    }
+
+    /// Returns the vector resized to the magnitude of 1. A zero vector stays
+    /// zero.
+    pub fn normalize(&self) -> Self {
+        self.resize(I32F32::ONE)
+    }
+
+    /// Returns the vector resized to `mag`, keeping its direction. A zero
+    /// vector stays zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed::types::I32F32;
+    /// use veccentric::Vecc;
+    ///
+    /// let a = Vecc::new(I32F32::from_num(3), I32F32::from_num(4));
+    /// let b = a.resize(I32F32::from_num(10));
+    ///
+    /// assert_eq!(b, Vecc::new(I32F32::from_num(6), I32F32::from_num(8)));
+    /// ```
+    pub fn resize(&self, mag: I32F32) -> Self {
+        if self.is_zero() {
+            return *self;
+        }
+
+        let scale = widen(mag) / self.mag_wide();
+
+        Self::new(narrow(widen(self.x) * scale), narrow(widen(self.y) * scale))
+    }
+
+    /// Returns the vector with its magnitude limited to `limit`.
+    pub fn limit(&self, limit: I32F32) -> Self {
+        if self.mag_wide() > widen(limit) {
+            self.resize(limit)
+        } else {
+            *self
+        }
+    }
+
+    /// Returns the vector rotated by `angle`. Positive angles rotate
+    /// counterclockwise.
+    pub fn rotate(&self, angle: I32F32) -> Self {
+        let (mut x, mut y) = (widen(self.x), widen(self.y));
+        let mut angle = widen(angle).rem_euclid(I64F64::TAU);
+
+        if angle > I64F64::PI {
+            angle -= I64F64::TAU;
+        }
+
+        // CORDIC only converges for angles up to about ±π/2.
+        if angle > I64F64::FRAC_PI_2 {
+            x = -x;
+            y = -y;
+            angle -= I64F64::PI;
+        } else if angle < -I64F64::FRAC_PI_2 {
+            x = -x;
+            y = -y;
+            angle += I64F64::PI;
+        }
+
+        let (x, y) = cordic_rotate(x, y, angle);
+
+        Self::new(narrow(x), narrow(y))
+    }
+
+    /// Returns the angle between the vector and the X axis, in
+    /// `[-π, π]`. The angle of a zero vector is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed::types::I32F32;
+    /// use veccentric::Vecc;
+    ///
+    /// let a = Vecc::new(I32F32::from_num(-2), I32F32::from_num(-2));
+    /// let expected = -I32F32::FRAC_PI_4 * 3;
+    ///
+    /// assert!((a.angle() - expected).abs() < 1e-8);
+    /// ```
+    pub fn angle(&self) -> I32F32 {
+        if self.is_zero() {
+            return I32F32::ZERO;
+        }
+
+        let (x, y) = (widen(self.x), widen(self.y));
+
+        let angle = if x >= 0 {
+            cordic_angle(x, y)
+        } else if y >= 0 {
+            cordic_angle(-x, -y) + I64F64::PI
+        } else {
+            cordic_angle(-x, -y) - I64F64::PI
+        };
+
+        narrow(angle)
+    }
+
+    /// Returns the point at fraction `t` of the way from the vector to
+    /// `other`.
+    pub fn lerp(&self, other: Self, t: I32F32) -> Self {
+        *self + (other - *self) * t
+    }
+}
@@ -0,0 +1,148 @@
+//! Floating point numbers usable as components of the extended vector API.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Floating point number — either [`f32`](f32) or [`f64`](f64).
+///
+/// [`Vecc<T>`](crate::vecc::Vecc) implements the extended API documented on
+/// [`Fecc`](crate::fecc::Fecc) for every `T: Float`, so `Vecc<f32>` has the
+/// same methods as `Vecc<f64>`.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::Vecc;
+///
+/// let a = Vecc::new(3.0_f32, 4.0);
+///
+/// assert_approx_eq!(f32, a.mag(), 5.0);
+/// assert_approx_eq!(f32, a.normalize().mag(), 1.0);
+/// ```
+pub trait Float:
+    Copy
+    + Default
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// `0.0`.
+    const ZERO: Self;
+
+    /// `1.0`.
+    const ONE: Self;
+
+    /// `2.0`.
+    const TWO: Self;
+
+    /// Archimedes' constant (π).
+    const PI: Self;
+
+    /// Converts an [`f64`](f64), rounding if necessary.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts to an [`f64`](f64).
+    fn to_f64(self) -> f64;
+
+    /// See [`f64::sqrt`](f64::sqrt).
+    fn sqrt(self) -> Self;
+
+    /// See [`f64::hypot`](f64::hypot).
+    fn hypot(self, other: Self) -> Self;
+
+    /// See [`f64::atan2`](f64::atan2).
+    fn atan2(self, other: Self) -> Self;
+
+    /// See [`f64::is_finite`](f64::is_finite).
+    fn is_finite(self) -> bool;
+
+    /// See [`f64::round`](f64::round).
+    fn round(self) -> Self;
+
+    /// See [`f64::floor`](f64::floor).
+    fn floor(self) -> Self;
+
+    /// See [`f64::ceil`](f64::ceil).
+    fn ceil(self) -> Self;
+
+    /// See [`f64::min`](f64::min).
+    fn min(self, other: Self) -> Self;
+
+    /// See [`f64::max`](f64::max).
+    fn max(self, other: Self) -> Self;
+
+    /// See [`f64::clamp`](f64::clamp).
+    fn clamp(self, min: Self, max: Self) -> Self;
+
+    /// See [`f64::rem_euclid`](f64::rem_euclid).
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($t:ident) => {
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const TWO: Self = 2.0;
+            const PI: Self = std::$t::consts::PI;
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                $t::hypot(self, other)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            fn round(self) -> Self {
+                $t::round(self)
+            }
+
+            fn floor(self) -> Self {
+                $t::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                $t::ceil(self)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $t::clamp(self, min, max)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                $t::rem_euclid(self, rhs)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
@@ -0,0 +1,300 @@
+//! Grid-based fluid simulation.
+
+use crate::{
+    grid::{Border, Grid2},
+    interpolate::Interpolate,
+    Fecc, Vecc,
+};
+
+/// The offsets of a cell's four neighbors.
+const NEIGHBORS: [Vecc<i64>; 4] = [
+    Vecc { x: 1, y: 0 },
+    Vecc { x: -1, y: 0 },
+    Vecc { x: 0, y: 1 },
+    Vecc { x: 0, y: -1 },
+];
+
+/// Fluid in a closed box, simulated with Jos Stam's *Stable Fluids*
+/// (SIGGRAPH 1999).
+///
+/// The velocity and the density of a dye carried by the fluid are stored on
+/// grids. Positions and velocities are in cells (and cells per second) —
+/// the value of cell `(x, y)` lies at the position `(x, y)`, as in
+/// [`Grid2::sample`](crate::grid::Grid2::sample). The simulation is stable
+/// for any time step, at the cost of some numerical damping.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{fluid::Fluid, Fecc};
+///
+/// let mut fluid = Fluid::new(32, 32, 0.0001, 0.0);
+/// let center = Fecc::new(16.0, 16.0);
+///
+/// for _ in 0..10 {
+///     fluid.add_density(center, 100.0);
+///     fluid.add_force(center, Fecc::new(200.0, 0.0));
+///     fluid.step(0.05);
+/// }
+///
+/// // The dye is pushed to the right.
+/// let right = fluid.sample_density(Fecc::new(18.0, 16.0));
+/// let left = fluid.sample_density(Fecc::new(14.0, 16.0));
+/// assert!(right > left);
+///
+/// // Particles can be advected through the fluid.
+/// assert!(fluid.sample_velocity(center).x > 0.0);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Fluid {
+    /// How fast the velocity spreads out.
+    pub viscosity: f64,
+
+    /// How fast the dye spreads out.
+    pub diffusion: f64,
+
+    /// The number of Gauss–Seidel iterations solving the diffusion and the
+    /// pressure. More iterations are slower but more accurate.
+    pub iterations: usize,
+
+    velocity: Grid2<Fecc>,
+    density: Grid2<f64>,
+    forces: Grid2<Fecc>,
+    sources: Grid2<f64>,
+}
+
+impl Fluid {
+    /// Constructs a new fluid at rest, without dye, with 20 iterations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    pub fn new(
+        width: usize,
+        height: usize,
+        viscosity: f64,
+        diffusion: f64,
+    ) -> Self {
+        assert!(width > 0 && height > 0, "the grid is empty");
+
+        Self {
+            viscosity,
+            diffusion,
+            iterations: 20,
+            velocity: Grid2::new(width, height, Fecc::zero()),
+            density: Grid2::new(width, height, 0.0),
+            forces: Grid2::new(width, height, Fecc::zero()),
+            sources: Grid2::new(width, height, 0.0),
+        }
+    }
+
+    /// Returns the velocity grid.
+    pub fn velocity(&self) -> &Grid2<Fecc> {
+        &self.velocity
+    }
+
+    /// Returns the velocity grid.
+    pub fn velocity_mut(&mut self) -> &mut Grid2<Fecc> {
+        &mut self.velocity
+    }
+
+    /// Returns the density grid.
+    pub fn density(&self) -> &Grid2<f64> {
+        &self.density
+    }
+
+    /// Returns the density grid.
+    pub fn density_mut(&mut self) -> &mut Grid2<f64> {
+        &mut self.density
+    }
+
+    /// Applies the force (per unit of mass) to the cell nearest to the
+    /// position during the next [`step`](crate::fluid::Fluid::step).
+    /// Positions outside the grid are ignored.
+    pub fn add_force(&mut self, position: Fecc, force: Fecc) {
+        if let Some(cell) = self.forces.get_mut(position.round()) {
+            *cell += force;
+        }
+    }
+
+    /// Adds the amount of dye per second to the cell nearest to the
+    /// position during the next [`step`](crate::fluid::Fluid::step).
+    /// Positions outside the grid are ignored.
+    pub fn add_density(&mut self, position: Fecc, amount: f64) {
+        if let Some(cell) = self.sources.get_mut(position.round()) {
+            *cell += amount;
+        }
+    }
+
+    /// Returns the velocity at the position, bilinearly interpolated.
+    pub fn sample_velocity(&self, position: Fecc) -> Fecc {
+        self.velocity.sample(position, Border::Clamp)
+    }
+
+    /// Returns the density at the position, bilinearly interpolated.
+    pub fn sample_density(&self, position: Fecc) -> f64 {
+        self.density.sample(position, Border::Clamp)
+    }
+
+    /// Advances the simulation by `dt` seconds, applying and clearing the
+    /// added forces and dye.
+    pub fn step(&mut self, dt: f64) {
+        let (width, height) = (self.velocity.width(), self.velocity.height());
+        let forces = std::mem::replace(
+            &mut self.forces,
+            Grid2::new(width, height, Fecc::zero()),
+        );
+        let sources = std::mem::replace(
+            &mut self.sources,
+            Grid2::new(width, height, 0.0),
+        );
+
+        for (velocity, force) in
+            self.velocity.cells_mut().iter_mut().zip(forces.cells())
+        {
+            *velocity += *force * dt;
+        }
+
+        for (density, source) in
+            self.density.cells_mut().iter_mut().zip(sources.cells())
+        {
+            *density += source * dt;
+        }
+
+        // Velocity.
+        let previous = self.velocity.clone();
+        relax(
+            &mut self.velocity,
+            &previous,
+            dt * self.viscosity,
+            1.0 + 4.0 * dt * self.viscosity,
+            self.iterations,
+            reflect,
+        );
+        self.project();
+        let previous = self.velocity.clone();
+        self.velocity = advect(&previous, &previous, dt);
+        self.project();
+
+        // Density.
+        let previous = self.density.clone();
+        relax(
+            &mut self.density,
+            &previous,
+            dt * self.diffusion,
+            1.0 + 4.0 * dt * self.diffusion,
+            self.iterations,
+            copy,
+        );
+        self.density = advect(&self.density, &self.velocity, dt);
+    }
+
+    /// Removes the divergence of the velocity, making the fluid
+    /// incompressible.
+    fn project(&mut self) {
+        let velocity = &self.velocity;
+        let (width, height) = (velocity.width(), velocity.height());
+        let divergence = Grid2::from_fn(width, height, |cell| {
+            let at = |offset| neighbor(velocity, cell, offset, reflect);
+            let dx = at(NEIGHBORS[0]).x - at(NEIGHBORS[1]).x;
+            let dy = at(NEIGHBORS[2]).y - at(NEIGHBORS[3]).y;
+
+            -0.5 * (dx + dy)
+        });
+        let mut pressure = Grid2::new(width, height, 0.0);
+        relax(&mut pressure, &divergence, 1.0, 4.0, self.iterations, copy);
+
+        for y in 0..height as i64 {
+            for x in 0..width as i64 {
+                let cell = Vecc::new(x, y);
+                let at = |offset| neighbor(&pressure, cell, offset, copy);
+                let gradient = Fecc::new(
+                    at(NEIGHBORS[0]) - at(NEIGHBORS[1]),
+                    at(NEIGHBORS[2]) - at(NEIGHBORS[3]),
+                );
+
+                self.velocity[cell] -= gradient * 0.5;
+            }
+        }
+    }
+}
+
+/// Solves `c · x - a · Σ neighbors(x) = previous` with Gauss–Seidel
+/// iterations.
+fn relax<T>(
+    grid: &mut Grid2<T>,
+    previous: &Grid2<T>,
+    a: f64,
+    c: f64,
+    iterations: usize,
+    boundary: fn(T, Vecc<i64>) -> T,
+) where
+    T: Interpolate,
+{
+    if a == 0.0 {
+        return;
+    }
+
+    for _ in 0..iterations {
+        for y in 0..grid.height() as i64 {
+            for x in 0..grid.width() as i64 {
+                let cell = Vecc::new(x, y);
+                let sum = NEIGHBORS
+                    .iter()
+                    .map(|offset| neighbor(grid, cell, *offset, boundary))
+                    .reduce(|a, b| a + b)
+                    .unwrap();
+
+                grid[cell] = (previous[cell] + sum * a) * (1.0 / c);
+            }
+        }
+    }
+}
+
+/// Moves the values along the velocity, tracing each cell back in time.
+fn advect<T>(grid: &Grid2<T>, velocity: &Grid2<Fecc>, dt: f64) -> Grid2<T>
+where
+    T: Interpolate,
+{
+    let max = Fecc::new(grid.width() as f64 - 1.0, grid.height() as f64 - 1.0);
+
+    Grid2::from_fn(grid.width(), grid.height(), |cell| {
+        let position = Fecc::new(cell.x as f64, cell.y as f64);
+        let origin = position - velocity[cell] * dt;
+        let origin =
+            Fecc::new(origin.x.clamp(0.0, max.x), origin.y.clamp(0.0, max.y));
+
+        grid.sample(origin, Border::Clamp)
+    })
+}
+
+/// Returns the value of the neighbor of the cell, or the value computed by
+/// `boundary` from the cell's own value if the neighbor lies outside the
+/// grid.
+fn neighbor<T>(
+    grid: &Grid2<T>,
+    cell: Vecc<i64>,
+    offset: Vecc<i64>,
+    boundary: fn(T, Vecc<i64>) -> T,
+) -> T
+where
+    T: Copy,
+{
+    grid.get(cell + offset)
+        .copied()
+        .unwrap_or_else(|| boundary(grid[cell], offset))
+}
+
+/// The walls reflect the velocity — the fluid doesn't flow through them.
+fn reflect(velocity: Fecc, offset: Vecc<i64>) -> Fecc {
+    if offset.x != 0 {
+        Fecc::new(-velocity.x, velocity.y)
+    } else {
+        Fecc::new(velocity.x, -velocity.y)
+    }
+}
+
+/// Nothing flows through the walls.
+fn copy<T>(value: T, _offset: Vecc<i64>) -> T {
+    value
+}
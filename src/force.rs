@@ -0,0 +1,295 @@
+//! Common forces.
+
+use crate::{spatial::SpatialHash, Fecc};
+
+/// How the magnitude of an attracting force changes with distance.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Falloff {
+    /// The magnitude doesn't depend on the distance.
+    Constant,
+
+    /// The magnitude decreases linearly, reaching zero at `range`.
+    Linear {
+        /// The distance at which the force vanishes.
+        range: f64,
+    },
+
+    /// The magnitude is inversely proportional to the distance.
+    Inverse,
+
+    /// The magnitude is inversely proportional to the squared distance, like
+    /// gravity. `softening` is added to the distance (in quadrature) so that
+    /// the force doesn't blow up when the bodies get very close.
+    InverseSquare {
+        /// The softening length.
+        softening: f64,
+    },
+}
+
+impl Falloff {
+    fn scale(&self, dist: f64) -> f64 {
+        match *self {
+            Falloff::Constant => 1.0,
+            Falloff::Linear { range } => {
+                if range > 0.0 {
+                    (1.0 - dist / range).max(0.0)
+                } else {
+                    0.0
+                }
+            }
+            Falloff::Inverse => 1.0 / dist,
+            Falloff::InverseSquare { softening } => {
+                1.0 / (dist * dist + softening * softening)
+            }
+        }
+    }
+}
+
+/// Returns the force pulling `point` towards `center`. A negative `strength`
+/// pushes the point away. At `center` itself the force is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{
+///     force::{self, Falloff},
+///     Fecc,
+/// };
+///
+/// let force = force::attract(
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(0.0, 2.0),
+///     8.0,
+///     Falloff::InverseSquare { softening: 0.0 },
+/// );
+///
+/// assert_approx_eq!(f64, force.x, 0.0);
+/// assert_approx_eq!(f64, force.y, 2.0);
+/// ```
+pub fn attract(
+    point: Fecc,
+    center: Fecc,
+    strength: f64,
+    falloff: Falloff,
+) -> Fecc {
+    let offset = center - point;
+
+    if offset.is_zero() {
+        return Fecc::zero();
+    }
+
+    let dist = offset.mag();
+
+    offset / dist * strength * falloff.scale(dist)
+}
+
+/// Returns the force pushing `point` away from `center`. Equivalent to
+/// [`attract`](crate::force::attract) with the strength negated.
+pub fn repel(
+    point: Fecc,
+    center: Fecc,
+    strength: f64,
+    falloff: Falloff,
+) -> Fecc {
+    attract(point, center, -strength, falloff)
+}
+
+/// Fixed point attracting (or repelling) other points, e.g. a gravity well,
+/// a magnet or the mouse cursor.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{
+///     force::{Attractor, Falloff},
+///     Fecc,
+/// };
+///
+/// let cursor = Attractor::repeller(
+///     Fecc::new(10.0, 10.0),
+///     50.0,
+///     Falloff::Linear { range: 20.0 },
+/// );
+///
+/// let force = cursor.force(Fecc::new(5.0, 10.0));
+/// assert!(force.x < 0.0);
+///
+/// let force = cursor.force(Fecc::new(50.0, 10.0));
+/// assert!(force.is_zero());
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Attractor {
+    /// The attracting point.
+    pub center: Fecc,
+
+    /// The strength of the force. Negative values repel.
+    pub strength: f64,
+
+    /// The falloff law.
+    pub falloff: Falloff,
+}
+
+impl Attractor {
+    /// Constructs a new attractor.
+    pub fn new(center: Fecc, strength: f64, falloff: Falloff) -> Self {
+        Self {
+            center,
+            strength,
+            falloff,
+        }
+    }
+
+    /// Constructs a new repeller, i.e. an attractor with negated strength.
+    pub fn repeller(center: Fecc, strength: f64, falloff: Falloff) -> Self {
+        Self::new(center, -strength, falloff)
+    }
+
+    /// Returns the force acting on `point`.
+    pub fn force(&self, point: Fecc) -> Fecc {
+        attract(point, self.center, self.strength, self.falloff)
+    }
+}
+
+/// Returns the drag force proportional to the velocity, `-k * v`. Suitable
+/// for slow motion through a viscous medium.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{force, Fecc};
+///
+/// let drag = force::linear_drag(Fecc::new(4.0, 0.0), 0.5);
+///
+/// assert_approx_eq!(f64, drag.x, -2.0);
+/// ```
+pub fn linear_drag(velocity: Fecc, k: f64) -> Fecc {
+    -velocity * k
+}
+
+/// Returns the drag force proportional to the squared speed,
+/// `-k * |v| * v`. Suitable for fast motion through air or water.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{force, Fecc};
+///
+/// let drag = force::quadratic_drag(Fecc::new(4.0, 0.0), 0.5);
+///
+/// assert_approx_eq!(f64, drag.x, -8.0);
+/// ```
+pub fn quadratic_drag(velocity: Fecc, k: f64) -> Fecc {
+    -velocity * velocity.mag() * k
+}
+
+/// Returns the sum of [`linear_drag`](crate::force::linear_drag) and
+/// [`quadratic_drag`](crate::force::quadratic_drag).
+///
+/// Note that with a large time step an explicit integrator can overshoot
+/// and reverse the velocity instead of bringing it to rest.
+pub fn drag(velocity: Fecc, linear: f64, quadratic: f64) -> Fecc {
+    linear_drag(velocity, linear) + quadratic_drag(velocity, quadratic)
+}
+
+/// Returns the total force acting on each point due to its neighbors within
+/// `cutoff`. Pairs further apart don't interact, so all the forces are zero
+/// if the cutoff isn't positive.
+///
+/// `law` maps the distance between two points to the magnitude of the force
+/// between them. Positive values push the points apart, negative values pull
+/// them together. Both points of a pair receive opposite forces. Coincident
+/// points don't interact.
+///
+/// A [`SpatialHash`](crate::spatial::SpatialHash) is used to find the
+/// neighbors, so the cost is roughly linear in the number of points if the
+/// cutoff is small.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{force, Fecc};
+///
+/// let points = [
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(1.0, 0.0),
+///     Fecc::new(100.0, 0.0),
+/// ];
+/// let forces =
+///     force::pairwise(&points, 5.0, force::soft_repulsion(10.0, 2.0));
+///
+/// assert!(forces[0].x < 0.0);
+/// assert!(forces[1].x > 0.0);
+/// assert!(forces[2].is_zero());
+///
+/// let forces =
+///     force::pairwise(&points, 0.0, force::soft_repulsion(10.0, 2.0));
+///
+/// assert!(forces.iter().all(|force| force.is_zero()));
+/// ```
+pub fn pairwise<L>(points: &[Fecc], cutoff: f64, law: L) -> Vec<Fecc>
+where
+    L: Fn(f64) -> f64,
+{
+    let mut forces = vec![Fecc::zero(); points.len()];
+
+    // The spatial hash needs a positive cell size.
+    if cutoff <= 0.0 || cutoff.is_nan() {
+        return forces;
+    }
+
+    let hash = SpatialHash::from_points(cutoff, points);
+
+    for (i, &point) in points.iter().enumerate() {
+        for j in hash.query(point, cutoff) {
+            if j <= i {
+                continue;
+            }
+
+            let offset = point - points[j];
+
+            if offset.is_zero() {
+                continue;
+            }
+
+            let dist = offset.mag();
+            let force = offset / dist * law(dist);
+
+            forces[i] += force;
+            forces[j] -= force;
+        }
+    }
+
+    forces
+}
+
+/// Returns the [Lennard-Jones](https://en.wikipedia.org/wiki/Lennard-Jones_potential)
+/// force law for [`pairwise`](crate::force::pairwise). `epsilon` is the
+/// depth of the potential well and `sigma` is the distance at which the
+/// potential is zero. Points repel closer than `2^(1/6) * sigma` and attract
+/// further away.
+///
+/// The force grows very fast at short distances, so small time steps are
+/// needed.
+pub fn lennard_jones(epsilon: f64, sigma: f64) -> impl Fn(f64) -> f64 {
+    move |dist| {
+        let s6 = (sigma / dist).powi(6);
+
+        24.0 * epsilon / dist * (2.0 * s6 * s6 - s6)
+    }
+}
+
+/// Returns a force law for [`pairwise`](crate::force::pairwise) pushing
+/// points apart with a force decreasing linearly from `strength` at zero
+/// distance to zero at `radius`.
+pub fn soft_repulsion(strength: f64, radius: f64) -> impl Fn(f64) -> f64 {
+    move |dist| {
+        if radius > 0.0 {
+            strength * (1.0 - dist / radius).max(0.0)
+        } else {
+            0.0
+        }
+    }
+}
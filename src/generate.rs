@@ -0,0 +1,234 @@
+//! Point generators — shapes and patterns without manual loops.
+
+use std::f64::consts::{PI, TAU};
+
+#[cfg(feature = "random")]
+use rand::Rng;
+
+use crate::{Aabb, Angle, Fecc, Polygon};
+
+/// Relative tolerance for fitting the last row or column of a lattice into
+/// a box, so that e.g. a box 1.0 wide fits 11 points 0.1 apart.
+const LATTICE_TOLERANCE: f64 = 1e-9;
+
+/// Returns `n` points evenly spaced on the circle, starting at angle `0`
+/// and going counterclockwise (with the y axis pointing up).
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{generate, Fecc};
+///
+/// let ticks = generate::circle_points(Fecc::new(1.0, 1.0), 2.0, 12)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(ticks.len(), 12);
+/// assert_approx_eq!(f64, ticks[0].x, 3.0);
+/// assert_approx_eq!(f64, ticks[3].y, 3.0);
+/// ```
+pub fn circle_points(
+    center: Fecc,
+    radius: f64,
+    n: usize,
+) -> impl Iterator<Item = Fecc> {
+    points_around(center, radius, n, 0.0)
+}
+
+/// Returns the regular polygon with `n` vertices on the circle, the first
+/// one at angle `rotation`.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{generate, Angular, Fecc};
+///
+/// let diamond = generate::regular_polygon(Fecc::zero(), 1.0, 4, 90.deg());
+///
+/// assert_approx_eq!(f64, diamond.0[0].y, 1.0);
+/// assert_approx_eq!(f64, diamond.signed_area(), 2.0);
+/// ```
+pub fn regular_polygon<A>(
+    center: Fecc,
+    radius: f64,
+    n: usize,
+    rotation: A,
+) -> Polygon
+where
+    A: Into<Angle>,
+{
+    points_around(center, radius, n, *rotation.into()).collect()
+}
+
+/// Returns `n` points evenly distributed in the disc along the sunflower
+/// (Vogel) spiral — each point is rotated by the golden angle from the
+/// previous one and the distance from the center grows with the square
+/// root of its index.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{generate, Fecc};
+///
+/// let center = Fecc::new(5.0, 5.0);
+/// let seeds = generate::sunflower_points(center, 2.0, 100)
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(seeds.len(), 100);
+/// assert!(seeds.iter().all(|seed| seed.dist(center) <= 2.0));
+/// ```
+pub fn sunflower_points(
+    center: Fecc,
+    radius: f64,
+    n: usize,
+) -> impl Iterator<Item = Fecc> {
+    let golden_angle = PI * (3.0 - 5.0_f64.sqrt());
+
+    (0..n).map(move |i| {
+        let distance = radius * ((i as f64 + 0.5) / n as f64).sqrt();
+
+        center + Fecc::from_angle(golden_angle * i as f64) * distance
+    })
+}
+
+/// Returns the points of a square lattice with the specified spacing
+/// covering the box, row by row, starting at its minimal corner.
+///
+/// # Panics
+///
+/// Panics if `spacing` isn't positive.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{generate, Aabb, Fecc};
+///
+/// let aabb = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(4.0, 2.0));
+/// let points = generate::grid_points(&aabb, 1.0).collect::<Vec<_>>();
+///
+/// assert_eq!(points.len(), 15);
+/// assert_eq!(points[0], Fecc::new(0.0, 0.0));
+/// assert_eq!(points[14], Fecc::new(4.0, 2.0));
+/// ```
+pub fn grid_points(aabb: &Aabb, spacing: f64) -> impl Iterator<Item = Fecc> {
+    lattice(aabb, Fecc::new(spacing, spacing), 0.0)
+}
+
+/// Returns the points of a hexagonal (triangular) lattice with the
+/// specified distance between neighbors covering the box, row by row,
+/// starting at its minimal corner. Every other row is offset by half of the
+/// spacing.
+///
+/// # Panics
+///
+/// Panics if `spacing` isn't positive.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{generate, Aabb, Fecc};
+///
+/// let aabb = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(4.0, 2.0));
+/// let points = generate::hex_points(&aabb, 1.0).collect::<Vec<_>>();
+///
+/// // The first row has 5 points, the second one 4.
+/// assert_approx_eq!(f64, points[5].x, 0.5);
+/// assert_approx_eq!(f64, points[0].dist(points[5]), 1.0);
+/// assert!(points.iter().all(|point| point.x <= 4.0 && point.y <= 2.0));
+/// ```
+pub fn hex_points(aabb: &Aabb, spacing: f64) -> impl Iterator<Item = Fecc> {
+    let step = Fecc::new(spacing, spacing * 3.0_f64.sqrt() / 2.0);
+
+    lattice(aabb, step, spacing / 2.0)
+}
+
+/// Returns one random point in each cell of a `cols` × `rows` grid covering
+/// the box, row by row — a cheaper alternative to Poisson-disk sampling for
+/// covering a region evenly but randomly. `jitter` is the fraction of the
+/// cell the point can be moved within: `0.0` places the points at the
+/// centers of the cells, `1.0` anywhere in the cells.
+///
+/// # Examples
+///
+/// ```
+/// use rand::{rngs::SmallRng, SeedableRng};
+/// use veccentric::{generate, shape::Contains, Aabb, Fecc};
+///
+/// let mut rng = SmallRng::from_seed([0xdd; 32]);
+/// let canvas = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(40.0, 30.0));
+/// let points =
+///     generate::stratified_samples(&canvas, 4, 3, 1.0, &mut rng)
+///         .collect::<Vec<_>>();
+///
+/// assert_eq!(points.len(), 12);
+///
+/// // The first point lies in the first cell.
+/// let cell = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(10.0, 10.0));
+/// assert!(cell.contains(points[0]));
+/// ```
+#[cfg(feature = "random")]
+#[doc(cfg(feature = "random"))]
+pub fn stratified_samples<'a, R>(
+    aabb: &Aabb,
+    cols: usize,
+    rows: usize,
+    jitter: f64,
+    rng: &'a mut R,
+) -> impl Iterator<Item = Fecc> + 'a
+where
+    R: Rng,
+{
+    let min = aabb.min;
+    let cell =
+        Fecc::new(aabb.width() / cols as f64, aabb.height() / rows as f64);
+
+    (0..rows)
+        .flat_map(move |row| (0..cols).map(move |col| (col, row)))
+        .map(move |(col, row)| {
+            let mut offset = || 0.5 + (rng.gen::<f64>() - 0.5) * jitter;
+            let (x, y) = (col as f64 + offset(), row as f64 + offset());
+
+            min + Fecc::new(x * cell.x, y * cell.y)
+        })
+}
+
+/// Returns the points of a lattice covering the box, with every other row
+/// offset by `offset`.
+fn lattice(aabb: &Aabb, step: Fecc, offset: f64) -> impl Iterator<Item = Fecc> {
+    assert!(step.x > 0.0, "spacing must be positive");
+
+    let Aabb { min, max } = *aabb;
+    let count = |length: f64, step: f64| {
+        if length < 0.0 {
+            0
+        } else {
+            (length / step + LATTICE_TOLERANCE).floor() as usize + 1
+        }
+    };
+    let rows = count(max.y - min.y, step.y);
+
+    (0..rows).flat_map(move |row| {
+        let shift = if row % 2 == 0 { 0.0 } else { offset };
+        let columns = count(max.x - min.x - shift, step.x);
+        let y = min.y + row as f64 * step.y;
+
+        (0..columns).map(move |column| {
+            Fecc::new(min.x + shift + column as f64 * step.x, y)
+        })
+    })
+}
+
+/// Returns `n` points evenly spaced on the circle, the first one at angle
+/// `rotation`.
+fn points_around(
+    center: Fecc,
+    radius: f64,
+    n: usize,
+    rotation: f64,
+) -> impl Iterator<Item = Fecc> {
+    (0..n).map(move |i| {
+        center + Fecc::from_angle(rotation + TAU * i as f64 / n as f64) * radius
+    })
+}
@@ -0,0 +1,316 @@
+//! Two-dimensional grids of values.
+
+use std::ops::{Index, IndexMut};
+
+use crate::{
+    interpolate::{self, Interpolate},
+    Fecc, Vecc,
+};
+
+/// How positions outside a [`Grid2`](crate::grid::Grid2) are treated when
+/// sampling.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub enum Border {
+    /// Positions are clamped to the grid, i.e. the values on the border
+    /// extend forever.
+    #[default]
+    Clamp,
+
+    /// The grid repeats in both directions.
+    Wrap,
+}
+
+/// How [`splat_points`](crate::grid::Grid2::splat_points) distributes a
+/// point among the cells.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub enum Kernel {
+    /// The whole point goes to the nearest cell.
+    #[default]
+    Nearest,
+
+    /// The point is split among the four surrounding cells with bilinear
+    /// weights.
+    Bilinear,
+}
+
+/// Rectangular grid of values stored row by row.
+///
+/// Cells are addressed by [`Vecc<i64>`](crate::vecc::Vecc) with `x` being
+/// the column and `y` the row.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{grid::Grid2, Vecc};
+///
+/// let mut grid = Grid2::new(3, 2, 0);
+/// grid[Vecc::new(2, 1)] = 5;
+///
+/// assert_eq!(grid.get(Vecc::new(2, 1)), Some(&5));
+/// assert_eq!(grid.get(Vecc::new(3, 1)), None);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid2<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid2<T> {
+    /// Constructs a new grid filled with `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Constructs a new grid with the value of each cell computed by `f`.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(Vecc<i64>) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(Vecc::new(x as i64, y as i64)))
+            .collect();
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Returns the number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Checks whether the cell lies within the grid.
+    pub fn contains(&self, cell: Vecc<i64>) -> bool {
+        self.ix(cell).is_some()
+    }
+
+    /// Returns a reference to the value in the cell or `None` if the cell
+    /// is out of bounds.
+    pub fn get(&self, cell: Vecc<i64>) -> Option<&T> {
+        self.ix(cell).map(|ix| &self.cells[ix])
+    }
+
+    /// Returns a mutable reference to the value in the cell or `None` if
+    /// the cell is out of bounds.
+    pub fn get_mut(&mut self, cell: Vecc<i64>) -> Option<&mut T> {
+        self.ix(cell).map(move |ix| &mut self.cells[ix])
+    }
+
+    /// Returns the values row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Returns the values row by row.
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Returns an iterator over the cells and their values.
+    pub fn iter(&self) -> impl Iterator<Item = (Vecc<i64>, &T)> + '_ {
+        let width = self.width;
+
+        self.cells.iter().enumerate().map(move |(ix, value)| {
+            (Vecc::new((ix % width) as i64, (ix / width) as i64), value)
+        })
+    }
+
+    fn ix(&self, cell: Vecc<i64>) -> Option<usize> {
+        if (0..self.width as i64).contains(&cell.x)
+            && (0..self.height as i64).contains(&cell.y)
+        {
+            Some(cell.x as usize + cell.y as usize * self.width)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Grid2<T>
+where
+    T: Interpolate,
+{
+    /// Returns the value at a continuous position, bilinearly interpolated
+    /// between the four surrounding cells. The value of cell `(x, y)` lies
+    /// exactly at the position `(x, y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{
+    ///     grid::{Border, Grid2},
+    ///     Fecc,
+    /// };
+    ///
+    /// let heightmap = Grid2::from_fn(2, 2, |cell| (cell.x + cell.y) as f64);
+    ///
+    /// let h = heightmap.sample(Fecc::new(0.5, 0.5), Border::Clamp);
+    /// assert_approx_eq!(f64, h, 1.0);
+    ///
+    /// // Halfway between the last column and the first one.
+    /// let h = heightmap.sample(Fecc::new(1.5, 0.0), Border::Wrap);
+    /// assert_approx_eq!(f64, h, 0.5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty.
+    pub fn sample(&self, position: Fecc, border: Border) -> T {
+        assert!(!self.cells.is_empty(), "the grid is empty");
+
+        let cell = position.floor();
+        let t = position - Fecc::new(cell.x as f64, cell.y as f64);
+        let at = |dx: i64, dy: i64| {
+            let cell = Vecc::new(cell.x + dx, cell.y + dy);
+
+            self[self.resolve(cell, border)]
+        };
+
+        let bottom = interpolate::lerp(at(0, 0), at(1, 0), t.x);
+        let top = interpolate::lerp(at(0, 1), at(1, 1), t.x);
+
+        interpolate::lerp(bottom, top, t.y)
+    }
+
+    fn resolve(&self, cell: Vecc<i64>, border: Border) -> Vecc<i64> {
+        let (width, height) = (self.width as i64, self.height as i64);
+
+        match border {
+            Border::Clamp => Vecc::new(
+                cell.x.clamp(0, width - 1),
+                cell.y.clamp(0, height - 1),
+            ),
+            Border::Wrap => {
+                Vecc::new(cell.x.rem_euclid(width), cell.y.rem_euclid(height))
+            }
+        }
+    }
+}
+
+impl Grid2<f64> {
+    /// Adds `1.0` for each point to the cells around it, building a density
+    /// map. Points (or their parts) outside the grid are ignored.
+    ///
+    /// The value of cell `(x, y)` lies at the position `(x, y)`, as in
+    /// [`sample`](crate::grid::Grid2::sample).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{
+    ///     grid::{Grid2, Kernel},
+    ///     Fecc, Vecc,
+    /// };
+    ///
+    /// let mut density = Grid2::new(4, 4, 0.0);
+    /// density.splat_points(
+    ///     &[Fecc::new(1.5, 1.0), Fecc::new(1.1, 0.9)],
+    ///     Kernel::Nearest,
+    /// );
+    ///
+    /// assert_approx_eq!(f64, density[Vecc::new(1, 1)], 1.0);
+    /// assert_approx_eq!(f64, density[Vecc::new(2, 1)], 1.0);
+    ///
+    /// let mut density = Grid2::new(4, 4, 0.0);
+    /// density.splat_points(&[Fecc::new(1.5, 1.0)], Kernel::Bilinear);
+    ///
+    /// assert_approx_eq!(f64, density[Vecc::new(1, 1)], 0.5);
+    /// assert_approx_eq!(f64, density[Vecc::new(2, 1)], 0.5);
+    /// ```
+    pub fn splat_points(&mut self, points: &[Fecc], kernel: Kernel) {
+        for &point in points {
+            match kernel {
+                Kernel::Nearest => {
+                    if let Some(value) = self.get_mut(point.round()) {
+                        *value += 1.0;
+                    }
+                }
+                Kernel::Bilinear => {
+                    let cell = point.floor();
+                    let t = point - Fecc::new(cell.x as f64, cell.y as f64);
+                    let weights = [
+                        (0, 0, (1.0 - t.x) * (1.0 - t.y)),
+                        (1, 0, t.x * (1.0 - t.y)),
+                        (0, 1, (1.0 - t.x) * t.y),
+                        (1, 1, t.x * t.y),
+                    ];
+
+                    for &(dx, dy, weight) in &weights {
+                        let cell = Vecc::new(cell.x + dx, cell.y + dy);
+
+                        if let Some(value) = self.get_mut(cell) {
+                            *value += weight;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the largest value or `None` if the grid is empty.
+    pub fn max_value(&self) -> Option<f64> {
+        self.cells.iter().copied().reduce(f64::max)
+    }
+
+    /// Returns the sum of the values.
+    pub fn sum(&self) -> f64 {
+        self.cells.iter().sum()
+    }
+
+    /// Scales the values so that the largest one is `1.0`. Does nothing if
+    /// the largest value isn't positive.
+    pub fn normalize_max(&mut self) {
+        if let Some(max) = self.max_value().filter(|&max| max > 0.0) {
+            self.scale(1.0 / max);
+        }
+    }
+
+    /// Scales the values so that they sum up to `1.0`. Does nothing if the
+    /// sum is zero.
+    pub fn normalize_sum(&mut self) {
+        let sum = self.sum();
+
+        if sum != 0.0 {
+            self.scale(1.0 / sum);
+        }
+    }
+
+    fn scale(&mut self, factor: f64) {
+        for value in &mut self.cells {
+            *value *= factor;
+        }
+    }
+}
+
+impl<T> Index<Vecc<i64>> for Grid2<T> {
+    type Output = T;
+
+    fn index(&self, cell: Vecc<i64>) -> &T {
+        self.get(cell).expect("cell out of bounds")
+    }
+}
+
+impl<T> IndexMut<Vecc<i64>> for Grid2<T> {
+    fn index_mut(&mut self, cell: Vecc<i64>) -> &mut T {
+        self.get_mut(cell).expect("cell out of bounds")
+    }
+}
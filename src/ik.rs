@@ -0,0 +1,239 @@
+//! Inverse kinematics with
+//! [FABRIK](http://www.andreasaristidou.com/FABRIK.html) (Forward And
+//! Backward Reaching Inverse Kinematics).
+//!
+//! A chain is a list of joints connected by rigid segments. The first joint
+//! (the root) stays in place while the others are moved so that the last one
+//! (the tip) reaches the target. If the target is out of reach, the chain
+//! stretches towards it.
+
+use crate::{Angle, Fecc};
+
+/// Moves the joints so that the last one reaches `target`, keeping the first
+/// one in place and the distances between consecutive joints equal to
+/// `lengths`. Returns the remaining distance between the tip and the target.
+///
+/// More iterations give more accurate results. The current positions of the
+/// joints are the starting point, so solving a slowly moving target every
+/// frame needs only a few iterations.
+///
+/// # Panics
+///
+/// Panics if there isn't exactly one length per pair of consecutive joints.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{ik, Fecc};
+///
+/// let mut arm = [Fecc::zero(), Fecc::new(1.0, 0.0), Fecc::new(2.0, 0.0)];
+/// let target = Fecc::new(1.0, 1.0);
+///
+/// let error = ik::fabrik(&mut arm, &[1.0, 1.0], target, 10);
+///
+/// assert!(error < 1e-6);
+/// assert!(arm[0].is_zero());
+/// assert_approx_eq!(f64, arm[0].dist(arm[1]), 1.0);
+/// assert_approx_eq!(f64, arm[1].dist(arm[2]), 1.0);
+/// ```
+pub fn fabrik(
+    joints: &mut [Fecc],
+    lengths: &[f64],
+    target: Fecc,
+    iterations: usize,
+) -> f64 {
+    solve(
+        joints,
+        lengths,
+        &[],
+        Fecc::new(1.0, 0.0),
+        target,
+        iterations,
+    )
+}
+
+/// Chain of joints with optional angle constraints, solved with
+/// [`fabrik`](crate::ik::fabrik).
+///
+/// Each segment can be limited to a range of angles relative to the
+/// previous segment (or to [`base`](crate::ik::Chain::base) for the first
+/// segment). Positive angles mean counterclockwise bending.
+///
+/// # Examples
+///
+/// An arm whose elbow only bends one way.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// # use std::f64::consts::PI;
+/// use veccentric::{ik::Chain, Fecc};
+///
+/// let joints = vec![Fecc::zero(), Fecc::new(1.0, 0.0), Fecc::new(2.0, 0.0)];
+/// let mut arm = Chain::new(joints).with_limit(1, 0.0, PI);
+///
+/// // Reachable only by bending the elbow clockwise...
+/// arm.solve(Fecc::new(1.0, -1.0), 20);
+///
+/// // ...so it bends counterclockwise instead.
+/// let (root, elbow, tip) = match arm.joints() {
+///     &[root, elbow, tip] => (root, elbow, tip),
+///     _ => unreachable!(),
+/// };
+/// assert!((elbow - root).cross(tip - elbow) >= -1e-9);
+/// assert_approx_eq!(f64, elbow.dist(tip), 1.0);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Chain {
+    /// The direction the first segment's limit is relative to.
+    pub base: Angle,
+
+    joints: Vec<Fecc>,
+    lengths: Vec<f64>,
+    limits: Vec<Option<(f64, f64)>>,
+}
+
+impl Chain {
+    /// Constructs a new unconstrained chain. The lengths of the segments are
+    /// the distances between the joints.
+    pub fn new(joints: Vec<Fecc>) -> Self {
+        let lengths: Vec<f64> = joints
+            .windows(2)
+            .map(|pair| pair[0].dist(pair[1]))
+            .collect();
+        let limits = vec![None; lengths.len()];
+
+        Self {
+            base: Angle::default(),
+            joints,
+            lengths,
+            limits,
+        }
+    }
+
+    /// Limits the angle of the segment (between the joints `segment` and
+    /// `segment + 1`) relative to the previous one to the range
+    /// `[min, max]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there's no such segment or if `min` is greater than `max`.
+    pub fn with_limit<A, B>(mut self, segment: usize, min: A, max: B) -> Self
+    where
+        A: Into<Angle>,
+        B: Into<Angle>,
+    {
+        let (min, max) = (*min.into(), *max.into());
+        assert!(min <= max, "the minimal angle is greater than the maximal");
+
+        self.limits[segment] = Some((min, max));
+
+        self
+    }
+
+    /// Sets the direction the first segment's limit is relative to.
+    pub fn with_base<A>(mut self, base: A) -> Self
+    where
+        A: Into<Angle>,
+    {
+        self.base = base.into();
+
+        self
+    }
+
+    /// Returns the joints, from the root to the tip.
+    pub fn joints(&self) -> &[Fecc] {
+        &self.joints
+    }
+
+    /// Returns the lengths of the segments.
+    pub fn lengths(&self) -> &[f64] {
+        &self.lengths
+    }
+
+    /// Returns the last joint.
+    pub fn tip(&self) -> Option<Fecc> {
+        self.joints.last().copied()
+    }
+
+    /// Moves the whole chain so that the root is at the specified position.
+    pub fn set_root(&mut self, root: Fecc) {
+        if let Some(&old) = self.joints.first() {
+            let offset = root - old;
+
+            for joint in &mut self.joints {
+                *joint += offset;
+            }
+        }
+    }
+
+    /// Moves the joints so that the tip reaches `target`, respecting the
+    /// limits. Returns the remaining distance between the tip and the
+    /// target.
+    pub fn solve(&mut self, target: Fecc, iterations: usize) -> f64 {
+        solve(
+            &mut self.joints,
+            &self.lengths,
+            &self.limits,
+            Fecc::from_angle(self.base),
+            target,
+            iterations,
+        )
+    }
+}
+
+fn solve(
+    joints: &mut [Fecc],
+    lengths: &[f64],
+    limits: &[Option<(f64, f64)>],
+    base: Fecc,
+    target: Fecc,
+    iterations: usize,
+) -> f64 {
+    assert_eq!(lengths.len() + 1, joints.len().max(1));
+
+    let (root, n) = match joints.first() {
+        Some(&root) => (root, joints.len()),
+        None => return 0.0,
+    };
+
+    for _ in 0..iterations {
+        // Backward pass — from the target to the root.
+        joints[n - 1] = target;
+
+        for i in (0..n - 1).rev() {
+            let direction = direction(joints[i + 1], joints[i], base);
+            joints[i] = joints[i + 1] + direction * lengths[i];
+        }
+
+        // Forward pass — from the root to the tip, applying the limits.
+        joints[0] = root;
+        let mut previous = base;
+
+        for i in 0..n - 1 {
+            let mut direction = direction(joints[i], joints[i + 1], previous);
+
+            if let Some(&Some((min, max))) = limits.get(i) {
+                let bend = previous.angle_to(direction).clamp(min, max);
+                direction = previous.rotate(bend);
+            }
+
+            joints[i + 1] = joints[i] + direction * lengths[i];
+            previous = direction;
+        }
+    }
+
+    joints[n - 1].dist(target)
+}
+
+/// Returns the unit vector pointing from `from` to `to` or `fallback` if the
+/// points coincide.
+fn direction(from: Fecc, to: Fecc, fallback: Fecc) -> Fecc {
+    let direction = (to - from).normalize();
+
+    if direction.is_zero() {
+        fallback
+    } else {
+        direction
+    }
+}
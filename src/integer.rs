@@ -0,0 +1,225 @@
+//! Implementation of the integer vector aliases —
+//! [`Ivecc`](crate::integer::Ivecc), [`Lvecc`](crate::integer::Lvecc) and
+//! [`Uvecc`](crate::integer::Uvecc).
+//!
+//! They share an API suited for grids and tile maps: neighborhoods,
+//! grid distances, overflow-aware arithmetic and conversion to
+//! [`Fecc`](crate::fecc::Fecc).
+
+use crate::{Fecc, Vecc};
+
+/// Vector with two [`i32`](i32) components.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::Ivecc;
+///
+/// let tile = Ivecc::new(2, -1);
+/// let neighbors: Vec<Ivecc> = tile.neighbors4().collect();
+///
+/// assert_eq!(neighbors.len(), 4);
+/// assert!(neighbors.contains(&Ivecc::new(2, -2)));
+/// assert!(neighbors.iter().all(|&n| tile.manhattan(n) == 1));
+///
+/// assert_eq!(tile.chebyshev(Ivecc::new(5, 1)), 3);
+///
+/// // Tiles within the radius of 5 from the origin.
+/// let in_range = |tile: Ivecc| tile.mag_squared() <= 5 * 5;
+///
+/// assert!(in_range(Ivecc::new(3, -4)));
+/// assert!(!in_range(Ivecc::new(4, -4)));
+/// assert_eq!(Ivecc::new(4, -4).mag_floor(), 5);
+/// assert_eq!(Ivecc::new(i32::MAX, i32::MAX).mag_floor(), 3_037_000_498);
+/// assert_eq!(tile.dist_squared(Ivecc::new(5, 3)), 25);
+/// assert_eq!(tile.checked_add(Ivecc::new(i32::MAX, 0)), None);
+/// ```
+pub type Ivecc = Vecc<i32>;
+
+/// Vector with two [`i64`](i64) components — the type returned by
+/// [`Fecc::floor`](crate::fecc::Fecc::floor) and similar methods.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{Fecc, Lvecc};
+///
+/// let cell: Lvecc = Fecc::new(3.7, -0.2).floor();
+///
+/// assert_eq!(cell, Lvecc::new(3, -1));
+/// assert_eq!(cell.neighbors8().count(), 8);
+/// assert_approx_eq!(f64, cell.to_fecc().y, -1.0);
+/// ```
+pub type Lvecc = Vecc<i64>;
+
+/// Vector with two [`u32`](u32) components.
+///
+/// Neighbors outside of the range of `u32` are skipped, so cells at the edge
+/// of the grid have fewer of them.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::Uvecc;
+///
+/// let corner = Uvecc::new(0, 0);
+///
+/// assert_eq!(corner.neighbors4().count(), 2);
+/// assert_eq!(corner.neighbors8().count(), 3);
+/// assert_eq!(corner.manhattan(Uvecc::new(3, 4)), 7);
+/// assert_eq!(corner.checked_sub(Uvecc::new(1, 0)), None);
+/// ```
+pub type Uvecc = Vecc<u32>;
+
+const ORTHOGONAL: [(i8, i8); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+const ALL: [(i8, i8); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+macro_rules! impl_integer {
+    ($t:ty, $dist:expr) => {
+        impl Vecc<$t> {
+            /// Constructs a new vector of zero magnitude.
+            pub fn zero() -> Self {
+                Self { x: 0, y: 0 }
+            }
+
+            /// Returns the
+            /// [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry)
+            /// to `other`, i.e. the number of orthogonal steps between them.
+            pub fn manhattan(&self, other: Self) -> $t {
+                $dist(self.x, other.x) + $dist(self.y, other.y)
+            }
+
+            /// Returns the
+            /// [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance)
+            /// to `other`, i.e. the number of steps between them if
+            /// diagonal steps are allowed.
+            pub fn chebyshev(&self, other: Self) -> $t {
+                $dist(self.x, other.x).max($dist(self.y, other.y))
+            }
+
+            /// Returns the squared magnitude. Overflows like the
+            /// component type does.
+            pub fn mag_squared(&self) -> $t {
+                self.x * self.x + self.y * self.y
+            }
+
+            /// Returns the magnitude rounded down, computed exactly with
+            /// integer square root.
+            ///
+            /// The squared magnitude is computed with 128-bit integers and
+            /// the magnitude is at most `2^63.5`, so the result always fits
+            /// in a [`u64`](u64), even when it doesn't fit in the component
+            /// type.
+            pub fn mag_floor(&self) -> u64 {
+                let x = (self.x as i128).unsigned_abs();
+                let y = (self.y as i128).unsigned_abs();
+
+                (x * x + y * y).isqrt() as u64
+            }
+
+            /// Returns the squared distance to `other`.
+            pub fn dist_squared(&self, other: Self) -> $t {
+                let (dx, dy) = ($dist(self.x, other.x), $dist(self.y, other.y));
+
+                dx * dx + dy * dy
+            }
+
+            /// Returns an iterator over the four orthogonal neighbors,
+            /// counterclockwise starting from the one on the right.
+            pub fn neighbors4(&self) -> impl Iterator<Item = Self> {
+                let this = *self;
+
+                ORTHOGONAL
+                    .iter()
+                    .filter_map(move |&(dx, dy)| this.step(dx, dy))
+            }
+
+            /// Returns an iterator over the eight orthogonal and diagonal
+            /// neighbors, counterclockwise starting from the one on the
+            /// right.
+            pub fn neighbors8(&self) -> impl Iterator<Item = Self> {
+                let this = *self;
+
+                ALL.iter().filter_map(move |&(dx, dy)| this.step(dx, dy))
+            }
+
+            fn step(&self, dx: i8, dy: i8) -> Option<Self> {
+                let shift = |value: $t, d: i8| match d {
+                    1 => value.checked_add(1),
+                    -1 => value.checked_sub(1),
+                    _ => Some(value),
+                };
+
+                Some(Self {
+                    x: shift(self.x, dx)?,
+                    y: shift(self.y, dy)?,
+                })
+            }
+
+            /// Adds two vectors, returning `None` on overflow.
+            pub fn checked_add(&self, other: Self) -> Option<Self> {
+                Some(Self {
+                    x: self.x.checked_add(other.x)?,
+                    y: self.y.checked_add(other.y)?,
+                })
+            }
+
+            /// Subtracts two vectors, returning `None` on overflow.
+            pub fn checked_sub(&self, other: Self) -> Option<Self> {
+                Some(Self {
+                    x: self.x.checked_sub(other.x)?,
+                    y: self.y.checked_sub(other.y)?,
+                })
+            }
+
+            /// Multiplies the vector by a number, returning `None` on
+            /// overflow.
+            pub fn checked_mul(&self, scalar: $t) -> Option<Self> {
+                Some(Self {
+                    x: self.x.checked_mul(scalar)?,
+                    y: self.y.checked_mul(scalar)?,
+                })
+            }
+
+            /// Adds two vectors, saturating at the numeric bounds.
+            pub fn saturating_add(&self, other: Self) -> Self {
+                Self {
+                    x: self.x.saturating_add(other.x),
+                    y: self.y.saturating_add(other.y),
+                }
+            }
+
+            /// Subtracts two vectors, saturating at the numeric bounds.
+            pub fn saturating_sub(&self, other: Self) -> Self {
+                Self {
+                    x: self.x.saturating_sub(other.x),
+                    y: self.y.saturating_sub(other.y),
+                }
+            }
+
+            /// Converts the components to [`f64`](f64). Components of
+            /// `i64` vectors beyond `2^53` are rounded.
+            pub fn to_fecc(&self) -> Fecc {
+                Fecc {
+                    x: self.x as f64,
+                    y: self.y as f64,
+                }
+            }
+        }
+    };
+}
+
+impl_integer!(i32, |a: i32, b: i32| (a - b).abs());
+impl_integer!(i64, |a: i64, b: i64| (a - b).abs());
+impl_integer!(u32, |a: u32, b: u32| a.abs_diff(b));
@@ -0,0 +1,394 @@
+//! Integrators stepping a position and a velocity with an acceleration
+//! depending on the position, e.g. gravity of a fixed body.
+//!
+//! [`velocity_verlet`](crate::integrate::velocity_verlet) and
+//! [`leapfrog`](crate::integrate::leapfrog) are symplectic — the energy
+//! oscillates around its initial value instead of drifting away, so orbits
+//! stay closed over long runs. [`euler`](crate::integrate::euler) is the
+//! explicit Euler method, cheap but unstable for orbits.
+//! [`Rkf45`](crate::integrate::Rkf45) adapts the time step to the error, for
+//! dynamics varying by orders of magnitude, e.g. close passes.
+//!
+//! # Examples
+//!
+//! Comparing the energy drift of a circular orbit.
+//!
+//! ```
+//! use veccentric::{dynamics::Diagnostics, integrate, Fecc};
+//!
+//! const GM: f64 = 1000.0;
+//!
+//! let gravity = |position: Fecc| -position * GM / position.mag().powi(3);
+//! let energy = |position: Fecc, velocity: Fecc| {
+//!     velocity.mag_squared() / 2.0 - GM / position.mag()
+//! };
+//!
+//! let run = |step: fn(&mut Fecc, &mut Fecc, &mut dyn FnMut(Fecc) -> Fecc)| {
+//!     let mut position = Fecc::new(10.0, 0.0);
+//!     let mut velocity = Fecc::new(0.0, 10.0);
+//!     let mut diagnostics = Diagnostics::new();
+//!
+//!     for _ in 0..2000 {
+//!         step(&mut position, &mut velocity, &mut |p| gravity(p));
+//!         diagnostics.sample(energy(position, velocity));
+//!     }
+//!
+//!     diagnostics.max_relative_drift()
+//! };
+//!
+//! let euler = run(|p, v, a| integrate::euler(p, v, a, 0.01));
+//! let verlet = run(|p, v, a| integrate::velocity_verlet(p, v, a, 0.01));
+//! let leapfrog = run(|p, v, a| integrate::leapfrog(p, v, a, 0.01));
+//!
+//! assert!(verlet < 1e-3);
+//! assert!(leapfrog < 1e-3);
+//! assert!(euler > 100.0 * verlet);
+//! ```
+
+use crate::Fecc;
+
+/// Performs a step of the explicit Euler method — moves with the current
+/// velocity and then accelerates with the acceleration at the current
+/// position.
+pub fn euler<F>(
+    position: &mut Fecc,
+    velocity: &mut Fecc,
+    mut acceleration: F,
+    dt: f64,
+) where
+    F: FnMut(Fecc) -> Fecc,
+{
+    let a = acceleration(*position);
+
+    *position += *velocity * dt;
+    *velocity += a * dt;
+}
+
+/// Performs a step of the velocity Verlet method. The acceleration is
+/// evaluated twice, at the current and at the next position.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{integrate, Fecc};
+///
+/// let gravity = |_| Fecc::new(0.0, -10.0);
+/// let mut position = Fecc::zero();
+/// let mut velocity = Fecc::new(1.0, 10.0);
+///
+/// for _ in 0..10 {
+///     integrate::velocity_verlet(&mut position, &mut velocity, gravity, 0.1);
+/// }
+///
+/// // Exact for constant acceleration.
+/// assert_approx_eq!(f64, position.x, 1.0);
+/// assert_approx_eq!(f64, position.y, 5.0);
+/// assert_approx_eq!(f64, velocity.y, 0.0, epsilon = 1e-9);
+/// ```
+pub fn velocity_verlet<F>(
+    position: &mut Fecc,
+    velocity: &mut Fecc,
+    mut acceleration: F,
+    dt: f64,
+) where
+    F: FnMut(Fecc) -> Fecc,
+{
+    let a = acceleration(*position);
+    *position += *velocity * dt + a * (dt * dt / 2.0);
+
+    let next_a = acceleration(*position);
+    *velocity += (a + next_a) * (dt / 2.0);
+}
+
+/// Performs a step of the leapfrog method in the drift-kick-drift form —
+/// moves for half of the step, accelerates with the acceleration at the
+/// midpoint and moves for the other half. The acceleration is evaluated
+/// once.
+pub fn leapfrog<F>(
+    position: &mut Fecc,
+    velocity: &mut Fecc,
+    mut acceleration: F,
+    dt: f64,
+) where
+    F: FnMut(Fecc) -> Fecc,
+{
+    *position += *velocity * (dt / 2.0);
+    *velocity += acceleration(*position) * dt;
+    *position += *velocity * (dt / 2.0);
+}
+
+/// Coefficients of the intermediate stages.
+const RKF45_A: [[f64; 5]; 6] = [
+    [0.0, 0.0, 0.0, 0.0, 0.0],
+    [1.0 / 4.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 32.0, 9.0 / 32.0, 0.0, 0.0, 0.0],
+    [1932.0 / 2197.0, -7200.0 / 2197.0, 7296.0 / 2197.0, 0.0, 0.0],
+    [439.0 / 216.0, -8.0, 3680.0 / 513.0, -845.0 / 4104.0, 0.0],
+    [
+        -8.0 / 27.0,
+        2.0,
+        -3544.0 / 2565.0,
+        1859.0 / 4104.0,
+        -11.0 / 40.0,
+    ],
+];
+
+/// Weights of the fourth-order solution.
+const RKF45_B4: [f64; 6] = [
+    25.0 / 216.0,
+    0.0,
+    1408.0 / 2565.0,
+    2197.0 / 4104.0,
+    -1.0 / 5.0,
+    0.0,
+];
+
+/// Weights of the fifth-order solution.
+const RKF45_B5: [f64; 6] = [
+    16.0 / 135.0,
+    0.0,
+    6656.0 / 12825.0,
+    28561.0 / 56430.0,
+    -9.0 / 50.0,
+    2.0 / 55.0,
+];
+
+/// Adaptive integrator using the
+/// [Runge–Kutta–Fehlberg method](https://en.wikipedia.org/wiki/Runge%E2%80%93Kutta%E2%80%93Fehlberg_method).
+///
+/// Each step compares a fourth- and a fifth-order solution. If they differ
+/// by more than the tolerance, the step is retried with a smaller time
+/// step, otherwise the fifth-order solution is kept and the next time step
+/// is chosen from the error. The time step is remembered between the calls,
+/// so it shrinks during close passes and grows back afterwards.
+///
+/// # Examples
+///
+/// A highly eccentric orbit, which explodes with a fixed time step large
+/// enough to be practical.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{integrate::Rkf45, Fecc};
+///
+/// let gravity = |position: Fecc| -position / position.mag().powi(3);
+/// let energy = |position: Fecc, velocity: Fecc| {
+///     velocity.mag_squared() / 2.0 - 1.0 / position.mag()
+/// };
+///
+/// let mut position = Fecc::new(1.0, 0.0);
+/// let mut velocity = Fecc::new(0.0, 0.1);
+/// let initial = energy(position, velocity);
+///
+/// let mut rkf45 = Rkf45::new(1e-9, 0.01);
+/// let mut dts = vec![];
+///
+/// // One orbital period.
+/// let period = 2.0 * std::f64::consts::PI * (1.0 / 1.99_f64).powf(1.5);
+/// let mut time = 0.0;
+///
+/// while time < period {
+///     let left = period - time;
+///     time += rkf45.step_within(&mut position, &mut velocity, gravity, left);
+///     dts.push(rkf45.dt());
+/// }
+///
+/// assert_approx_eq!(f64, position.x, 1.0, epsilon = 1e-6);
+/// assert_approx_eq!(f64, position.y, 0.0, epsilon = 1e-6);
+/// assert_approx_eq!(f64, energy(position, velocity), initial, epsilon = 1e-6);
+///
+/// // The time steps vary wildly between the apoapsis and the periapsis.
+/// let min = dts.iter().cloned().fold(f64::INFINITY, f64::min);
+/// let max = dts.iter().cloned().fold(0.0, f64::max);
+/// assert!(max > 100.0 * min);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Rkf45 {
+    /// The largest accepted error of a single step, in units of position
+    /// and velocity.
+    pub tolerance: f64,
+
+    /// The smallest time step. Steps this short are accepted regardless of
+    /// the error. It must be positive, otherwise the time step may shrink to
+    /// zero and [`advance`](crate::integrate::Rkf45::advance) never ends.
+    pub min_dt: f64,
+
+    /// The largest time step.
+    pub max_dt: f64,
+
+    dt: f64,
+}
+
+impl Rkf45 {
+    /// Constructs a new integrator with the initial time step. The smallest
+    /// time step is a billionth of the initial one, the largest one is not
+    /// bounded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dt` is not positive.
+    pub fn new(tolerance: f64, dt: f64) -> Self {
+        assert!(dt > 0.0, "the time step must be positive");
+
+        Self {
+            tolerance,
+            min_dt: dt * 1e-9,
+            max_dt: f64::INFINITY,
+            dt,
+        }
+    }
+
+    /// Sets the smallest time step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_dt` is not positive.
+    pub fn with_min_dt(mut self, min_dt: f64) -> Self {
+        assert!(min_dt > 0.0, "the smallest time step must be positive");
+
+        self.min_dt = min_dt;
+
+        self
+    }
+
+    /// Sets the largest time step.
+    pub fn with_max_dt(mut self, max_dt: f64) -> Self {
+        self.max_dt = max_dt;
+
+        self
+    }
+
+    /// Returns the time step the next step will try first.
+    pub fn dt(&self) -> f64 {
+        self.dt
+    }
+
+    /// Performs a single step, retrying it with smaller time steps until the
+    /// error is within the tolerance. Returns the time step taken.
+    pub fn step<F>(
+        &mut self,
+        position: &mut Fecc,
+        velocity: &mut Fecc,
+        acceleration: F,
+    ) -> f64
+    where
+        F: FnMut(Fecc) -> Fecc,
+    {
+        self.step_within(position, velocity, acceleration, f64::INFINITY)
+    }
+
+    /// Performs a single step like [`step`](crate::integrate::Rkf45::step),
+    /// but no longer than `limit`, e.g. the time left until the next frame.
+    /// Returns the time step taken.
+    pub fn step_within<F>(
+        &mut self,
+        position: &mut Fecc,
+        velocity: &mut Fecc,
+        mut acceleration: F,
+        limit: f64,
+    ) -> f64
+    where
+        F: FnMut(Fecc) -> Fecc,
+    {
+        loop {
+            let unlimited = self.dt.clamp(self.min_dt, self.max_dt);
+            let dt = unlimited.min(limit);
+            let (next, error) =
+                rkf45(*position, *velocity, &mut acceleration, dt);
+
+            // Scale the time step by `(tolerance / error)^(1/5)` with a
+            // safety factor, changing it at most five times at once.
+            let factor = if error == 0.0 {
+                5.0
+            } else if error.is_finite() {
+                (0.9 * (self.tolerance / error).powf(0.2)).clamp(0.2, 5.0)
+            } else {
+                0.2
+            };
+
+            if error <= self.tolerance || dt <= self.min_dt {
+                *position = next.0;
+                *velocity = next.1;
+
+                // A step shortened by the limit says little about the time
+                // step which could have been taken.
+                if dt == unlimited || factor < 1.0 {
+                    self.dt = (dt * factor).clamp(self.min_dt, self.max_dt);
+                }
+
+                return dt;
+            }
+
+            self.dt = (dt * factor).max(self.min_dt);
+        }
+    }
+
+    /// Integrates over `duration` with as many steps as needed. Returns the
+    /// number of steps.
+    pub fn advance<F>(
+        &mut self,
+        position: &mut Fecc,
+        velocity: &mut Fecc,
+        mut acceleration: F,
+        duration: f64,
+    ) -> usize
+    where
+        F: FnMut(Fecc) -> Fecc,
+    {
+        let mut time = 0.0;
+        let mut steps = 0;
+
+        while time < duration {
+            time += self.step_within(
+                position,
+                velocity,
+                &mut acceleration,
+                duration - time,
+            );
+            steps += 1;
+        }
+
+        steps
+    }
+}
+
+/// Returns the fifth-order solution and the difference between it and the
+/// fourth-order one.
+fn rkf45<F>(
+    position: Fecc,
+    velocity: Fecc,
+    acceleration: &mut F,
+    dt: f64,
+) -> ((Fecc, Fecc), f64)
+where
+    F: FnMut(Fecc) -> Fecc,
+{
+    let mut k = [(Fecc::zero(), Fecc::zero()); 6];
+
+    for (i, coefficients) in RKF45_A.iter().enumerate() {
+        let (x, v) = coefficients[..i]
+            .iter()
+            .zip(&k)
+            .fold((position, velocity), |(x, v), (&c, &(dx, dv))| {
+                (x + dx * (c * dt), v + dv * (c * dt))
+            });
+
+        k[i] = (v, acceleration(x));
+    }
+
+    let combine = |weights: &[f64; 6]| {
+        weights
+            .iter()
+            .zip(&k)
+            .fold((position, velocity), |(x, v), (&w, &(dx, dv))| {
+                (x + dx * (w * dt), v + dv * (w * dt))
+            })
+    };
+
+    let (x4, v4) = combine(&RKF45_B4);
+    let (x5, v5) = combine(&RKF45_B5);
+    let error = (x5 - x4).mag().max((v5 - v4).mag());
+
+    ((x5, v5), error)
+}
@@ -0,0 +1,170 @@
+//! Interpolation between scalars and vectors.
+//!
+//! All functions are generic over [`Interpolate`](crate::interpolate::Interpolate),
+//! which is implemented for [`f64`](f64) and [`Fecc`](crate::fecc::Fecc).
+//!
+//! # Examples
+//!
+//! ```
+//! # use float_cmp::assert_approx_eq;
+//! use veccentric::{interpolate, Fecc};
+//!
+//! let a = Fecc::new(0.0, 0.0);
+//! let b = Fecc::new(10.0, 0.0);
+//!
+//! let p = interpolate::smoothstep(a, b, 0.25);
+//! assert_approx_eq!(f64, p.x, 1.5625);
+//!
+//! let x = interpolate::smoothstep(0.0, 10.0, 0.25);
+//! assert_approx_eq!(f64, x, 1.5625);
+//! ```
+
+use std::ops::{Add, Mul, Sub};
+
+use crate::Fecc;
+
+/// Values which can be interpolated.
+pub trait Interpolate:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f64, Output = Self>
+{
+    /// Returns `t` such that [`lerp(a, b, t)`](crate::interpolate::lerp) is
+    /// the closest to `value`. The result is not clamped. Returns `0.0` if
+    /// `a == b`.
+    fn inverse_lerp(a: Self, b: Self, value: Self) -> f64;
+}
+
+impl Interpolate for f64 {
+    fn inverse_lerp(a: Self, b: Self, value: Self) -> f64 {
+        if a == b {
+            0.0
+        } else {
+            (value - a) / (b - a)
+        }
+    }
+}
+
+impl Interpolate for Fecc {
+    fn inverse_lerp(a: Self, b: Self, value: Self) -> f64 {
+        let ab = b - a;
+
+        if ab.is_zero() {
+            0.0
+        } else {
+            (value - a).dot(ab) / ab.mag_squared()
+        }
+    }
+}
+
+/// Linearly interpolates between `a` and `b`. `t` is not clamped.
+pub fn lerp<T>(a: T, b: T, t: f64) -> T
+where
+    T: Interpolate,
+{
+    a + (b - a) * t
+}
+
+/// Inverse of [`lerp`](crate::interpolate::lerp). For vectors, `value` is
+/// projected onto the line through `a` and `b`.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{interpolate, Fecc};
+///
+/// assert_approx_eq!(f64, interpolate::inverse_lerp(10.0, 20.0, 12.5), 0.25);
+///
+/// let a = Fecc::new(0.0, 0.0);
+/// let b = Fecc::new(10.0, 0.0);
+/// let value = Fecc::new(7.5, 3.0);
+///
+/// assert_approx_eq!(f64, interpolate::inverse_lerp(a, b, value), 0.75);
+/// ```
+pub fn inverse_lerp<T>(a: T, b: T, value: T) -> f64
+where
+    T: Interpolate,
+{
+    T::inverse_lerp(a, b, value)
+}
+
+/// Interpolates between `a` and `b` with the
+/// [smoothstep](https://en.wikipedia.org/wiki/Smoothstep) curve (`3t² − 2t³`).
+/// `t` is clamped to `[0.0, 1.0]`.
+pub fn smoothstep<T>(a: T, b: T, t: f64) -> T
+where
+    T: Interpolate,
+{
+    let t = t.clamp(0.0, 1.0);
+
+    lerp(a, b, t * t * (3.0 - 2.0 * t))
+}
+
+/// Interpolates between `a` and `b` with Ken Perlin's smootherstep curve
+/// (`6t⁵ − 15t⁴ + 10t³`), whose first and second derivatives are zero at both
+/// ends. `t` is clamped to `[0.0, 1.0]`.
+pub fn smootherstep<T>(a: T, b: T, t: f64) -> T
+where
+    T: Interpolate,
+{
+    let t = t.clamp(0.0, 1.0);
+
+    lerp(a, b, t * t * t * (t * (t * 6.0 - 15.0) + 10.0))
+}
+
+/// Cubic Hermite interpolation between `p0` (with tangent `m0`) and `p1`
+/// (with tangent `m1`).
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{interpolate, Fecc};
+///
+/// let p0 = Fecc::new(0.0, 0.0);
+/// let p1 = Fecc::new(10.0, 0.0);
+/// let up = Fecc::new(0.0, 10.0);
+///
+/// let start = interpolate::hermite(p0, up, p1, up, 0.0);
+/// let end = interpolate::hermite(p0, up, p1, up, 1.0);
+///
+/// assert_approx_eq!(f64, start.dist(p0), 0.0);
+/// assert_approx_eq!(f64, end.dist(p1), 0.0);
+/// ```
+pub fn hermite<T>(p0: T, m0: T, p1: T, m1: T, t: f64) -> T
+where
+    T: Interpolate,
+{
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    p0 * (2.0 * t3 - 3.0 * t2 + 1.0)
+        + m0 * (t3 - 2.0 * t2 + t)
+        + p1 * (-2.0 * t3 + 3.0 * t2)
+        + m1 * (t3 - t2)
+}
+
+/// Cubic uniform [Catmull–Rom](https://en.wikipedia.org/wiki/Cubic_Hermite_spline#Catmull%E2%80%93Rom_spline)
+/// interpolation between `p1` and `p2`, using `p0` and `p3` to compute the
+/// tangents. The tangents don't depend on the distances between the points,
+/// so unevenly spaced points may produce loops and cusps.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::interpolate;
+///
+/// // Points on a line are interpolated linearly.
+/// let x = interpolate::catmull_rom(0.0, 1.0, 2.0, 3.0, 0.5);
+///
+/// assert_approx_eq!(f64, x, 1.5);
+/// ```
+pub fn catmull_rom<T>(p0: T, p1: T, p2: T, p3: T, t: f64) -> T
+where
+    T: Interpolate,
+{
+    let m1 = (p2 - p0) * 0.5;
+    let m2 = (p3 - p1) * 0.5;
+
+    hermite(p1, m1, p2, m2, t)
+}
@@ -0,0 +1,128 @@
+//! [Kalman filter](https://en.wikipedia.org/wiki/Kalman_filter) smoothing
+//! noisy position measurements.
+
+use crate::Fecc;
+
+/// The initial variance of the velocity — large, since it's unknown until
+/// the first few measurements arrive.
+const INITIAL_VELOCITY_VARIANCE: f64 = 1e6;
+
+/// Constant-velocity Kalman filter estimating the position and the velocity
+/// of an object from noisy measurements of its position, e.g. mouse, touch
+/// or sensor input.
+///
+/// The object is assumed to move with a constant velocity, disturbed by
+/// random accelerations. The noise is the same along both axes and
+/// independent between them, so both axes share a single covariance matrix.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{kalman::KalmanFilter, Fecc};
+///
+/// let velocity = Fecc::new(1.0, 0.5);
+/// let mut filter = KalmanFilter::new(Fecc::zero(), 0.01, 0.25);
+///
+/// for i in 1..=100 {
+///     let t = i as f64 * 0.1;
+///     let noise = if i % 2 == 0 { 0.5 } else { -0.5 };
+///     let measurement = velocity * t + Fecc::new(noise, -noise);
+///
+///     filter.step(measurement, 0.1);
+/// }
+///
+/// assert!(filter.position.dist(velocity * 10.0) < 0.2);
+/// assert!(filter.velocity.dist(velocity) < 0.1);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct KalmanFilter {
+    /// The estimated position.
+    pub position: Fecc,
+
+    /// The estimated velocity.
+    pub velocity: Fecc,
+
+    /// The spectral density of the random acceleration. Larger values make
+    /// the filter follow changes of the velocity faster.
+    pub process_noise: f64,
+
+    /// The variance of the measurement noise. Larger values make the output
+    /// smoother.
+    pub measurement_noise: f64,
+
+    /// The covariance of the estimated position and velocity (along each
+    /// axis).
+    covariance: [[f64; 2]; 2],
+}
+
+impl KalmanFilter {
+    /// Constructs a new filter at the initial position, with zero velocity.
+    /// The initial velocity is very uncertain, so it's quickly replaced by
+    /// the one inferred from the measurements.
+    pub fn new(
+        position: Fecc,
+        process_noise: f64,
+        measurement_noise: f64,
+    ) -> Self {
+        Self {
+            position,
+            velocity: Fecc::zero(),
+            process_noise,
+            measurement_noise,
+            covariance: [
+                [measurement_noise, 0.0],
+                [0.0, INITIAL_VELOCITY_VARIANCE],
+            ],
+        }
+    }
+
+    /// Returns the covariance matrix of the position and the velocity along
+    /// each axis, `[[var(p), cov(p, v)], [cov(v, p), var(v)]]`.
+    pub fn covariance(&self) -> [[f64; 2]; 2] {
+        self.covariance
+    }
+
+    /// Predicts the state `dt` seconds later.
+    pub fn predict(&mut self, dt: f64) {
+        let [[p00, p01], [p10, p11]] = self.covariance;
+        let q = self.process_noise;
+
+        self.position += self.velocity * dt;
+        self.covariance = [
+            [
+                p00 + dt * (p10 + p01) + dt * dt * p11 + q * dt.powi(3) / 3.0,
+                p01 + dt * p11 + q * dt * dt / 2.0,
+            ],
+            [p10 + dt * p11 + q * dt * dt / 2.0, p11 + q * dt],
+        ];
+    }
+
+    /// Corrects the state with a measurement of the position.
+    pub fn update(&mut self, measurement: Fecc) {
+        let [[p00, p01], [p10, p11]] = self.covariance;
+        let innovation = measurement - self.position;
+        let variance = p00 + self.measurement_noise;
+
+        if variance <= 0.0 {
+            return;
+        }
+
+        let (k0, k1) = (p00 / variance, p10 / variance);
+
+        self.position += innovation * k0;
+        self.velocity += innovation * k1;
+        self.covariance = [
+            [(1.0 - k0) * p00, (1.0 - k0) * p01],
+            [p10 - k1 * p00, p11 - k1 * p01],
+        ];
+    }
+
+    /// Predicts the state `dt` seconds later, corrects it with the
+    /// measurement and returns the estimated position.
+    pub fn step(&mut self, measurement: Fecc, dt: f64) -> Fecc {
+        self.predict(dt);
+        self.update(measurement);
+
+        self.position
+    }
+}
@@ -0,0 +1,180 @@
+//! Thin wrappers over [`Fecc`](crate::fecc::Fecc) which only allow
+//! physically meaningful operations.
+//!
+//! Positions can't be added together, but subtracting them gives a
+//! [`Displacement`](crate::kinematics::Displacement). Multiplying by a number
+//! means multiplying by a duration (e.g. velocity times time gives a
+//! displacement), dividing a force by a number means dividing by a mass.
+//! The wrapped vector is always accessible through the public field.
+//!
+//! # Examples
+//!
+//! ```
+//! # use float_cmp::assert_approx_eq;
+//! use veccentric::{
+//!     kinematics::{Force, Position, Velocity},
+//!     Fecc,
+//! };
+//!
+//! let (dt, mass) = (0.5, 2.0);
+//! let start = Position(Fecc::new(0.0, 10.0));
+//! let mut position = start;
+//! let mut velocity = Velocity(Fecc::new(4.0, 0.0));
+//! let gravity = Force(Fecc::new(0.0, -20.0));
+//!
+//! velocity += gravity / mass * dt;
+//! position += velocity * dt;
+//!
+//! let displacement = position - start;
+//!
+//! assert_approx_eq!(f64, displacement.0.x, 2.0);
+//! assert_approx_eq!(f64, displacement.0.y, -2.5);
+//! ```
+//!
+//! ```compile_fail
+//! use veccentric::{
+//!     kinematics::{Force, Position},
+//!     Fecc,
+//! };
+//!
+//! let _ = Position(Fecc::zero()) + Force(Fecc::zero());
+//! ```
+
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::Fecc;
+
+/// A point in space.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Position(pub Fecc);
+
+/// The difference between two positions.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Displacement(pub Fecc);
+
+/// The rate of change of position.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Velocity(pub Fecc);
+
+/// The rate of change of velocity.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Acceleration(pub Fecc);
+
+/// A force.
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Force(pub Fecc);
+
+/// Implements addition, subtraction and negation between values of the same
+/// quantity.
+macro_rules! impl_linear {
+    ($($t:ident),*) => {
+        $(
+            impl Add for $t {
+                type Output = $t;
+
+                fn add(self, rhs: $t) -> $t {
+                    $t(self.0 + rhs.0)
+                }
+            }
+
+            impl Sub for $t {
+                type Output = $t;
+
+                fn sub(self, rhs: $t) -> $t {
+                    $t(self.0 - rhs.0)
+                }
+            }
+
+            impl Neg for $t {
+                type Output = $t;
+
+                fn neg(self) -> $t {
+                    $t(-self.0)
+                }
+            }
+
+            impl AddAssign for $t {
+                fn add_assign(&mut self, rhs: $t) {
+                    self.0 += rhs.0;
+                }
+            }
+
+            impl SubAssign for $t {
+                fn sub_assign(&mut self, rhs: $t) {
+                    self.0 -= rhs.0;
+                }
+            }
+        )*
+    };
+}
+
+impl_linear!(Displacement, Velocity, Acceleration, Force);
+
+/// Implements `lhs op f64 = output`.
+macro_rules! impl_scalar {
+    ($lhs:ident, $op:ident, $method:ident, $output:ident) => {
+        impl $op<f64> for $lhs {
+            type Output = $output;
+
+            fn $method(self, rhs: f64) -> $output {
+                $output(self.0.$method(rhs))
+            }
+        }
+    };
+}
+
+impl_scalar!(Velocity, Mul, mul, Displacement);
+impl_scalar!(Acceleration, Mul, mul, Velocity);
+impl_scalar!(Displacement, Div, div, Velocity);
+impl_scalar!(Velocity, Div, div, Acceleration);
+impl_scalar!(Force, Div, div, Acceleration);
+
+impl Sub for Position {
+    type Output = Displacement;
+
+    fn sub(self, rhs: Position) -> Displacement {
+        Displacement(self.0 - rhs.0)
+    }
+}
+
+impl Add<Displacement> for Position {
+    type Output = Position;
+
+    fn add(self, rhs: Displacement) -> Position {
+        Position(self.0 + rhs.0)
+    }
+}
+
+impl Sub<Displacement> for Position {
+    type Output = Position;
+
+    fn sub(self, rhs: Displacement) -> Position {
+        Position(self.0 - rhs.0)
+    }
+}
+
+impl AddAssign<Displacement> for Position {
+    fn add_assign(&mut self, rhs: Displacement) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign<Displacement> for Position {
+    fn sub_assign(&mut self, rhs: Displacement) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Position {
+    /// Returns the distance to another position.
+    pub fn dist(&self, other: Position) -> f64 {
+        self.0.dist(other.0)
+    }
+}
+
+impl Acceleration {
+    /// Returns the force needed to accelerate a body of the given mass.
+    pub fn force(&self, mass: f64) -> Force {
+        Force(self.0 * mass)
+    }
+}
@@ -6,7 +6,12 @@
 //!
 //! The main type, [`Vecc<T>`](crate::vecc::Vecc), is a generic struct
 //! implementing many useful traits and operator overloading.
-//! [`Fecc`](crate::fecc::Fecc) is a type alias for [`Vecc<f64>`](crate::vecc::Vecc). It has an extended API, heavily inspired by [`p5.Vector`](https://p5js.org/reference/#/p5.Vector).
+//! [`Fecc`](crate::fecc::Fecc) is a type alias for [`Vecc<f64>`](crate::vecc::Vecc). It has an extended API, heavily inspired by [`p5.Vector`](https://p5js.org/reference/#/p5.Vector),
+//! which [`Vecc<f32>`](crate::vecc::Vecc) shares through the [`Float`](crate::float::Float) trait.
+//! The [integer aliases](crate::integer) have an API suited for grids.
+//! [`Vecc3<T>`](crate::vecc3::Vecc3) and [`Fecc3`](crate::fecc3::Fecc3)
+//! are their three-dimensional counterparts and
+//! [`Vecc4<T>`](crate::vecc4::Vecc4) holds homogeneous coordinates.
 //!
 //! # Features
 //!
@@ -14,9 +19,45 @@
 //! methods on [`Fecc`](crate::fecc::Fecc):
 //! [`from_rng`](crate::fecc::Fecc::from_rng),
 //! [`from_seed`](crate::fecc::Fecc::from_seed),
-//! [`from_entropy`](crate::fecc::Fecc::from_entropy).
+//! [`from_entropy`](crate::fecc::Fecc::from_entropy). It also enables
+//! [`generate::stratified_samples`](crate::generate::stratified_samples),
+//! [`packing::pack_circles`](crate::packing::pack_circles) and the
+//! [`dla`](crate::dla) module (diffusion-limited aggregation).
 //!
-//! The `all` feature enables just `random`.
+//! The `engine` feature enables the [`engine`](crate::engine) module — a
+//! minimal pixel engine (the one used by the examples).
+//!
+//! The `noise` feature enables the [`noise`](crate::noise) module (Perlin
+//! noise) and noise-driven behaviors built on top of it, e.g.
+//! [`Wander::steer_noise`](crate::steering::Wander::steer_noise).
+//!
+//! The `triangulate` feature enables the [`triangulate`](crate::triangulate)
+//! module (Delaunay triangulation and Voronoi diagrams).
+//!
+//! The `serde` feature implements `Serialize` and `Deserialize` for
+//! [`Vecc`](crate::vecc::Vecc) and enables the [`serde`](crate::serde)
+//! module with alternative representations.
+//!
+//! The `fixed` feature implements the magnitude and rotation methods for
+//! [`Vecc<I32F32>`](crate::vecc::Vecc), see the [`fixed`](crate::fixed)
+//! module.
+//!
+//! The `exact` feature enables the [`exact`](crate::exact) module — exact
+//! orientation and intersection predicates using arbitrary-precision
+//! rationals.
+//!
+//! The `double-double` feature enables the
+//! [`double_double`](crate::double_double) module — vectors with about twice
+//! the precision of [`Fecc`](crate::fecc::Fecc).
+//!
+//! The `uom` feature enables the [`units`](crate::units) module — vectors of
+//! physical quantities from [`uom`](https://docs.rs/uom).
+//!
+//! The `fluid` feature enables the [`fluid`](crate::fluid) module — a
+//! grid-based fluid simulation.
+//!
+//! The `all` feature enables `random`, `engine`, `noise`, `triangulate`,
+//! `serde`, `fixed`, `exact`, `double-double`, `uom` and `fluid`.
 //!
 //! # Notes
 //!
@@ -44,10 +85,122 @@
 //! For more examples, go to [`Vecc`'s docs](crate::vecc::Vecc) or to
 //! [the repository](https://github.com/micouy/veccentric/tree/master/examples).
 
+#[macro_use]
+mod macros;
+
+pub mod aabb;
+pub mod affine;
 pub mod angle;
+pub mod arc;
+pub mod body;
+pub mod broadphase;
+pub mod bvecc;
+pub mod bytes;
+pub mod circle;
+pub mod collision;
+pub mod curves;
+pub mod dead_reckoning;
+#[cfg(feature = "random")]
+#[doc(cfg(feature = "random"))]
+pub mod dla;
+#[cfg(feature = "double-double")]
+#[doc(cfg(feature = "double-double"))]
+pub mod double_double;
+pub mod dubins;
+pub mod dynamics;
+pub mod ease;
+#[cfg(feature = "engine")]
+#[doc(cfg(feature = "engine"))]
+pub mod engine;
+#[cfg(feature = "exact")]
+#[doc(cfg(feature = "exact"))]
+pub mod exact;
 pub mod fecc;
+pub mod fecc3;
+pub mod field;
+#[cfg(feature = "fixed")]
+#[doc(cfg(feature = "fixed"))]
+pub mod fixed;
+pub mod float;
+#[cfg(feature = "fluid")]
+#[doc(cfg(feature = "fluid"))]
+pub mod fluid;
+pub mod force;
+pub mod generate;
+pub mod grid;
+pub mod ik;
+pub mod integer;
+pub mod integrate;
+pub mod interpolate;
+pub mod kalman;
+pub mod kinematics;
+pub mod lsystem;
+pub mod matrix;
+#[cfg(feature = "noise")]
+#[doc(cfg(feature = "noise"))]
+pub mod noise;
+pub mod one_euro;
+pub mod oscillator;
+pub mod packing;
+pub mod particle;
+pub mod perception;
+pub mod pid;
+pub mod pixel;
+pub mod polar;
+pub mod polygon;
+pub mod polyline;
+pub mod predicates;
+pub mod raster;
+pub mod ray;
+pub mod rotor;
+pub mod segment;
+#[cfg(feature = "serde")]
+#[doc(cfg(feature = "serde"))]
+pub mod serde;
+pub mod shape;
+pub mod size;
+pub mod skeleton;
+pub mod spatial;
+pub mod steering;
+pub mod summation;
+pub mod timeline;
+pub mod torus;
+pub mod transform;
+pub mod triangle;
+#[cfg(feature = "triangulate")]
+#[doc(cfg(feature = "triangulate"))]
+pub mod triangulate;
+pub mod trigger;
+pub mod turtle;
+#[cfg(feature = "uom")]
+#[doc(cfg(feature = "uom"))]
+pub mod units;
 pub mod vecc;
+pub mod vecc3;
+pub mod vecc4;
+pub mod verlet;
+pub mod visibility;
+pub mod wave;
+pub mod world;
 
+pub use aabb::Aabb;
 pub use angle::{Angle, Angular};
+pub use arc::Arc;
+pub use body::Body2;
+pub use bvecc::BVecc;
+pub use circle::Circle;
 pub use fecc::Fecc;
+pub use fecc3::Fecc3;
+pub use float::Float;
+pub use integer::{Ivecc, Lvecc, Uvecc};
+pub use polygon::Polygon;
+pub use polyline::Polyline;
+pub use ray::{Ray, RayHit};
+pub use segment::Segment;
+pub use timeline::Timeline;
+pub use transform::Transform2;
+pub use triangle::Triangle;
+pub use turtle::Turtle;
 pub use vecc::Vecc;
+pub use vecc3::Vecc3;
+pub use vecc4::Vecc4;
@@ -0,0 +1,175 @@
+//! [L-systems](https://en.wikipedia.org/wiki/L-system) — string rewriting
+//! interpreted with [`Turtle`](crate::turtle::Turtle) graphics.
+//!
+//! The interpreter understands the following symbols, other symbols are
+//! ignored (they're still useful in the rules):
+//!
+//! * `F` and `G` — move forward, drawing a line,
+//! * `f` — move forward without drawing,
+//! * `+` — turn counterclockwise by the angle step,
+//! * `-` — turn clockwise by the angle step,
+//! * `|` — turn around,
+//! * `[` — save the position and the heading,
+//! * `]` — restore the most recently saved position and heading.
+
+use std::{collections::HashMap, f64::consts::PI};
+
+use crate::{Angle, Fecc, Polyline, Turtle};
+
+/// L-system — an axiom and the rules rewriting each symbol.
+///
+/// # Examples
+///
+/// The Koch curve.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{lsystem::LSystem, Angular, Fecc};
+///
+/// let koch = LSystem::new("F").with_rule('F', "F+F-F-F+F");
+///
+/// assert_eq!(koch.expand(1), "F+F-F-F+F");
+///
+/// let strokes = koch.draw(2, 1.0, 90.deg());
+/// assert_eq!(strokes.len(), 1);
+/// assert_eq!(strokes[0].0.len(), 26);
+///
+/// let end = strokes[0].0[25];
+/// assert_approx_eq!(f64, end.x, 9.0, epsilon = 1e-9);
+/// assert_approx_eq!(f64, end.y, 0.0, epsilon = 1e-9);
+/// ```
+///
+/// A branching plant. Each branch is a separate stroke.
+///
+/// ```
+/// use veccentric::{lsystem::LSystem, Angular};
+///
+/// let plant = LSystem::new("X")
+///     .with_rule('X', "F[+X][-X]FX")
+///     .with_rule('F', "FF");
+///
+/// let strokes = plant.draw(3, 1.0, 25.deg());
+/// assert!(strokes.len() > 1);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct LSystem {
+    /// The initial string.
+    pub axiom: String,
+
+    rules: HashMap<char, String>,
+}
+
+impl LSystem {
+    /// Constructs a new L-system without any rules.
+    pub fn new(axiom: &str) -> Self {
+        Self {
+            axiom: axiom.to_owned(),
+            rules: HashMap::new(),
+        }
+    }
+
+    /// Adds a rule replacing `symbol` with `replacement` in each
+    /// generation. Symbols without rules are left unchanged.
+    pub fn with_rule(mut self, symbol: char, replacement: &str) -> Self {
+        self.rules.insert(symbol, replacement.to_owned());
+
+        self
+    }
+
+    /// Returns the string after the specified number of generations.
+    pub fn expand(&self, generations: usize) -> String {
+        (0..generations).fold(self.axiom.clone(), |current, _| {
+            current.chars().fold(String::new(), |mut next, symbol| {
+                match self.rules.get(&symbol) {
+                    Some(replacement) => next.push_str(replacement),
+                    None => next.push(symbol),
+                }
+
+                next
+            })
+        })
+    }
+
+    /// Expands the L-system and draws it with a turtle starting at the
+    /// origin and facing the positive X axis. Returns the strokes.
+    pub fn draw<A>(
+        &self,
+        generations: usize,
+        step: f64,
+        angle: A,
+    ) -> Vec<Polyline>
+    where
+        A: Into<Angle>,
+    {
+        let mut turtle = Turtle::new(Fecc::zero(), 0.0);
+        interpret(&self.expand(generations), &mut turtle, step, angle);
+
+        turtle.into_strokes()
+    }
+}
+
+/// Interprets the commands with the turtle, moving by `step` and turning by
+/// `angle`. See the [module's docs](crate::lsystem) for the list of
+/// symbols. Unmatched `]` are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{lsystem, Angular, Fecc, Turtle};
+///
+/// let mut turtle = Turtle::new(Fecc::zero(), 0.0);
+/// lsystem::interpret("F[+F]fF", &mut turtle, 2.0, 90.deg());
+///
+/// assert_approx_eq!(f64, turtle.position().x, 6.0);
+/// assert_eq!(turtle.strokes().len(), 2);
+/// ```
+pub fn interpret<A>(commands: &str, turtle: &mut Turtle, step: f64, angle: A)
+where
+    A: Into<Angle>,
+{
+    let angle = angle.into();
+    let mut stack = vec![];
+
+    for symbol in commands.chars() {
+        match symbol {
+            'F' | 'G' => {
+                turtle.forward(step);
+            }
+            'f' => {
+                jump(
+                    turtle,
+                    turtle.position()
+                        + Fecc::from_angle(turtle.heading()) * step,
+                );
+            }
+            '+' => {
+                turtle.turn(angle);
+            }
+            '-' => {
+                turtle.turn(-angle);
+            }
+            '|' => {
+                turtle.turn(PI);
+            }
+            '[' => stack.push((turtle.position(), turtle.heading())),
+            ']' => {
+                if let Some((position, heading)) = stack.pop() {
+                    jump(turtle, position);
+                    turtle.turn(heading - turtle.heading());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Moves the turtle without drawing.
+fn jump(turtle: &mut Turtle, target: Fecc) {
+    let is_pen_down = turtle.is_pen_down();
+    turtle.pen_up().move_to(target);
+
+    if is_pen_down {
+        turtle.pen_down();
+    }
+}
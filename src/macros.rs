@@ -0,0 +1,202 @@
+//! Operator implementations shared by the vector types with more than two
+//! components.
+
+/// Implements a component-wise operator for each combination of owned and
+/// borrowed vectors.
+macro_rules! impl_vector_op {
+    ($v:ident { $($c:ident),+ }, $op:ident, $f:ident) => {
+        impl<T> $op<$v<T>> for $v<T>
+        where
+            T: $op<Output = T>,
+        {
+            type Output = $v<T>;
+
+            fn $f(self, rhs: $v<T>) -> Self::Output {
+                $v {
+                    $($c: self.$c.$f(rhs.$c),)+
+                }
+            }
+        }
+
+        impl<T> $op<&$v<T>> for $v<T>
+        where
+            T: $op<Output = T> + Copy,
+        {
+            type Output = $v<T>;
+
+            fn $f(self, rhs: &$v<T>) -> Self::Output {
+                self.$f(*rhs)
+            }
+        }
+
+        impl<T> $op<$v<T>> for &$v<T>
+        where
+            T: $op<Output = T> + Copy,
+        {
+            type Output = $v<T>;
+
+            fn $f(self, rhs: $v<T>) -> Self::Output {
+                (*self).$f(rhs)
+            }
+        }
+
+        impl<T> $op<&$v<T>> for &$v<T>
+        where
+            T: $op<Output = T> + Copy,
+        {
+            type Output = $v<T>;
+
+            fn $f(self, rhs: &$v<T>) -> Self::Output {
+                (*self).$f(*rhs)
+            }
+        }
+    };
+}
+
+/// Implements an operator with a number for each combination of owned and
+/// borrowed args.
+macro_rules! impl_scalar_op {
+    ($v:ident { $($c:ident),+ }, $op:ident, $f:ident) => {
+        impl<T> $op<T> for $v<T>
+        where
+            T: $op<Output = T> + Copy,
+        {
+            type Output = $v<T>;
+
+            fn $f(self, rhs: T) -> Self::Output {
+                $v {
+                    $($c: self.$c.$f(rhs),)+
+                }
+            }
+        }
+
+        impl<T> $op<&T> for $v<T>
+        where
+            T: $op<Output = T> + Copy,
+        {
+            type Output = $v<T>;
+
+            fn $f(self, rhs: &T) -> Self::Output {
+                self.$f(*rhs)
+            }
+        }
+
+        impl<T> $op<T> for &$v<T>
+        where
+            T: $op<Output = T> + Copy,
+        {
+            type Output = $v<T>;
+
+            fn $f(self, rhs: T) -> Self::Output {
+                (*self).$f(rhs)
+            }
+        }
+
+        impl<T> $op<&T> for &$v<T>
+        where
+            T: $op<Output = T> + Copy,
+        {
+            type Output = $v<T>;
+
+            fn $f(self, rhs: &T) -> Self::Output {
+                (*self).$f(*rhs)
+            }
+        }
+    };
+}
+
+/// Implements a compound assignment operator with a vector on the right
+/// side, owned or borrowed.
+macro_rules! impl_vector_assign_op {
+    ($v:ident { $($c:ident),+ }, $op:ident, $f:ident) => {
+        impl<T> $op<$v<T>> for $v<T>
+        where
+            T: $op<T>,
+        {
+            fn $f(&mut self, rhs: $v<T>) {
+                $(self.$c.$f(rhs.$c);)+
+            }
+        }
+
+        impl<T> $op<&$v<T>> for $v<T>
+        where
+            T: $op<T> + Copy,
+        {
+            fn $f(&mut self, rhs: &$v<T>) {
+                self.$f(*rhs);
+            }
+        }
+    };
+}
+
+/// Implements a compound assignment operator with a number on the right
+/// side, owned or borrowed.
+macro_rules! impl_scalar_assign_op {
+    ($v:ident { $($c:ident),+ }, $op:ident, $f:ident) => {
+        impl<T> $op<T> for $v<T>
+        where
+            T: $op<T> + Copy,
+        {
+            fn $f(&mut self, rhs: T) {
+                $(self.$c.$f(rhs);)+
+            }
+        }
+
+        impl<T> $op<&T> for $v<T>
+        where
+            T: $op<T> + Copy,
+        {
+            fn $f(&mut self, rhs: &T) {
+                self.$f(*rhs);
+            }
+        }
+    };
+}
+
+/// Implements negation, owned or borrowed.
+macro_rules! impl_neg {
+    ($v:ident { $($c:ident),+ }) => {
+        impl<T, U> Neg for $v<T>
+        where
+            T: Neg<Output = U>,
+        {
+            type Output = $v<U>;
+
+            fn neg(self) -> Self::Output {
+                $v {
+                    $($c: self.$c.neg(),)+
+                }
+            }
+        }
+
+        impl<T, U> Neg for &$v<T>
+        where
+            T: Neg<Output = U> + Copy,
+        {
+            type Output = $v<U>;
+
+            fn neg(self) -> Self::Output {
+                $v {
+                    $($c: self.$c.neg(),)+
+                }
+            }
+        }
+    };
+}
+
+/// Implements all of the operators of a vector type.
+macro_rules! impl_ops {
+    ($v:ident { $($c:ident),+ }) => {
+        impl_neg!($v { $($c),+ });
+
+        impl_vector_op!($v { $($c),+ }, Add, add);
+        impl_vector_op!($v { $($c),+ }, Sub, sub);
+        impl_scalar_op!($v { $($c),+ }, Mul, mul);
+        impl_scalar_op!($v { $($c),+ }, Div, div);
+
+        impl_vector_assign_op!($v { $($c),+ }, AddAssign, add_assign);
+        impl_vector_assign_op!($v { $($c),+ }, SubAssign, sub_assign);
+        impl_scalar_assign_op!($v { $($c),+ }, MulAssign, mul_assign);
+        impl_scalar_assign_op!($v { $($c),+ }, DivAssign, div_assign);
+    };
+}
@@ -0,0 +1,157 @@
+//! Matrices.
+
+use std::ops::{Add, Mul, Sub};
+
+use crate::{Angle, Float, Vecc};
+
+/// 2×2 matrix, stored as columns.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{matrix::Mat2, Angular, Fecc, Vecc};
+///
+/// let rotation = Mat2::from_angle(90.0.deg());
+/// let rotated = rotation * Fecc::new(1.0, 0.0);
+///
+/// assert_approx_eq!(f64, rotated.x, 0.0, epsilon = 1e-9);
+/// assert_approx_eq!(f64, rotated.y, 1.0);
+///
+/// let shear = Mat2::from_rows(Vecc::new(1, 2), Vecc::new(0, 1));
+///
+/// assert_eq!(shear * Vecc::new(1, 1), Vecc::new(3, 1));
+/// assert_eq!(shear.determinant(), 1);
+/// assert_eq!(shear.transpose().x_axis, Vecc::new(1, 2));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Default, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Mat2<T> {
+    /// The first column — the image of the unit X vector.
+    pub x_axis: Vecc<T>,
+
+    /// The second column — the image of the unit Y vector.
+    pub y_axis: Vecc<T>,
+}
+
+impl<T> Mat2<T> {
+    /// Constructs a new matrix from its columns.
+    pub fn from_cols(x_axis: Vecc<T>, y_axis: Vecc<T>) -> Self {
+        Self { x_axis, y_axis }
+    }
+
+    /// Constructs a new matrix from its rows.
+    pub fn from_rows(first: Vecc<T>, second: Vecc<T>) -> Self {
+        Self {
+            x_axis: Vecc::new(first.x, second.x),
+            y_axis: Vecc::new(first.y, second.y),
+        }
+    }
+
+    /// Returns the transposed matrix.
+    pub fn transpose(self) -> Self {
+        Self::from_rows(self.x_axis, self.y_axis)
+    }
+
+    /// Returns the determinant — the factor by which areas are scaled. It's
+    /// negative if the matrix flips the plane.
+    pub fn determinant(&self) -> T
+    where
+        T: Sub<Output = T> + Mul<Output = T> + Copy,
+    {
+        self.x_axis.cross(self.y_axis)
+    }
+}
+
+impl<T> Mat2<T>
+where
+    T: Float,
+{
+    /// Returns the identity matrix.
+    pub fn identity() -> Self {
+        Self {
+            x_axis: Vecc::new(T::ONE, T::ZERO),
+            y_axis: Vecc::new(T::ZERO, T::ONE),
+        }
+    }
+
+    /// Returns the matrix of a counterclockwise rotation.
+    pub fn from_angle<A>(angle: A) -> Self
+    where
+        A: Into<Angle>,
+    {
+        let angle = angle.into();
+        let (sin, cos) = (T::from_f64(angle.sin()), T::from_f64(angle.cos()));
+
+        Self {
+            x_axis: Vecc::new(cos, sin),
+            y_axis: Vecc::new(-sin, cos),
+        }
+    }
+
+    /// Returns the matrix of a scaling by `factors.x` along the X axis and
+    /// by `factors.y` along the Y axis.
+    pub fn from_scale(factors: Vecc<T>) -> Self {
+        Self {
+            x_axis: Vecc::new(factors.x, T::ZERO),
+            y_axis: Vecc::new(T::ZERO, factors.y),
+        }
+    }
+
+    /// Returns the inverse matrix or `None` if the determinant is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{matrix::Mat2, Fecc};
+    ///
+    /// let m = Mat2::from_cols(Fecc::new(2.0, 1.0), Fecc::new(1.0, 1.0));
+    /// let p = Fecc::new(3.0, -4.0);
+    /// let back = m.inverse().unwrap() * (m * p);
+    ///
+    /// assert_approx_eq!(f64, back.x, 3.0);
+    /// assert_approx_eq!(f64, back.y, -4.0);
+    ///
+    /// let (a, b) = (Fecc::new(1.0, 2.0), Fecc::new(2.0, 4.0));
+    /// assert!(Mat2::from_cols(a, b).inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+
+        if determinant == T::ZERO {
+            return None;
+        }
+
+        Some(Self {
+            x_axis: Vecc::new(self.y_axis.y, -self.x_axis.y) / determinant,
+            y_axis: Vecc::new(-self.y_axis.x, self.x_axis.x) / determinant,
+        })
+    }
+}
+
+impl<T> Mul<Vecc<T>> for Mat2<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Copy,
+{
+    type Output = Vecc<T>;
+
+    fn mul(self, rhs: Vecc<T>) -> Vecc<T> {
+        self.x_axis * rhs.x + self.y_axis * rhs.y
+    }
+}
+
+/// Composition. `(a * b) * v` is equal to `a * (b * v)`.
+impl<T> Mul<Mat2<T>> for Mat2<T>
+where
+    T: Add<Output = T> + Mul<Output = T> + Copy,
+{
+    type Output = Mat2<T>;
+
+    fn mul(self, rhs: Mat2<T>) -> Mat2<T> {
+        Mat2 {
+            x_axis: self * rhs.x_axis,
+            y_axis: self * rhs.y_axis,
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Gradient noise.
+
+use crate::Fecc;
+
+/// [Perlin noise](https://en.wikipedia.org/wiki/Perlin_noise) generator.
+///
+/// The noise is smooth and deterministic — the same seed always produces the
+/// same noise. Its values are in `[-1.0, 1.0]` and it is equal to `0.0` at
+/// integer coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{noise::Perlin, Fecc};
+///
+/// let perlin = Perlin::new(42);
+///
+/// let a = perlin.noise2(Fecc::new(0.5, 0.5));
+/// let b = perlin.noise2(Fecc::new(0.51, 0.5));
+///
+/// assert!((-1.0..=1.0).contains(&a));
+/// assert!((a - b).abs() < 0.1);
+/// ```
+#[derive(Clone)]
+pub struct Perlin {
+    permutation: [u8; 512],
+}
+
+impl Perlin {
+    /// Constructs a new noise generator.
+    pub fn new(seed: u64) -> Self {
+        let mut table = [0_u8; 256];
+
+        for (i, value) in table.iter_mut().enumerate() {
+            *value = i as u8;
+        }
+
+        // Fisher–Yates shuffle driven by SplitMix64.
+        let mut state = seed;
+
+        for i in (1..256).rev() {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^= z >> 31;
+
+            table.swap(i, (z % (i as u64 + 1)) as usize);
+        }
+
+        let mut permutation = [0_u8; 512];
+
+        for (i, value) in permutation.iter_mut().enumerate() {
+            *value = table[i % 256];
+        }
+
+        Self { permutation }
+    }
+
+    /// Samples one-dimensional noise.
+    pub fn noise1(&self, x: f64) -> f64 {
+        let cell = x.floor();
+        let ix = (cell as i64 & 0xff) as usize;
+        let x = x - cell;
+
+        let grad = |hash: u8, x: f64| if hash & 1 == 0 { x } else { -x };
+        let a = grad(self.permutation[ix], x);
+        let b = grad(self.permutation[ix + 1], x - 1.0);
+
+        (2.0 * (a + (b - a) * fade(x))).clamp(-1.0, 1.0)
+    }
+
+    /// Samples two-dimensional noise.
+    pub fn noise2(&self, position: Fecc) -> f64 {
+        let cell = position.floor();
+        let ix = (cell.x & 0xff) as usize;
+        let iy = (cell.y & 0xff) as usize;
+        let x = position.x - position.x.floor();
+        let y = position.y - position.y.floor();
+
+        let p = &self.permutation;
+        let aa = p[p[ix] as usize + iy];
+        let ab = p[p[ix] as usize + iy + 1];
+        let ba = p[p[ix + 1] as usize + iy];
+        let bb = p[p[ix + 1] as usize + iy + 1];
+
+        let (u, v) = (fade(x), fade(y));
+        let bottom = lerp(grad2(aa, x, y), grad2(ba, x - 1.0, y), u);
+        let top = lerp(grad2(ab, x, y - 1.0), grad2(bb, x - 1.0, y - 1.0), u);
+
+        lerp(bottom, top, v).clamp(-1.0, 1.0)
+    }
+
+    /// Samples curl noise — the curl of two-dimensional noise, computed with
+    /// central differences `epsilon` apart, as described by Robert Bridson
+    /// et al. in *Curl-Noise for Procedural Fluid Flow* (SIGGRAPH 2007). The
+    /// field is divergence-free, so particles following it swirl like in a
+    /// fluid without bunching up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{noise::Perlin, Fecc};
+    ///
+    /// let perlin = Perlin::new(42);
+    /// let curl = |p: Fecc| perlin.curl_noise(p, 1e-4);
+    ///
+    /// // The divergence is (close to) zero.
+    /// let (p, h) = (Fecc::new(1.3, 2.7), 1e-3);
+    /// let divergence = (curl(p + Fecc::new(h, 0.0)).x
+    ///     - curl(p - Fecc::new(h, 0.0)).x
+    ///     + curl(p + Fecc::new(0.0, h)).y
+    ///     - curl(p - Fecc::new(0.0, h)).y)
+    ///     / (2.0 * h);
+    ///
+    /// assert!(divergence.abs() < 1e-3);
+    /// ```
+    pub fn curl_noise(&self, position: Fecc, epsilon: f64) -> Fecc {
+        let dx = Fecc::new(epsilon, 0.0);
+        let dy = Fecc::new(0.0, epsilon);
+        let ddx = self.noise2(position + dx) - self.noise2(position - dx);
+        let ddy = self.noise2(position + dy) - self.noise2(position - dy);
+
+        Fecc::new(ddy, -ddx) / (2.0 * epsilon)
+    }
+}
+
+impl Default for Perlin {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+fn grad2(hash: u8, x: f64, y: f64) -> f64 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
@@ -0,0 +1,118 @@
+//! [1€ filter](https://gery.casiez.net/1euro/) — low-latency smoothing of
+//! noisy input.
+
+use std::f64::consts::TAU;
+
+use crate::{pid::Signal, Fecc};
+
+/// Adaptive low-pass filter by Géry Casiez, Nicolas Roussel and Daniel
+/// Vogel, smoothing e.g. pointer or gesture positions.
+///
+/// The cutoff frequency grows with the speed of the signal: slow movements
+/// are smoothed heavily to remove jitter, while fast ones are followed
+/// closely to reduce lag. Tune it by first setting
+/// [`beta`](crate::one_euro::OneEuroFilter::beta) to zero and lowering
+/// [`min_cutoff`](crate::one_euro::OneEuroFilter::min_cutoff) until the
+/// jitter is acceptable when moving slowly, then increasing `beta` until the
+/// lag is acceptable when moving fast.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{one_euro::OneEuroFilter, Fecc};
+///
+/// let mut filter = OneEuroFilter::new(1.0, 0.01);
+///
+/// // A jittery pointer at rest.
+/// for i in 0..100 {
+///     let jitter = if i % 2 == 0 { 0.5 } else { -0.5 };
+///     let input = Fecc::new(10.0 + jitter, 5.0);
+///     let smoothed = filter.filter(input, 1.0 / 60.0);
+///
+///     if i > 50 {
+///         assert!((smoothed.x - 10.0).abs() < 0.1);
+///     }
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct OneEuroFilter<T = Fecc> {
+    /// The minimal cutoff frequency in hertz. Lower values remove more
+    /// jitter at low speeds.
+    pub min_cutoff: f64,
+
+    /// How fast the cutoff frequency grows with the speed. Higher values
+    /// reduce the lag at high speeds.
+    pub beta: f64,
+
+    /// The cutoff frequency in hertz used to smooth the speed.
+    pub derivative_cutoff: f64,
+
+    /// The previous output and its derivative.
+    previous: Option<(T, T)>,
+}
+
+impl<T> OneEuroFilter<T>
+where
+    T: Signal,
+{
+    /// Constructs a new filter with the specified minimal cutoff frequency
+    /// and speed coefficient. The derivative cutoff frequency is 1 Hz.
+    pub fn new(min_cutoff: f64, beta: f64) -> Self {
+        Self {
+            min_cutoff,
+            beta,
+            derivative_cutoff: 1.0,
+            previous: None,
+        }
+    }
+
+    /// Sets the cutoff frequency used to smooth the speed.
+    pub fn with_derivative_cutoff(mut self, derivative_cutoff: f64) -> Self {
+        self.derivative_cutoff = derivative_cutoff;
+
+        self
+    }
+
+    /// Returns the last output, if there was any input yet.
+    pub fn value(&self) -> Option<T> {
+        self.previous.map(|(value, _)| value)
+    }
+
+    /// Forgets the previous input, e.g. when the pointer is lifted.
+    pub fn reset(&mut self) {
+        self.previous = None;
+    }
+
+    /// Filters a new value arriving `dt` seconds after the previous one and
+    /// returns the smoothed value. The first value is returned unchanged.
+    pub fn filter(&mut self, value: T, dt: f64) -> T {
+        let (previous, previous_derivative) = match self.previous {
+            Some(previous) if dt > 0.0 => previous,
+            Some((previous, _)) => return previous,
+            None => {
+                self.previous = Some((value, T::default()));
+
+                return value;
+            }
+        };
+
+        let difference = value.difference(previous);
+        let derivative = previous_derivative
+            + (difference * (1.0 / dt) - previous_derivative)
+                * smoothing(self.derivative_cutoff, dt);
+        let cutoff = self.min_cutoff + self.beta * derivative.magnitude();
+        let filtered = previous + difference * smoothing(cutoff, dt);
+
+        self.previous = Some((filtered, derivative));
+
+        filtered
+    }
+}
+
+/// Returns the smoothing factor of an exponential low-pass filter with the
+/// specified cutoff frequency.
+fn smoothing(cutoff: f64, dt: f64) -> f64 {
+    let tau = 1.0 / (TAU * cutoff);
+
+    1.0 / (1.0 + tau / dt)
+}
@@ -0,0 +1,341 @@
+//! Ready-made oscillating systems — pendulums and springs.
+//!
+//! The Y axis points up, so gravity pulls the bobs towards negative Y. Angles
+//! of pendulums are measured counterclockwise from the straight-down
+//! position. All of the systems are integrated with the semi-implicit Euler
+//! method, the same way as [`Body2`](crate::body::Body2).
+
+use std::f64::consts::TAU;
+
+use crate::{Angle, Fecc};
+
+/// Simple pendulum — a point mass on a massless rod.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{oscillator::Pendulum, Fecc};
+///
+/// let mut pendulum = Pendulum::new(Fecc::zero(), 1.0, 0.1, 9.81);
+/// let start = pendulum.bob_position();
+///
+/// let dt = 0.0001;
+/// let steps = (pendulum.period() / dt).round() as usize;
+///
+/// for _ in 0..steps {
+///     pendulum.step(dt);
+/// }
+///
+/// // Back where it started after one period.
+/// let end = pendulum.bob_position();
+/// assert_approx_eq!(f64, end.x, start.x, epsilon = 1e-3);
+/// assert_approx_eq!(f64, end.y, start.y, epsilon = 1e-3);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Pendulum {
+    /// The point the pendulum hangs from.
+    pub pivot: Fecc,
+
+    /// The length of the rod.
+    pub length: f64,
+
+    /// The angle between the rod and the straight-down direction.
+    pub angle: Angle,
+
+    /// The angular velocity in radians per second. Positive values mean
+    /// counterclockwise rotation.
+    pub omega: f64,
+
+    /// The gravitational acceleration.
+    pub gravity: f64,
+
+    /// The angular damping coefficient. `0.0` means no damping.
+    pub damping: f64,
+}
+
+impl Pendulum {
+    /// Constructs a new pendulum at rest, released at the specified angle.
+    pub fn new<A>(pivot: Fecc, length: f64, angle: A, gravity: f64) -> Self
+    where
+        A: Into<Angle>,
+    {
+        Self {
+            pivot,
+            length,
+            angle: angle.into(),
+            omega: 0.0,
+            gravity,
+            damping: 0.0,
+        }
+    }
+
+    /// Advances the simulation by `dt` seconds.
+    pub fn step(&mut self, dt: f64) {
+        let alpha = -self.gravity / self.length * self.angle.sin()
+            - self.damping * self.omega;
+
+        self.omega += alpha * dt;
+        self.angle += Angle::from(self.omega * dt);
+    }
+
+    /// Returns the position of the bob.
+    pub fn bob_position(&self) -> Fecc {
+        self.pivot + hanging(self.angle, self.length)
+    }
+
+    /// Returns the period of small oscillations, `2π √(length / gravity)`.
+    /// Larger swings take slightly longer.
+    pub fn period(&self) -> f64 {
+        TAU * (self.length / self.gravity).sqrt()
+    }
+
+    /// Returns the mechanical energy per unit mass of the bob. It stays
+    /// (approximately) constant unless the pendulum is damped.
+    pub fn energy(&self) -> f64 {
+        let kinetic = 0.5 * (self.length * self.omega).powi(2);
+        let potential = self.gravity * self.length * (1.0 - self.angle.cos());
+
+        kinetic + potential
+    }
+}
+
+/// Double pendulum — a pendulum hanging from the bob of another one. Its
+/// motion is chaotic, so tiny differences in the initial angles quickly
+/// lead to completely different trajectories.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{oscillator::DoublePendulum, Fecc};
+///
+/// let mut a = DoublePendulum::new(Fecc::zero(), 1.0, 1.0, 2.0, 2.0, 9.81);
+/// let mut b = a;
+/// b.angle2 = (2.0 + 1e-9).into();
+///
+/// for _ in 0..20_000 {
+///     a.step(0.001);
+///     b.step(0.001);
+/// }
+///
+/// // The rods are rigid...
+/// let (first, second) = a.bob_positions();
+/// assert_approx_eq!(f64, first.mag(), 1.0);
+/// assert_approx_eq!(f64, first.dist(second), 1.0, epsilon = 1e-9);
+///
+/// // ...but the pendulums have drifted apart.
+/// assert!(second.dist(b.bob_positions().1) > 1e-3);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct DoublePendulum {
+    /// The point the first rod hangs from.
+    pub pivot: Fecc,
+
+    /// The length of the first rod.
+    pub length1: f64,
+
+    /// The length of the second rod.
+    pub length2: f64,
+
+    /// The mass of the first bob.
+    pub mass1: f64,
+
+    /// The mass of the second bob.
+    pub mass2: f64,
+
+    /// The angle between the first rod and the straight-down direction.
+    pub angle1: Angle,
+
+    /// The angle between the second rod and the straight-down direction.
+    pub angle2: Angle,
+
+    /// The angular velocity of the first rod in radians per second.
+    pub omega1: f64,
+
+    /// The angular velocity of the second rod in radians per second.
+    pub omega2: f64,
+
+    /// The gravitational acceleration.
+    pub gravity: f64,
+}
+
+impl DoublePendulum {
+    /// Constructs a new double pendulum at rest with bobs of unit mass,
+    /// released at the specified angles.
+    pub fn new<A, B>(
+        pivot: Fecc,
+        length1: f64,
+        length2: f64,
+        angle1: A,
+        angle2: B,
+        gravity: f64,
+    ) -> Self
+    where
+        A: Into<Angle>,
+        B: Into<Angle>,
+    {
+        Self {
+            pivot,
+            length1,
+            length2,
+            mass1: 1.0,
+            mass2: 1.0,
+            angle1: angle1.into(),
+            angle2: angle2.into(),
+            omega1: 0.0,
+            omega2: 0.0,
+            gravity,
+        }
+    }
+
+    /// Advances the simulation by `dt` seconds.
+    pub fn step(&mut self, dt: f64) {
+        let (m1, m2) = (self.mass1, self.mass2);
+        let (l1, l2) = (self.length1, self.length2);
+        let (w1, w2) = (self.omega1, self.omega2);
+        let (a1, a2) = (*self.angle1, *self.angle2);
+        let g = self.gravity;
+        let delta = a1 - a2;
+        let denominator = 2.0 * m1 + m2 - m2 * (2.0 * delta).cos();
+
+        let alpha1 = (-g * (2.0 * m1 + m2) * a1.sin()
+            - m2 * g * (a1 - 2.0 * a2).sin()
+            - 2.0
+                * delta.sin()
+                * m2
+                * (w2 * w2 * l2 + w1 * w1 * l1 * delta.cos()))
+            / (l1 * denominator);
+        let alpha2 = 2.0
+            * delta.sin()
+            * (w1 * w1 * l1 * (m1 + m2)
+                + g * (m1 + m2) * a1.cos()
+                + w2 * w2 * l2 * m2 * delta.cos())
+            / (l2 * denominator);
+
+        self.omega1 += alpha1 * dt;
+        self.omega2 += alpha2 * dt;
+        self.angle1 += Angle::from(self.omega1 * dt);
+        self.angle2 += Angle::from(self.omega2 * dt);
+    }
+
+    /// Returns the positions of the first and the second bob.
+    pub fn bob_positions(&self) -> (Fecc, Fecc) {
+        let first = self.pivot + hanging(self.angle1, self.length1);
+        let second = first + hanging(self.angle2, self.length2);
+
+        (first, second)
+    }
+}
+
+/// Damped harmonic oscillator — a mass on a spring of zero rest length
+/// attached to a fixed anchor.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{oscillator::Spring, Fecc};
+///
+/// let mut spring = Spring::new(Fecc::zero(), Fecc::new(1.0, 0.0), 4.0, 1.0);
+/// assert_approx_eq!(f64, spring.angular_frequency(), 2.0);
+///
+/// // Half a period later the mass is on the other side of the anchor.
+/// let dt = 0.0001;
+/// let steps = (std::f64::consts::FRAC_PI_2 / dt).round() as usize;
+///
+/// for _ in 0..steps {
+///     spring.step(dt);
+/// }
+///
+/// assert_approx_eq!(f64, spring.bob_position().x, -1.0, epsilon = 1e-3);
+///
+/// // Critically damped springs settle without overshooting.
+/// let mut spring = Spring::new(Fecc::zero(), Fecc::new(1.0, 0.0), 4.0, 1.0);
+/// spring.damping = 4.0;
+/// assert_approx_eq!(f64, spring.damping_ratio(), 1.0);
+///
+/// for _ in 0..50_000 {
+///     spring.step(dt);
+///     assert!(spring.bob_position().x > 0.0);
+/// }
+///
+/// assert!(spring.bob_position().x < 1e-3);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Spring {
+    /// The fixed end of the spring.
+    pub anchor: Fecc,
+
+    /// The position of the mass.
+    pub position: Fecc,
+
+    /// The velocity of the mass.
+    pub velocity: Fecc,
+
+    /// The spring constant.
+    pub stiffness: f64,
+
+    /// The damping coefficient. `0.0` means no damping.
+    pub damping: f64,
+
+    /// The mass attached to the spring.
+    pub mass: f64,
+}
+
+impl Spring {
+    /// Constructs a new undamped spring with the mass at rest.
+    pub fn new(
+        anchor: Fecc,
+        position: Fecc,
+        stiffness: f64,
+        mass: f64,
+    ) -> Self {
+        Self {
+            anchor,
+            position,
+            velocity: Fecc::zero(),
+            stiffness,
+            damping: 0.0,
+            mass,
+        }
+    }
+
+    /// Advances the simulation by `dt` seconds.
+    pub fn step(&mut self, dt: f64) {
+        let force = (self.anchor - self.position) * self.stiffness
+            - self.velocity * self.damping;
+
+        self.velocity += force / self.mass * dt;
+        self.position += self.velocity * dt;
+    }
+
+    /// Returns the position of the mass.
+    pub fn bob_position(&self) -> Fecc {
+        self.position
+    }
+
+    /// Returns the angular frequency of the undamped oscillations,
+    /// `√(stiffness / mass)`.
+    pub fn angular_frequency(&self) -> f64 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    /// Returns the damping ratio. Springs with ratios below `1.0` oscillate
+    /// around the anchor, `1.0` settles the fastest without oscillating and
+    /// larger ratios settle slowly.
+    pub fn damping_ratio(&self) -> f64 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// Returns the mechanical energy of the system.
+    pub fn energy(&self) -> f64 {
+        0.5 * self.mass * self.velocity.mag_squared()
+            + 0.5 * self.stiffness * self.position.dist_squared(self.anchor)
+    }
+}
+
+/// Returns the offset of the end of a rod hanging at the specified angle.
+fn hanging(angle: Angle, length: f64) -> Fecc {
+    Fecc::new(angle.sin(), -angle.cos()) * length
+}
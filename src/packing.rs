@@ -0,0 +1,221 @@
+//! Circle packing.
+
+#[cfg(feature = "random")]
+use std::f64::consts::TAU;
+
+#[cfg(feature = "random")]
+use rand::Rng;
+
+#[cfg(feature = "random")]
+use crate::Fecc;
+use crate::{spatial::SpatialHash, Aabb, Circle};
+
+/// The maximal number of relaxation iterations in
+/// [`pack_circles`](crate::packing::pack_circles).
+#[cfg(feature = "random")]
+const RELAXATION_ITERATIONS: usize = 1000;
+
+/// Overlaps smaller than this are ignored.
+const OVERLAP_TOLERANCE: f64 = 1e-9;
+
+/// Incrementally built packing of non-overlapping circles inside a box.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{packing::CirclePacker, Aabb, Circle, Fecc};
+///
+/// let bounds = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(10.0, 10.0));
+/// let mut packer = CirclePacker::new(bounds, 2.0);
+///
+/// assert!(packer.try_place(Circle::new(Fecc::new(3.0, 3.0), 2.0)));
+/// // Overlapping.
+/// assert!(!packer.try_place(Circle::new(Fecc::new(5.0, 3.0), 1.0)));
+/// // Sticking out of the box.
+/// assert!(!packer.try_place(Circle::new(Fecc::new(9.5, 5.0), 1.0)));
+/// assert!(packer.try_place(Circle::new(Fecc::new(7.0, 3.0), 2.0)));
+///
+/// assert_eq!(packer.circles().len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CirclePacker {
+    bounds: Aabb,
+    max_radius: f64,
+    circles: Vec<Circle>,
+    hash: SpatialHash,
+}
+
+impl CirclePacker {
+    /// Constructs a new, empty packing. Circles larger than `max_radius`
+    /// can't be placed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_radius` isn't positive.
+    pub fn new(bounds: Aabb, max_radius: f64) -> Self {
+        Self {
+            bounds,
+            max_radius,
+            circles: vec![],
+            hash: SpatialHash::new(2.0 * max_radius),
+        }
+    }
+
+    /// Returns the placed circles.
+    pub fn circles(&self) -> &[Circle] {
+        &self.circles
+    }
+
+    /// Returns the placed circles, consuming the packing.
+    pub fn into_circles(self) -> Vec<Circle> {
+        self.circles
+    }
+
+    /// Checks whether the circle fits — it lies inside the box and doesn't
+    /// overlap any of the placed circles.
+    pub fn fits(&self, circle: &Circle) -> bool {
+        let Circle { center, radius } = *circle;
+        let Aabb { min, max } = self.bounds;
+
+        radius <= self.max_radius
+            && center.x - radius >= min.x
+            && center.x + radius <= max.x
+            && center.y - radius >= min.y
+            && center.y + radius <= max.y
+            && self
+                .hash
+                .query(center, radius + self.max_radius)
+                .map(|ix| self.circles[ix])
+                .all(|other| {
+                    other.center.dist(center) + OVERLAP_TOLERANCE
+                        >= other.radius + radius
+                })
+    }
+
+    /// Places the circle if it [fits](crate::packing::CirclePacker::fits)
+    /// and returns whether it was placed.
+    pub fn try_place(&mut self, circle: Circle) -> bool {
+        let fits = self.fits(&circle);
+
+        if fits {
+            self.hash.insert(self.circles.len(), circle.center);
+            self.circles.push(circle);
+        }
+
+        fits
+    }
+}
+
+/// Packs circles with the specified radii into the box, in the same order.
+///
+/// The circles are scattered randomly and then pushed apart until they
+/// don't overlap. If they're too large to fit, some of them will still
+/// overlap. Circles larger than the box are placed in its center.
+///
+/// # Examples
+///
+/// ```
+/// use rand::{rngs::SmallRng, SeedableRng};
+/// use veccentric::{packing, Aabb, Fecc};
+///
+/// let mut rng = SmallRng::from_seed([0xdd; 32]);
+/// let bounds = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(40.0, 40.0));
+/// let radii = (0..30).map(|i| 1.0 + (i % 3) as f64).collect::<Vec<_>>();
+///
+/// let circles = packing::pack_circles(&bounds, &radii, &mut rng);
+///
+/// for (i, a) in circles.iter().enumerate() {
+///     assert!(a.center.x - a.radius >= 0.0 && a.center.x + a.radius <= 40.0);
+///
+///     for b in &circles[i + 1..] {
+///         assert!(a.center.dist(b.center) >= a.radius + b.radius - 1e-6);
+///     }
+/// }
+/// ```
+#[cfg(feature = "random")]
+#[doc(cfg(feature = "random"))]
+pub fn pack_circles<R>(bounds: &Aabb, radii: &[f64], rng: &mut R) -> Vec<Circle>
+where
+    R: Rng,
+{
+    let max_radius = radii.iter().copied().fold(0.0, f64::max);
+    let confine = |center: Fecc, radius: f64| {
+        let inset = |min: f64, max: f64, value: f64| {
+            if max - min < 2.0 * radius {
+                (min + max) / 2.0
+            } else {
+                value.clamp(min + radius, max - radius)
+            }
+        };
+
+        Fecc::new(
+            inset(bounds.min.x, bounds.max.x, center.x),
+            inset(bounds.min.y, bounds.max.y, center.y),
+        )
+    };
+    let mut centers = radii
+        .iter()
+        .map(|&radius| {
+            let t = Fecc::new(rng.gen::<f64>(), rng.gen::<f64>());
+            let position = Fecc::new(
+                bounds.min.x + t.x * bounds.width(),
+                bounds.min.y + t.y * bounds.height(),
+            );
+
+            confine(position, radius)
+        })
+        .collect::<Vec<_>>();
+
+    if max_radius <= 0.0 {
+        return circles(&centers, radii);
+    }
+
+    for _ in 0..RELAXATION_ITERATIONS {
+        let hash = SpatialHash::from_points(2.0 * max_radius, &centers);
+        let mut offsets = vec![Fecc::zero(); centers.len()];
+        let mut overlapping = false;
+
+        for (i, (&a, &radius_a)) in centers.iter().zip(radii).enumerate() {
+            for j in hash.query(a, radius_a + max_radius).filter(|&j| j > i) {
+                let (b, radius_b) = (centers[j], radii[j]);
+                let overlap = radius_a + radius_b - a.dist(b);
+
+                if overlap <= OVERLAP_TOLERANCE {
+                    continue;
+                }
+
+                // Coincident circles are pushed apart in a random direction.
+                let direction = if a == b {
+                    Fecc::from_angle(rng.gen::<f64>() * TAU)
+                } else {
+                    (b - a).normalize()
+                };
+                offsets[i] -= direction * (overlap / 2.0);
+                offsets[j] += direction * (overlap / 2.0);
+                overlapping = true;
+            }
+        }
+
+        if !overlapping {
+            break;
+        }
+
+        for ((center, offset), &radius) in
+            centers.iter_mut().zip(offsets).zip(radii)
+        {
+            *center = confine(*center + offset, radius);
+        }
+    }
+
+    circles(&centers, radii)
+}
+
+/// Zips the centers with the radii.
+#[cfg(feature = "random")]
+fn circles(centers: &[Fecc], radii: &[f64]) -> Vec<Circle> {
+    centers
+        .iter()
+        .zip(radii)
+        .map(|(&center, &radius)| Circle::new(center, radius))
+        .collect()
+}
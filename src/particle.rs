@@ -0,0 +1,95 @@
+//! Particle systems.
+
+use std::iter::FromIterator;
+
+use crate::{field::VectorField, Body2, Fecc};
+
+/// Collection of particles which are moved together.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{
+///     field::Uniform,
+///     particle::ParticleSystem,
+///     Body2, Fecc,
+/// };
+///
+/// let mut system: ParticleSystem = (0..100)
+///     .map(|i| Body2::new(Fecc::new(i as f64, 0.0), 1.0))
+///     .collect();
+/// let wind = Uniform(Fecc::new(1.0, 0.0));
+/// let drag = |_, velocity: Fecc| -velocity * 0.5;
+///
+/// system.apply_field(&wind, 1.0);
+/// system.apply_force_fn(drag, 1.0);
+/// system.step(1.0);
+///
+/// assert_approx_eq!(f64, system.particles[0].velocity.x, 0.5);
+/// assert_approx_eq!(f64, system.particles[10].position.x, 10.5);
+/// ```
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct ParticleSystem {
+    /// The particles.
+    pub particles: Vec<Body2>,
+}
+
+impl ParticleSystem {
+    /// Constructs a new system without particles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a particle.
+    pub fn push(&mut self, particle: Body2) {
+        self.particles.push(particle);
+    }
+
+    /// Applies the force sampled from `field` at each particle's position
+    /// for `dt` seconds.
+    pub fn apply_field<F>(&mut self, field: &F, dt: f64)
+    where
+        F: VectorField + ?Sized,
+    {
+        for particle in &mut self.particles {
+            particle.apply_force(field.sample(particle.position), dt);
+        }
+    }
+
+    /// Applies the force computed by `force` from each particle's position
+    /// and velocity for `dt` seconds.
+    pub fn apply_force_fn<F>(&mut self, mut force: F, dt: f64)
+    where
+        F: FnMut(Fecc, Fecc) -> Fecc,
+    {
+        for particle in &mut self.particles {
+            let force = force(particle.position, particle.velocity);
+            particle.apply_force(force, dt);
+        }
+    }
+
+    /// Moves all particles with their current velocities for `dt` seconds.
+    pub fn step(&mut self, dt: f64) {
+        for particle in &mut self.particles {
+            particle.step(dt);
+        }
+    }
+}
+
+impl From<Vec<Body2>> for ParticleSystem {
+    fn from(particles: Vec<Body2>) -> Self {
+        Self { particles }
+    }
+}
+
+impl FromIterator<Body2> for ParticleSystem {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Body2>,
+    {
+        Self {
+            particles: iter.into_iter().collect(),
+        }
+    }
+}
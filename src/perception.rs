@@ -0,0 +1,158 @@
+//! Sensing helpers — what an agent can see.
+
+use crate::{Angle, Fecc, Ray, Segment};
+
+/// Checks whether `point` lies within the observer's field of view: a cone
+/// of the angle `fov` centered on `heading` and limited to `range`.
+///
+/// If `heading` is a zero vector, only the range is checked.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{perception, Angular, Fecc};
+///
+/// let observer = Fecc::new(0.0, 0.0);
+/// let heading = Fecc::new(1.0, 0.0);
+///
+/// // In front.
+/// assert!(perception::in_fov(
+///     observer,
+///     heading,
+///     Fecc::new(5.0, 1.0),
+///     90.0.deg(),
+///     10.0,
+/// ));
+///
+/// // Behind.
+/// assert!(!perception::in_fov(
+///     observer,
+///     heading,
+///     Fecc::new(-5.0, 0.0),
+///     90.0.deg(),
+///     10.0,
+/// ));
+///
+/// // Too far.
+/// assert!(!perception::in_fov(
+///     observer,
+///     heading,
+///     Fecc::new(20.0, 0.0),
+///     90.0.deg(),
+///     10.0,
+/// ));
+/// ```
+pub fn in_fov<A>(
+    observer: Fecc,
+    heading: Fecc,
+    point: Fecc,
+    fov: A,
+    range: f64,
+) -> bool
+where
+    A: Into<Angle>,
+{
+    let offset = point - observer;
+
+    if offset.mag_squared() > range * range {
+        return false;
+    }
+
+    if offset.is_zero() || heading.is_zero() {
+        return true;
+    }
+
+    heading.angle_to(offset).abs() <= *fov.into() / 2.0
+}
+
+/// Checks whether the line of sight between `observer` and `point` is
+/// blocked by any of the `occluders`.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{perception, Fecc, Segment};
+///
+/// let wall = Segment(Fecc::new(5.0, -10.0), Fecc::new(5.0, 10.0));
+///
+/// assert!(perception::is_occluded(
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(10.0, 0.0),
+///     &[wall],
+/// ));
+/// assert!(!perception::is_occluded(
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(4.0, 0.0),
+///     &[wall],
+/// ));
+/// ```
+pub fn is_occluded(observer: Fecc, point: Fecc, occluders: &[Segment]) -> bool {
+    let offset = point - observer;
+
+    if offset.is_zero() {
+        return false;
+    }
+
+    let dist = offset.mag();
+    let ray = Ray::new(observer, offset);
+
+    occluders.iter().any(|occluder| {
+        ray.cast_segment(occluder)
+            .is_some_and(|hit| hit.distance < dist)
+    })
+}
+
+/// Returns the indices of the `neighbors` within the observer's field of
+/// view (see [`in_fov`](crate::perception::in_fov)) whose line of sight
+/// isn't blocked by any of the `occluders`. Pass an empty slice to skip the
+/// occlusion test.
+///
+/// The indices can be used to look up other properties of the neighbors,
+/// e.g. their velocities.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{perception, Angular, Fecc, Segment};
+///
+/// let neighbors = [
+///     Fecc::new(5.0, 0.0),
+///     Fecc::new(-5.0, 0.0),
+///     Fecc::new(0.0, 8.0),
+/// ];
+/// let wall = Segment(Fecc::new(-1.0, 4.0), Fecc::new(1.0, 4.0));
+///
+/// let visible = perception::visible_neighbors(
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(1.0, 1.0),
+///     &neighbors,
+///     180.0.deg(),
+///     10.0,
+///     &[wall],
+/// );
+///
+/// assert_eq!(visible, vec![0]);
+/// ```
+pub fn visible_neighbors<A>(
+    observer: Fecc,
+    heading: Fecc,
+    neighbors: &[Fecc],
+    fov: A,
+    range: f64,
+    occluders: &[Segment],
+) -> Vec<usize>
+where
+    A: Into<Angle>,
+{
+    let fov = fov.into();
+
+    neighbors
+        .iter()
+        .enumerate()
+        .filter(|&(_, &neighbor)| {
+            in_fov(observer, heading, neighbor, fov, range)
+                && !is_occluded(observer, neighbor, occluders)
+        })
+        .map(|(ix, _)| ix)
+        .collect()
+}
@@ -0,0 +1,203 @@
+//! [PID controller](https://en.wikipedia.org/wiki/PID_controller).
+
+use std::{
+    f64::consts::{PI, TAU},
+    ops::{Add, Mul, Sub},
+};
+
+use crate::{Angle, Fecc};
+
+/// Value a [`Pid`](crate::pid::Pid) controller or a
+/// [`OneEuroFilter`](crate::one_euro::OneEuroFilter) can work with.
+pub trait Signal:
+    Copy
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<f64, Output = Self>
+{
+    /// Returns `self - other`. Angles are wrapped to `[-π, π)` so that the
+    /// controller always turns the shorter way.
+    fn difference(self, other: Self) -> Self {
+        self - other
+    }
+
+    /// Limits the magnitude of the value.
+    fn limit(self, limit: f64) -> Self;
+
+    /// Returns the magnitude of the value.
+    fn magnitude(self) -> f64;
+}
+
+impl Signal for f64 {
+    fn limit(self, limit: f64) -> Self {
+        self.clamp(-limit, limit)
+    }
+
+    fn magnitude(self) -> f64 {
+        self.abs()
+    }
+}
+
+impl Signal for Fecc {
+    fn limit(self, limit: f64) -> Self {
+        Fecc::limit(&self, limit)
+    }
+
+    fn magnitude(self) -> f64 {
+        self.mag()
+    }
+}
+
+impl Signal for Angle {
+    fn difference(self, other: Self) -> Self {
+        Angle::from((*self - *other + PI).rem_euclid(TAU) - PI)
+    }
+
+    fn limit(self, limit: f64) -> Self {
+        Angle::from(self.clamp(-limit, limit))
+    }
+
+    fn magnitude(self) -> f64 {
+        self.abs()
+    }
+}
+
+/// Proportional–integral–derivative controller.
+///
+/// The output is the sum of the error, its integral and its derivative,
+/// each multiplied by its gain. The magnitude of the integral can be limited
+/// to prevent it from growing without bounds while the output can't reduce
+/// the error (integral windup).
+///
+/// Use either [`update`](crate::pid::Pid::update) or
+/// [`update_measured`](crate::pid::Pid::update_measured) with one
+/// controller, not both.
+///
+/// # Examples
+///
+/// Steering a vehicle towards a target heading.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{pid::Pid, Angular};
+///
+/// let mut pid = Pid::new(4.0, 0.1, 0.2).with_integral_limit(1.0);
+/// let target = 170.deg();
+/// let mut heading = (-170.0).deg();
+///
+/// for _ in 0..200 {
+///     let turn_rate = pid.update_measured(target, heading, 0.05);
+///     heading = heading + turn_rate * 0.05;
+/// }
+///
+/// // It turned the shorter way, across ±180°.
+/// assert_approx_eq!(f64, *heading, *(-190.0).deg(), epsilon = 0.01);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Pid<T> {
+    /// The proportional gain.
+    pub kp: f64,
+
+    /// The integral gain.
+    pub ki: f64,
+
+    /// The derivative gain.
+    pub kd: f64,
+
+    /// The maximal magnitude of the integral.
+    pub integral_limit: f64,
+
+    integral: T,
+    previous_error: Option<T>,
+    previous_measurement: Option<T>,
+}
+
+impl<T> Pid<T>
+where
+    T: Signal,
+{
+    /// Constructs a new controller with the specified gains and no integral
+    /// limit.
+    pub fn new(kp: f64, ki: f64, kd: f64) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            integral_limit: f64::INFINITY,
+            integral: T::default(),
+            previous_error: None,
+            previous_measurement: None,
+        }
+    }
+
+    /// Sets the maximal magnitude of the integral.
+    pub fn with_integral_limit(mut self, limit: f64) -> Self {
+        self.integral_limit = limit;
+
+        self
+    }
+
+    /// Returns the current integral of the error.
+    pub fn integral(&self) -> T {
+        self.integral
+    }
+
+    /// Clears the integral and the previous error and measurement, e.g.
+    /// after the target changes abruptly.
+    pub fn reset(&mut self) {
+        self.integral = T::default();
+        self.previous_error = None;
+        self.previous_measurement = None;
+    }
+
+    /// Returns the output for the error (`target - measurement`) after `dt`
+    /// seconds. The derivative is taken of the error, so a sudden change of
+    /// the target causes a spike in the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{pid::Pid, Fecc};
+    ///
+    /// let mut pid = Pid::new(2.0, 0.0, 0.0);
+    /// let output = pid.update(Fecc::new(1.0, -3.0), 0.1);
+    ///
+    /// assert_approx_eq!(f64, output.x, 2.0);
+    /// assert_approx_eq!(f64, output.y, -6.0);
+    /// ```
+    pub fn update(&mut self, error: T, dt: f64) -> T {
+        let derivative = match self.previous_error {
+            Some(previous) if dt > 0.0 => {
+                error.difference(previous) * (1.0 / dt)
+            }
+            _ => T::default(),
+        };
+        self.previous_error = Some(error);
+
+        self.output(error, derivative, dt)
+    }
+
+    /// Returns the output steering `measurement` towards `target` after `dt`
+    /// seconds. The derivative is taken of the measurement instead of the
+    /// error, which avoids spikes when the target changes.
+    pub fn update_measured(&mut self, target: T, measurement: T, dt: f64) -> T {
+        let error = target.difference(measurement);
+        let derivative = match self.previous_measurement {
+            Some(previous) if dt > 0.0 => {
+                previous.difference(measurement) * (1.0 / dt)
+            }
+            _ => T::default(),
+        };
+        self.previous_measurement = Some(measurement);
+
+        self.output(error, derivative, dt)
+    }
+
+    fn output(&mut self, error: T, derivative: T, dt: f64) -> T {
+        self.integral = (self.integral + error * dt).limit(self.integral_limit);
+
+        error * self.kp + self.integral * self.ki + derivative * self.kd
+    }
+}
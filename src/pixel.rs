@@ -0,0 +1,107 @@
+//! Conversions between pixels and positions.
+//!
+//! The conventions match the example engine's `Buffer`: pixel `(x, y)`
+//! covers the area `[x, x + 1) × [y, y + 1)` (multiplied by the pixel size)
+//! and pixels are stored row by row, so sampling a function over an image
+//! looks the same in every program.
+//!
+//! # Examples
+//!
+//! ```
+//! use veccentric::{pixel, Fecc};
+//!
+//! let (width, height) = (4, 3);
+//! let mut image = vec![0.0; width * height];
+//!
+//! for (value, center) in
+//!     image.iter_mut().zip(pixel::iter_pixel_centers(width, height))
+//! {
+//!     *value = center.x * center.y;
+//! }
+//!
+//! let cell = pixel::pixel_of(Fecc::new(1.2, 2.9), 1.0);
+//! let ix = pixel::index(cell, width, height).unwrap();
+//! assert_eq!(image[ix], 1.5 * 2.5);
+//! ```
+
+use crate::{Fecc, Vecc};
+
+/// Returns the position of the center of the pixel.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{pixel, Fecc};
+///
+/// assert_eq!(pixel::pixel_center(0, 0, 1.0), Fecc::new(0.5, 0.5));
+/// assert_eq!(pixel::pixel_center(2, -1, 4.0), Fecc::new(10.0, -2.0));
+/// ```
+pub fn pixel_center(ix: i64, iy: i64, pixel_size: f64) -> Fecc {
+    Fecc::new(ix as f64 + 0.5, iy as f64 + 0.5) * pixel_size
+}
+
+/// Returns the pixel containing the position.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{pixel, Fecc, Vecc};
+///
+/// assert_eq!(pixel::pixel_of(Fecc::new(0.5, 0.99), 1.0), Vecc::new(0, 0));
+/// assert_eq!(pixel::pixel_of(Fecc::new(10.0, -2.0), 4.0), Vecc::new(2, -1));
+/// ```
+pub fn pixel_of(position: Fecc, pixel_size: f64) -> Vecc<i64> {
+    (position / pixel_size).floor()
+}
+
+/// Returns the index of the pixel in an image stored row by row or `None`
+/// if the pixel lies outside the image.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{pixel, Vecc};
+///
+/// assert_eq!(pixel::index(Vecc::new(1, 2), 4, 3), Some(9));
+/// assert_eq!(pixel::index(Vecc::new(4, 0), 4, 3), None);
+/// assert_eq!(pixel::index(Vecc::new(0, -1), 4, 3), None);
+/// ```
+pub fn index(pixel: Vecc<i64>, width: usize, height: usize) -> Option<usize> {
+    let size = Vecc::new(width as i64, height as i64);
+
+    if pixel.all_ge(Vecc::new(0, 0)) && pixel.all_lt(size) {
+        Some(pixel.x as usize + pixel.y as usize * width)
+    } else {
+        None
+    }
+}
+
+/// Returns an iterator over the centers of the pixels of an image with
+/// pixels of size `1.0`, row by row — in the same order as
+/// [`index`](crate::pixel::index).
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{pixel, Fecc};
+///
+/// let centers: Vec<_> = pixel::iter_pixel_centers(2, 2).collect();
+///
+/// assert_eq!(
+///     centers,
+///     vec![
+///         Fecc::new(0.5, 0.5),
+///         Fecc::new(1.5, 0.5),
+///         Fecc::new(0.5, 1.5),
+///         Fecc::new(1.5, 1.5),
+///     ]
+/// );
+/// ```
+pub fn iter_pixel_centers(
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = Fecc> {
+    (0..height).flat_map(move |iy| {
+        (0..width).map(move |ix| pixel_center(ix as i64, iy as i64, 1.0))
+    })
+}
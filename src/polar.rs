@@ -0,0 +1,172 @@
+//! Polar coordinates.
+
+use std::{f64::consts::PI, ops::*};
+
+use crate::{Angle, Fecc};
+
+/// Point or vector in polar coordinates — the distance from the origin and
+/// the counterclockwise angle from the positive X axis.
+///
+/// Converting from [`Fecc`](crate::fecc::Fecc) yields `r >= 0` and `theta`
+/// in the range `[-π, π]`, the zero vector becomes `r = 0`, `theta = 0`.
+/// Converting back and forth only introduces rounding errors.
+///
+/// Multiplication of two polar coordinates multiplies their distances and
+/// adds their angles (like multiplication of complex numbers), so it can be
+/// used to scale and rotate at once.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{polar::Polar, Angular, Fecc};
+///
+/// let polar = Polar::from(Fecc::new(0.0, 2.0));
+/// assert_approx_eq!(f64, polar.r, 2.0);
+/// assert_approx_eq!(f64, *polar.theta, 90.0_f64.to_radians());
+///
+/// let orbit = polar.with_r(3.0) * Polar::new(1.0, 90.deg());
+/// let position = Fecc::from(orbit);
+///
+/// assert_approx_eq!(f64, position.x, -3.0);
+/// assert_approx_eq!(f64, position.y, 0.0, epsilon = 1e-9);
+/// ```
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Polar {
+    /// The distance from the origin.
+    pub r: f64,
+
+    /// The angle from the positive X axis.
+    pub theta: Angle,
+}
+
+impl Polar {
+    /// Constructs new polar coordinates.
+    pub fn new<A>(r: f64, theta: A) -> Self
+    where
+        A: Into<Angle>,
+    {
+        Self {
+            r,
+            theta: theta.into(),
+        }
+    }
+
+    /// Sets the distance from the origin.
+    pub fn with_r(mut self, r: f64) -> Self {
+        self.r = r;
+
+        self
+    }
+
+    /// Sets the angle.
+    pub fn with_theta<A>(mut self, theta: A) -> Self
+    where
+        A: Into<Angle>,
+    {
+        self.theta = theta.into();
+
+        self
+    }
+
+    /// Rotates counterclockwise by the angle.
+    pub fn rotate<A>(self, angle: A) -> Self
+    where
+        A: Into<Angle>,
+    {
+        Self {
+            r: self.r,
+            theta: self.theta + angle.into(),
+        }
+    }
+}
+
+impl From<Fecc> for Polar {
+    fn from(vector: Fecc) -> Self {
+        Self {
+            r: vector.mag(),
+            theta: Angle::from(vector.angle()),
+        }
+    }
+}
+
+impl From<Polar> for Fecc {
+    fn from(Polar { r, theta }: Polar) -> Self {
+        Fecc::from_angle(theta) * r
+    }
+}
+
+/// Points in the opposite direction.
+impl Neg for Polar {
+    type Output = Polar;
+
+    fn neg(self) -> Polar {
+        self.rotate(PI)
+    }
+}
+
+/// Multiplies the distances and adds the angles.
+impl Mul<Polar> for Polar {
+    type Output = Polar;
+
+    fn mul(self, rhs: Polar) -> Polar {
+        Polar {
+            r: self.r * rhs.r,
+            theta: self.theta + rhs.theta,
+        }
+    }
+}
+
+/// Divides the distances and subtracts the angles.
+impl Div<Polar> for Polar {
+    type Output = Polar;
+
+    fn div(self, rhs: Polar) -> Polar {
+        Polar {
+            r: self.r / rhs.r,
+            theta: self.theta - rhs.theta,
+        }
+    }
+}
+
+/// Scales the distance.
+impl Mul<f64> for Polar {
+    type Output = Polar;
+
+    fn mul(self, rhs: f64) -> Polar {
+        self.with_r(self.r * rhs)
+    }
+}
+
+/// Scales the distance.
+impl Div<f64> for Polar {
+    type Output = Polar;
+
+    fn div(self, rhs: f64) -> Polar {
+        self.with_r(self.r / rhs)
+    }
+}
+
+impl MulAssign<Polar> for Polar {
+    fn mul_assign(&mut self, rhs: Polar) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<Polar> for Polar {
+    fn div_assign(&mut self, rhs: Polar) {
+        *self = *self / rhs;
+    }
+}
+
+impl MulAssign<f64> for Polar {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.r *= rhs;
+    }
+}
+
+impl DivAssign<f64> for Polar {
+    fn div_assign(&mut self, rhs: f64) {
+        self.r /= rhs;
+    }
+}
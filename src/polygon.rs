@@ -0,0 +1,253 @@
+//! Polygon.
+
+use std::iter::FromIterator;
+
+use crate::{shape::Contains, Fecc, Segment, Triangle};
+
+/// Closed shape described by its vertices. The last vertex is implicitly
+/// connected to the first one.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{Fecc, Polygon};
+///
+/// let square = Polygon(vec![
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(1.0, 0.0),
+///     Fecc::new(1.0, 1.0),
+///     Fecc::new(0.0, 1.0),
+/// ]);
+///
+/// assert_eq!(square.edges().count(), 4);
+/// assert!(square.is_convex());
+/// ```
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct Polygon(pub Vec<Fecc>);
+
+impl Polygon {
+    /// Constructs a new polygon.
+    pub fn new(vertices: Vec<Fecc>) -> Self {
+        Self(vertices)
+    }
+
+    /// Returns an iterator over the edges, including the one closing the
+    /// polygon.
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + '_ {
+        let n = self.0.len();
+
+        (0..n).map(move |i| Segment(self.0[i], self.0[(i + 1) % n]))
+    }
+
+    /// Returns the average of the vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polygon has no vertices.
+    pub fn vertex_mean(&self) -> Fecc {
+        assert!(!self.0.is_empty(), "the polygon has no vertices");
+
+        self.0.iter().fold(Fecc::zero(), |sum, &v| sum + v)
+            / self.0.len() as f64
+    }
+
+    /// Checks whether the polygon is convex. The vertices may be ordered
+    /// clockwise or counterclockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{Fecc, Polygon};
+    ///
+    /// let arrow = Polygon(vec![
+    ///     Fecc::new(0.0, 0.0),
+    ///     Fecc::new(2.0, 1.0),
+    ///     Fecc::new(0.0, 2.0),
+    ///     Fecc::new(1.0, 1.0),
+    /// ]);
+    ///
+    /// assert!(!arrow.is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool {
+        let n = self.0.len();
+        let mut sign = 0.0;
+
+        for i in 0..n {
+            let a = self.0[i];
+            let b = self.0[(i + 1) % n];
+            let c = self.0[(i + 2) % n];
+            let turn = (b - a).cross(c - b);
+
+            if turn != 0.0 {
+                if sign * turn < 0.0 {
+                    return false;
+                }
+
+                sign = turn;
+            }
+        }
+
+        true
+    }
+
+    /// Returns the signed area — positive if the vertices are ordered
+    /// counterclockwise, negative otherwise.
+    pub fn signed_area(&self) -> f64 {
+        self.edges().map(|Segment(a, b)| a.cross(b)).sum::<f64>() / 2.0
+    }
+
+    /// Splits a simple polygon (one without self-intersections) into
+    /// triangles using ear clipping. Returns triples of vertex indices, each
+    /// ordered the same way as the polygon's vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{Fecc, Polygon};
+    ///
+    /// // L-shape.
+    /// let polygon = Polygon(vec![
+    ///     Fecc::new(0.0, 0.0),
+    ///     Fecc::new(2.0, 0.0),
+    ///     Fecc::new(2.0, 1.0),
+    ///     Fecc::new(1.0, 1.0),
+    ///     Fecc::new(1.0, 2.0),
+    ///     Fecc::new(0.0, 2.0),
+    /// ]);
+    ///
+    /// assert_eq!(polygon.triangulate().len(), 4);
+    /// ```
+    pub fn triangulate(&self) -> Vec<[usize; 3]> {
+        let vertices = &self.0;
+        let orientation = self.signed_area().signum();
+        let mut remaining: Vec<usize> = (0..vertices.len()).collect();
+        let mut triangles = vec![];
+
+        while remaining.len() > 3 {
+            let n = remaining.len();
+            let ear = (0..n).find(|&i| {
+                let (a, b, c) = (
+                    remaining[(i + n - 1) % n],
+                    remaining[i],
+                    remaining[(i + 1) % n],
+                );
+                let (pa, pb, pc) = (vertices[a], vertices[b], vertices[c]);
+
+                // Convex corner with no other vertex inside.
+                (pb - pa).cross(pc - pb) * orientation > 0.0
+                    && remaining.iter().all(|&v| {
+                        v == a
+                            || v == b
+                            || v == c
+                            || !Triangle(pa, pb, pc).contains(vertices[v])
+                    })
+            });
+
+            // Degenerate input (e.g. collinear vertices only) has no ears.
+            let i = match ear {
+                Some(i) => i,
+                None => break,
+            };
+
+            triangles.push([
+                remaining[(i + n - 1) % n],
+                remaining[i],
+                remaining[(i + 1) % n],
+            ]);
+            remaining.remove(i);
+        }
+
+        if remaining.len() == 3 {
+            triangles.push([remaining[0], remaining[1], remaining[2]]);
+        }
+
+        triangles
+    }
+
+    /// Splits a simple polygon into convex pieces.
+    ///
+    /// The polygon is [triangulated](crate::polygon::Polygon::triangulate)
+    /// and then neighboring pieces are merged as long as the result stays
+    /// convex (the Hertel–Mehlhorn algorithm). The number of pieces is at
+    /// most four times the optimum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{Fecc, Polygon};
+    ///
+    /// // L-shape.
+    /// let polygon = Polygon(vec![
+    ///     Fecc::new(0.0, 0.0),
+    ///     Fecc::new(2.0, 0.0),
+    ///     Fecc::new(2.0, 1.0),
+    ///     Fecc::new(1.0, 1.0),
+    ///     Fecc::new(1.0, 2.0),
+    ///     Fecc::new(0.0, 2.0),
+    /// ]);
+    /// let pieces = polygon.decompose_convex();
+    ///
+    /// assert_eq!(pieces.len(), 2);
+    /// assert!(pieces.iter().all(Polygon::is_convex));
+    /// ```
+    pub fn decompose_convex(&self) -> Vec<Polygon> {
+        let mut pieces: Vec<Vec<usize>> =
+            self.triangulate().iter().map(|t| t.to_vec()).collect();
+
+        'merging: loop {
+            for i in 0..pieces.len() {
+                for j in (i + 1)..pieces.len() {
+                    if let Some(merged) = self.merge(&pieces[i], &pieces[j]) {
+                        pieces[i] = merged;
+                        pieces.swap_remove(j);
+
+                        continue 'merging;
+                    }
+                }
+            }
+
+            break;
+        }
+
+        pieces
+            .into_iter()
+            .map(|piece| piece.into_iter().map(|v| self.0[v]).collect())
+            .collect()
+    }
+
+    /// Merges two pieces sharing an edge if the result is convex.
+    fn merge(&self, p: &[usize], q: &[usize]) -> Option<Vec<usize>> {
+        let (np, nq) = (p.len(), q.len());
+        let (i, j) = (0..np).find_map(|i| {
+            let (a, b) = (p[i], p[(i + 1) % np]);
+
+            (0..nq)
+                .find(|&j| q[j] == b && q[(j + 1) % nq] == a)
+                .map(|j| (i, j))
+        })?;
+
+        // `p` from `b` around to `a`, then `q` from after `a` to before `b`.
+        let merged: Vec<usize> = (0..np)
+            .map(|k| p[(i + 1 + k) % np])
+            .chain((0..(nq - 2)).map(|k| q[(j + 2 + k) % nq]))
+            .collect();
+        let polygon: Polygon = merged.iter().map(|&v| self.0[v]).collect();
+
+        polygon.is_convex().then_some(merged)
+    }
+}
+
+impl From<Vec<Fecc>> for Polygon {
+    fn from(vertices: Vec<Fecc>) -> Self {
+        Self(vertices)
+    }
+}
+
+impl FromIterator<Fecc> for Polygon {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Fecc>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
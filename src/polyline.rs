@@ -0,0 +1,387 @@
+//! Polyline — a path made of straight segments.
+
+use std::iter::FromIterator;
+
+use crate::{Fecc, Segment};
+
+/// A path made of straight segments connecting consecutive points.
+///
+/// The parametric methods ([`point_at`](crate::polyline::Polyline::point_at),
+/// [`tangent_at`](crate::polyline::Polyline::tangent_at)) are parametrized by
+/// arc length, i.e. `t = 0.5` always refers to the point halfway along the
+/// path, no matter how long the individual segments are.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{Fecc, Polyline};
+///
+/// let path = Polyline(vec![
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(10.0, 0.0),
+///     Fecc::new(10.0, 30.0),
+/// ]);
+///
+/// assert_approx_eq!(f64, path.length(), 40.0);
+///
+/// let quarter = path.point_at(0.25);
+/// assert_approx_eq!(f64, quarter.x, 10.0);
+/// assert_approx_eq!(f64, quarter.y, 0.0);
+///
+/// let half = path.point_at(0.5);
+/// assert_approx_eq!(f64, half.x, 10.0);
+/// assert_approx_eq!(f64, half.y, 10.0);
+/// ```
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct Polyline(pub Vec<Fecc>);
+
+impl Polyline {
+    /// Constructs a new polyline.
+    pub fn new(points: Vec<Fecc>) -> Self {
+        Self(points)
+    }
+
+    /// Returns an iterator over the segments as `(start, end)` pairs.
+    pub fn segments(&self) -> impl Iterator<Item = (Fecc, Fecc)> + '_ {
+        self.0.windows(2).map(|pair| (pair[0], pair[1]))
+    }
+
+    /// Returns the total length of the polyline.
+    pub fn length(&self) -> f64 {
+        self.segments().map(|(a, b)| a.dist(b)).sum()
+    }
+
+    /// Returns the point at the specified fraction of the polyline's length.
+    /// `t` is clamped to `[0.0, 1.0]`.
+    ///
+    /// This method computes the lengths of all segments on every call. Use
+    /// [`parametrize`](crate::polyline::Polyline::parametrize) to sample the
+    /// same polyline many times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn point_at(&self, t: f64) -> Fecc {
+        self.parametrize().point_at(t)
+    }
+
+    /// Returns the unit tangent at the specified fraction of the polyline's
+    /// length. `t` is clamped to `[0.0, 1.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{Fecc, Polyline};
+    ///
+    /// let path = Polyline(vec![
+    ///     Fecc::new(0.0, 0.0),
+    ///     Fecc::new(10.0, 0.0),
+    ///     Fecc::new(10.0, 10.0),
+    /// ]);
+    /// let tangent = path.tangent_at(0.75);
+    ///
+    /// assert_approx_eq!(f64, tangent.x, 0.0);
+    /// assert_approx_eq!(f64, tangent.y, 1.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn tangent_at(&self, t: f64) -> Fecc {
+        self.parametrize().tangent_at(t)
+    }
+
+    /// Precomputes the cumulative lengths of the segments, so that the
+    /// polyline can be sampled in `O(log n)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{Fecc, Polyline};
+    ///
+    /// let path: Polyline = (0..=100)
+    ///     .map(|i| Fecc::new(i as f64, 0.0))
+    ///     .collect();
+    /// let table = path.parametrize();
+    ///
+    /// assert_approx_eq!(f64, table.length(), 100.0);
+    /// assert_approx_eq!(f64, table.point_at(0.425).x, 42.5);
+    /// ```
+    pub fn parametrize(&self) -> ArcLengthTable<'_> {
+        let mut total = 0.0;
+        let mut cumulative = Vec::with_capacity(self.0.len());
+
+        if !self.0.is_empty() {
+            cumulative.push(0.0);
+        }
+
+        for (a, b) in self.segments() {
+            total += a.dist(b);
+            cumulative.push(total);
+        }
+
+        ArcLengthTable {
+            polyline: self,
+            cumulative,
+        }
+    }
+
+    /// Returns the point on the polyline closest to `point`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{Fecc, Polyline};
+    ///
+    /// let path = Polyline(vec![
+    ///     Fecc::new(0.0, 0.0),
+    ///     Fecc::new(10.0, 0.0),
+    ///     Fecc::new(10.0, 10.0),
+    /// ]);
+    /// let nearest = path.nearest_point(Fecc::new(3.0, 2.0));
+    ///
+    /// assert_approx_eq!(f64, nearest.x, 3.0);
+    /// assert_approx_eq!(f64, nearest.y, 0.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn nearest_point(&self, point: Fecc) -> Fecc {
+        match self.nearest_segment(point) {
+            Some((_, nearest)) => nearest,
+            None => *self.0.first().expect("the polyline is empty"),
+        }
+    }
+
+    /// Returns the index of the segment closest to `point` together with the
+    /// closest point on that segment. Returns `None` if the polyline has no
+    /// segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{Fecc, Polyline};
+    ///
+    /// let path = Polyline(vec![
+    ///     Fecc::new(0.0, 0.0),
+    ///     Fecc::new(10.0, 0.0),
+    ///     Fecc::new(10.0, 10.0),
+    /// ]);
+    /// let (ix, _) = path.nearest_segment(Fecc::new(12.0, 7.0)).unwrap();
+    ///
+    /// assert_eq!(ix, 1);
+    /// ```
+    pub fn nearest_segment(&self, point: Fecc) -> Option<(usize, Fecc)> {
+        self.segments()
+            .map(|(a, b)| Segment(a, b).closest_point(point))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                a.dist_squared(point).total_cmp(&b.dist_squared(point))
+            })
+    }
+
+    /// Returns a new polyline with `n` points spaced evenly along the
+    /// original one. The first and the last points are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{Fecc, Polyline};
+    ///
+    /// let path = Polyline(vec![Fecc::new(0.0, 0.0), Fecc::new(10.0, 0.0)]);
+    /// let resampled = path.resample(11);
+    ///
+    /// assert_eq!(resampled.0.len(), 11);
+    /// assert_approx_eq!(f64, resampled.0[3].x, 3.0);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty and `n > 0`.
+    pub fn resample(&self, n: usize) -> Self {
+        let table = self.parametrize();
+
+        match n {
+            0 => Self(vec![]),
+            1 => Self(vec![table.point_at(0.0)]),
+            _ => (0..n)
+                .map(|i| table.point_at(i as f64 / (n - 1) as f64))
+                .collect(),
+        }
+    }
+
+    /// Simplifies the polyline using the
+    /// [Ramer–Douglas–Peucker algorithm](https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm).
+    /// Points closer than `epsilon` to the simplified path are removed. The
+    /// first and the last points are always preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{Fecc, Polyline};
+    ///
+    /// let stroke = Polyline(vec![
+    ///     Fecc::new(0.0, 0.0),
+    ///     Fecc::new(1.0, 0.1),
+    ///     Fecc::new(2.0, -0.1),
+    ///     Fecc::new(3.0, 5.0),
+    ///     Fecc::new(4.0, 6.0),
+    ///     Fecc::new(5.0, 7.0),
+    /// ]);
+    /// let simplified = stroke.simplify(0.5);
+    ///
+    /// assert_eq!(
+    ///     simplified.0,
+    ///     vec![
+    ///         Fecc::new(0.0, 0.0),
+    ///         Fecc::new(2.0, -0.1),
+    ///         Fecc::new(3.0, 5.0),
+    ///         Fecc::new(5.0, 7.0),
+    ///     ]
+    /// );
+    /// ```
+    pub fn simplify(&self, epsilon: f64) -> Self {
+        let points = &self.0;
+
+        if points.len() < 3 {
+            return self.clone();
+        }
+
+        let mut keep = vec![false; points.len()];
+        let mut ranges = vec![(0, points.len() - 1)];
+        keep[0] = true;
+        keep[points.len() - 1] = true;
+
+        while let Some((first, last)) = ranges.pop() {
+            let (a, b) = (points[first], points[last]);
+            let farthest = ((first + 1)..last)
+                .map(|ix| {
+                    let closest = Segment(a, b).closest_point(points[ix]);
+
+                    (ix, closest.dist(points[ix]))
+                })
+                .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+            if let Some((ix, dist)) = farthest {
+                if dist > epsilon {
+                    keep[ix] = true;
+                    ranges.push((first, ix));
+                    ranges.push((ix, last));
+                }
+            }
+        }
+
+        points
+            .iter()
+            .zip(keep)
+            .filter_map(|(&point, keep)| if keep { Some(point) } else { None })
+            .collect()
+    }
+}
+
+/// Polyline with precomputed cumulative segment lengths. See
+/// [`Polyline::parametrize`](crate::polyline::Polyline::parametrize).
+#[derive(Clone, Debug)]
+pub struct ArcLengthTable<'a> {
+    polyline: &'a Polyline,
+    cumulative: Vec<f64>,
+}
+
+impl<'a> ArcLengthTable<'a> {
+    /// Returns the parametrized polyline.
+    pub fn polyline(&self) -> &'a Polyline {
+        self.polyline
+    }
+
+    /// Returns the total length of the polyline.
+    pub fn length(&self) -> f64 {
+        self.cumulative.last().copied().unwrap_or(0.0)
+    }
+
+    /// Returns the point at the specified fraction of the polyline's length.
+    /// `t` is clamped to `[0.0, 1.0]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn point_at(&self, t: f64) -> Fecc {
+        let points = &self.polyline.0;
+        let (ix, s) = self.locate(t);
+
+        match points.get(ix + 1) {
+            Some(&next) => points[ix] + (next - points[ix]) * s,
+            None => points[ix],
+        }
+    }
+
+    /// Returns the unit tangent at the specified fraction of the polyline's
+    /// length. `t` is clamped to `[0.0, 1.0]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polyline is empty.
+    pub fn tangent_at(&self, t: f64) -> Fecc {
+        let points = &self.polyline.0;
+        let (ix, _) = self.locate(t);
+
+        match points.get(ix + 1) {
+            Some(&next) => (next - points[ix]).normalize(),
+            None => Fecc::zero(),
+        }
+    }
+
+    /// Finds the segment containing the point at `t` and the position of
+    /// that point along it. Zero-length segments are skipped.
+    fn locate(&self, t: f64) -> (usize, f64) {
+        let n = self.cumulative.len();
+
+        assert!(n > 0, "the polyline is empty");
+
+        let target = t.clamp(0.0, 1.0) * self.length();
+        let mut end = self.cumulative.partition_point(|&c| c < target).max(1);
+
+        while end < n && self.cumulative[end] == self.cumulative[end - 1] {
+            end += 1;
+        }
+
+        if end >= n {
+            // Only zero-length segments left (or no segments at all).
+            return match self.last_nonzero_segment() {
+                Some(ix) => (ix, 1.0),
+                None => (n - 1, 0.0),
+            };
+        }
+
+        let start = self.cumulative[end - 1];
+        let length = self.cumulative[end] - start;
+
+        (end - 1, ((target - start) / length).clamp(0.0, 1.0))
+    }
+
+    fn last_nonzero_segment(&self) -> Option<usize> {
+        self.cumulative
+            .windows(2)
+            .rposition(|pair| pair[1] > pair[0])
+    }
+}
+
+impl From<Vec<Fecc>> for Polyline {
+    fn from(points: Vec<Fecc>) -> Self {
+        Self(points)
+    }
+}
+
+impl FromIterator<Fecc> for Polyline {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Fecc>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
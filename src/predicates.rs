@@ -0,0 +1,211 @@
+//! Robust geometric predicates.
+//!
+//! Naive float implementations of these predicates may return the wrong sign
+//! for nearly degenerate inputs (e.g. almost collinear points), which makes
+//! algorithms built on top of them produce inconsistent results. The
+//! functions here follow [Shewchuk's
+//! approach](https://www.cs.cmu.edu/~quake/robust.html): the result is first
+//! computed with plain floats and, only if it's too close to zero to be
+//! trusted, recomputed exactly using floating-point expansions. The sign of
+//! the result is always correct, as long as no intermediate value overflows
+//! or underflows.
+
+use crate::Fecc;
+
+/// Half of the machine epsilon.
+const EPSILON: f64 = f64::EPSILON / 2.0;
+
+const ORIENT_BOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+/// Returns a positive value if `a`, `b` and `c` are ordered
+/// counterclockwise, a negative value if they're ordered clockwise and zero
+/// if they're collinear. The magnitude is approximately twice the area of the
+/// triangle.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{predicates, Fecc};
+///
+/// let a = Fecc::new(0.1, 0.3);
+/// let b = Fecc::new(0.7, 2.1);
+///
+/// assert!(predicates::orient2d(a, b, Fecc::new(0.0, 1.0)) > 0.0);
+///
+/// // The rounded cross product isn't zero, but the points are exactly
+/// // collinear.
+/// let c = Fecc::new(0.3, 0.9);
+///
+/// assert_ne!((b - a).cross(c - a), 0.0);
+/// assert_eq!(predicates::orient2d(a, b, c), 0.0);
+/// ```
+pub fn orient2d(a: Fecc, b: Fecc, c: Fecc) -> f64 {
+    let left = (a.x - c.x) * (b.y - c.y);
+    let right = (a.y - c.y) * (b.x - c.x);
+    let det = left - right;
+
+    if det.abs() >= ORIENT_BOUND * (left.abs() + right.abs()) {
+        return det;
+    }
+
+    // Expand the determinant so that it only multiplies input coordinates,
+    // whose products are exact.
+    let terms = [
+        product(a.x, b.y),
+        product(-a.x, c.y),
+        product(-a.y, b.x),
+        product(a.y, c.x),
+        product(b.x, c.y),
+        product(-b.y, c.x),
+    ];
+
+    estimate(&terms.iter().fold(vec![], |sum, term| add(&sum, term)))
+}
+
+/// Returns a positive value if `d` lies inside the circle passing through
+/// `a`, `b` and `c`, a negative value if it lies outside and zero if it lies
+/// on the circle. The points `a`, `b` and `c` must be ordered
+/// counterclockwise, otherwise the sign is reversed.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{predicates, Fecc};
+///
+/// let a = Fecc::new(1.0, 0.0);
+/// let b = Fecc::new(0.0, 1.0);
+/// let c = Fecc::new(-1.0, 0.0);
+///
+/// assert!(predicates::incircle(a, b, c, Fecc::new(0.0, 0.5)) > 0.0);
+/// assert!(predicates::incircle(a, b, c, Fecc::new(2.0, 0.0)) < 0.0);
+/// assert_eq!(predicates::incircle(a, b, c, Fecc::new(0.0, -1.0)), 0.0);
+/// ```
+pub fn incircle(a: Fecc, b: Fecc, c: Fecc, d: Fecc) -> f64 {
+    let (ad, bd, cd) = (a - d, b - d, c - d);
+    let ab = ad.x * bd.y - bd.x * ad.y;
+    let bc = bd.x * cd.y - cd.x * bd.y;
+    let ca = cd.x * ad.y - ad.x * cd.y;
+    let det =
+        ad.mag_squared() * bc + bd.mag_squared() * ca + cd.mag_squared() * ab;
+    let permanent = ((bd.x * cd.y).abs() + (cd.x * bd.y).abs())
+        * ad.mag_squared()
+        + ((cd.x * ad.y).abs() + (ad.x * cd.y).abs()) * bd.mag_squared()
+        + ((ad.x * bd.y).abs() + (bd.x * ad.y).abs()) * cd.mag_squared();
+
+    if det.abs() >= INCIRCLE_BOUND * permanent {
+        return det;
+    }
+
+    // The differences are computed exactly as two-component expansions.
+    let diff = |p: f64, q: f64| normalized(sum(p, -q));
+    let (adx, ady) = (diff(a.x, d.x), diff(a.y, d.y));
+    let (bdx, bdy) = (diff(b.x, d.x), diff(b.y, d.y));
+    let (cdx, cdy) = (diff(c.x, d.x), diff(c.y, d.y));
+
+    let cross = |px: &[f64], py: &[f64], qx: &[f64], qy: &[f64]| {
+        add(&multiply(px, qy), &negate(&multiply(qx, py)))
+    };
+    let lift = |x: &[f64], y: &[f64]| add(&multiply(x, x), &multiply(y, y));
+
+    let ab = cross(&adx, &ady, &bdx, &bdy);
+    let bc = cross(&bdx, &bdy, &cdx, &cdy);
+    let ca = cross(&cdx, &cdy, &adx, &ady);
+
+    let det = add(
+        &add(
+            &multiply(&lift(&adx, &ady), &bc),
+            &multiply(&lift(&bdx, &bdy), &ca),
+        ),
+        &multiply(&lift(&cdx, &cdy), &ab),
+    );
+
+    estimate(&det)
+}
+
+// Floating-point expansions, i.e. sums of non-overlapping floats ordered by
+// increasing magnitude, represent intermediate results exactly.
+
+/// Returns the rounded sum and its rounding error.
+fn sum(a: f64, b: f64) -> [f64; 2] {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    let error = (a - a_virtual) + (b - b_virtual);
+
+    [error, x]
+}
+
+/// Returns the rounded product and its rounding error.
+fn product(a: f64, b: f64) -> [f64; 2] {
+    let x = a * b;
+
+    [a.mul_add(b, -x), x]
+}
+
+fn normalized(expansion: [f64; 2]) -> Vec<f64> {
+    expansion.iter().copied().filter(|&c| c != 0.0).collect()
+}
+
+fn negate(e: &[f64]) -> Vec<f64> {
+    e.iter().map(|&c| -c).collect()
+}
+
+/// Adds a float to an expansion.
+fn grow(e: &[f64], b: f64) -> Vec<f64> {
+    let mut result = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+
+    for &c in e {
+        let [h, x] = sum(q, c);
+        q = x;
+
+        if h != 0.0 {
+            result.push(h);
+        }
+    }
+
+    if q != 0.0 {
+        result.push(q);
+    }
+
+    result
+}
+
+fn add(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(e.to_vec(), |sum, &c| grow(&sum, c))
+}
+
+/// Multiplies an expansion by a float.
+fn scale(e: &[f64], b: f64) -> Vec<f64> {
+    let mut result = Vec::with_capacity(e.len() * 2);
+    let mut q = 0.0;
+
+    for &c in e {
+        let [low, high] = product(c, b);
+
+        for part in [low, high] {
+            let [h, x] = sum(q, part);
+            q = x;
+
+            if h != 0.0 {
+                result.push(h);
+            }
+        }
+    }
+
+    if q != 0.0 {
+        result.push(q);
+    }
+
+    result
+}
+
+fn multiply(e: &[f64], f: &[f64]) -> Vec<f64> {
+    f.iter().fold(vec![], |sum, &c| add(&sum, &scale(e, c)))
+}
+
+/// Returns an approximation of the expansion with the correct sign.
+fn estimate(e: &[f64]) -> f64 {
+    e.iter().sum()
+}
@@ -0,0 +1,164 @@
+//! Rasterization of lines and circles onto an integer grid.
+//!
+//! The iterators yield [`Vecc<i64>`](crate::vecc::Vecc) — the same type
+//! [`Fecc::floor`](crate::fecc::Fecc::floor) returns — so they can be used
+//! to draw onto any pixel buffer.
+
+use crate::Vecc;
+
+/// Returns an iterator over the pixels of the line from `start` to `end`
+/// (both inclusive), computed with
+/// [Bresenham's algorithm](https://en.wikipedia.org/wiki/Bresenham%27s_line_algorithm).
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{raster, Vecc};
+///
+/// let pixels: Vec<_> = raster::line(Vecc::new(0, 0), Vecc::new(3, 1)).collect();
+///
+/// assert_eq!(
+///     pixels,
+///     vec![
+///         Vecc::new(0, 0),
+///         Vecc::new(1, 0),
+///         Vecc::new(2, 1),
+///         Vecc::new(3, 1),
+///     ]
+/// );
+/// ```
+pub fn line(start: Vecc<i64>, end: Vecc<i64>) -> Bresenham {
+    let dx = (end.x - start.x).abs();
+    let dy = -(end.y - start.y).abs();
+
+    Bresenham {
+        current: start,
+        end,
+        step: Vecc::new((end.x - start.x).signum(), (end.y - start.y).signum()),
+        delta: Vecc::new(dx, dy),
+        error: dx + dy,
+        done: false,
+    }
+}
+
+/// Returns an iterator over the pixels of the outline of a circle, computed
+/// with the [midpoint circle algorithm](https://en.wikipedia.org/wiki/Midpoint_circle_algorithm).
+///
+/// Pixels lying on the boundaries between octants may be yielded more than
+/// once.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{raster, Vecc};
+///
+/// let center = Vecc::new(10, 10);
+///
+/// for pixel in raster::circle(center, 5) {
+///     let offset = pixel - center;
+///     let dist_squared = offset.dot(offset);
+///
+///     assert!((16..=36).contains(&dist_squared));
+/// }
+/// ```
+pub fn circle(center: Vecc<i64>, radius: i64) -> MidpointCircle {
+    MidpointCircle {
+        center,
+        x: radius.abs(),
+        y: 0,
+        error: 1 - radius.abs(),
+        octant: 0,
+    }
+}
+
+/// Iterator over the pixels of a line. See [`line`](crate::raster::line).
+#[derive(Clone, Debug)]
+pub struct Bresenham {
+    current: Vecc<i64>,
+    end: Vecc<i64>,
+    step: Vecc<i64>,
+    delta: Vecc<i64>,
+    error: i64,
+    done: bool,
+}
+
+impl Iterator for Bresenham {
+    type Item = Vecc<i64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let pixel = self.current;
+
+        if self.current == self.end {
+            self.done = true;
+
+            return Some(pixel);
+        }
+
+        let doubled_error = 2 * self.error;
+
+        if doubled_error >= self.delta.y {
+            self.error += self.delta.y;
+            self.current.x += self.step.x;
+        }
+
+        if doubled_error <= self.delta.x {
+            self.error += self.delta.x;
+            self.current.y += self.step.y;
+        }
+
+        Some(pixel)
+    }
+}
+
+/// Iterator over the pixels of a circle's outline. See
+/// [`circle`](crate::raster::circle).
+#[derive(Clone, Debug)]
+pub struct MidpointCircle {
+    center: Vecc<i64>,
+    x: i64,
+    y: i64,
+    error: i64,
+    octant: u8,
+}
+
+impl Iterator for MidpointCircle {
+    type Item = Vecc<i64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.x < self.y {
+            return None;
+        }
+
+        let (x, y) = (self.x, self.y);
+        let offset = match self.octant {
+            0 => Vecc::new(x, y),
+            1 => Vecc::new(y, x),
+            2 => Vecc::new(-y, x),
+            3 => Vecc::new(-x, y),
+            4 => Vecc::new(-x, -y),
+            5 => Vecc::new(-y, -x),
+            6 => Vecc::new(y, -x),
+            _ => Vecc::new(x, -y),
+        };
+
+        if self.octant == 7 {
+            self.octant = 0;
+            self.y += 1;
+
+            if self.error < 0 {
+                self.error += 2 * self.y + 1;
+            } else {
+                self.x -= 1;
+                self.error += 2 * (self.y - self.x) + 1;
+            }
+        } else {
+            self.octant += 1;
+        }
+
+        Some(self.center + offset)
+    }
+}
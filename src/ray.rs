@@ -0,0 +1,136 @@
+//! Ray casting.
+
+use crate::{Circle, Fecc, Segment};
+
+/// Half-line starting at `origin` and extending in `direction`.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{Circle, Fecc, Ray};
+///
+/// let ray = Ray::new(Fecc::zero(), Fecc::new(1.0, 0.0));
+/// let circle = Circle::new(Fecc::new(10.0, 0.0), 2.0);
+/// let hit = ray.cast_circle(&circle).unwrap();
+///
+/// assert_approx_eq!(f64, hit.distance, 8.0);
+/// assert_approx_eq!(f64, hit.normal.x, -1.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Ray {
+    /// The starting point of the ray.
+    pub origin: Fecc,
+
+    /// The unit vector pointing in the direction of the ray.
+    pub direction: Fecc,
+}
+
+/// The point where a ray hits a shape.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RayHit {
+    /// The distance from the ray's origin to the hit point.
+    pub distance: f64,
+
+    /// The hit point.
+    pub point: Fecc,
+
+    /// The unit normal of the shape's surface at the hit point, facing the
+    /// ray's origin.
+    pub normal: Fecc,
+}
+
+impl Ray {
+    /// Constructs a new ray. `direction` is normalized.
+    pub fn new(origin: Fecc, direction: Fecc) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Returns the point at the specified distance from the origin.
+    pub fn at(&self, distance: f64) -> Fecc {
+        self.origin + self.direction * distance
+    }
+
+    /// Returns the first point where the ray hits the circle. If the origin
+    /// is inside the circle, the hit is at the origin.
+    pub fn cast_circle(&self, circle: &Circle) -> Option<RayHit> {
+        let offset = self.origin - circle.center;
+        let b = offset.dot(self.direction);
+        let c = offset.mag_squared() - circle.radius * circle.radius;
+
+        if c > 0.0 && b > 0.0 {
+            return None;
+        }
+
+        let discriminant = b * b - c;
+
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let distance = (-b - discriminant.sqrt()).max(0.0);
+        let point = self.at(distance);
+        let normal = if c > 0.0 {
+            (point - circle.center).normalize()
+        } else {
+            -self.direction
+        };
+
+        Some(RayHit {
+            distance,
+            point,
+            normal,
+        })
+    }
+
+    /// Returns the point where the ray hits the segment. Segments parallel
+    /// to the ray are never hit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{Fecc, Ray, Segment};
+    ///
+    /// let ray = Ray::new(Fecc::zero(), Fecc::new(1.0, 1.0));
+    /// let wall = Segment(Fecc::new(5.0, -10.0), Fecc::new(5.0, 10.0));
+    /// let hit = ray.cast_segment(&wall).unwrap();
+    ///
+    /// assert_approx_eq!(f64, hit.point.x, 5.0);
+    /// assert_approx_eq!(f64, hit.point.y, 5.0);
+    /// assert_approx_eq!(f64, hit.normal.x, -1.0);
+    /// ```
+    pub fn cast_segment(&self, segment: &Segment) -> Option<RayHit> {
+        let Segment(a, b) = *segment;
+        let along = b - a;
+        let denominator = self.direction.cross(along);
+
+        if denominator == 0.0 {
+            return None;
+        }
+
+        let offset = a - self.origin;
+        let distance = offset.cross(along) / denominator;
+        let s = offset.cross(self.direction) / denominator;
+
+        if distance < 0.0 || !(0.0..=1.0).contains(&s) {
+            return None;
+        }
+
+        let normal = Fecc::new(-along.y, along.x).normalize();
+        let normal = if normal.dot(self.direction) > 0.0 {
+            -normal
+        } else {
+            normal
+        };
+
+        Some(RayHit {
+            distance,
+            point: self.at(distance),
+            normal,
+        })
+    }
+}
@@ -0,0 +1,168 @@
+//! Precomputed rotations.
+
+use std::ops::Mul;
+
+use crate::{matrix::Mat2, Angle, Float, Vecc};
+
+/// Counterclockwise rotation stored as a unit complex number — the cosine and
+/// the sine of the angle.
+///
+/// [`Fecc::rotate`](crate::fecc::Fecc::rotate) computes the sine and the
+/// cosine on every call. A rotor computes them once, so rotating many
+/// vectors by the same angle only takes a few multiplications each.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{rotor::Rotor2, Angular, Fecc};
+///
+/// let rotor = Rotor2::from_angle(90.deg());
+/// let mut particles = vec![Fecc::new(1.0, 0.0), Fecc::new(0.0, 2.0)];
+///
+/// for particle in &mut particles {
+///     *particle = rotor * *particle;
+/// }
+///
+/// assert_approx_eq!(f64, particles[0].y, 1.0);
+/// assert_approx_eq!(f64, particles[1].x, -2.0);
+///
+/// // Composition adds the angles.
+/// let half_turn = rotor * rotor;
+/// assert_approx_eq!(f64, half_turn.angle(), 180.0_f64.to_radians());
+///
+/// let back = rotor.inverse() * particles[0];
+/// assert_approx_eq!(f64, back.x, 1.0);
+/// assert_approx_eq!(f64, back.y, 0.0, epsilon = 1e-9);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Rotor2<T> {
+    cos: T,
+    sin: T,
+}
+
+impl<T> Rotor2<T>
+where
+    T: Float,
+{
+    /// Returns the rotation by zero.
+    pub fn identity() -> Self {
+        Self {
+            cos: T::ONE,
+            sin: T::ZERO,
+        }
+    }
+
+    /// Constructs a new rotor, computing the sine and the cosine of the
+    /// angle.
+    pub fn from_angle<A>(angle: A) -> Self
+    where
+        A: Into<Angle>,
+    {
+        let angle = angle.into();
+
+        Self {
+            cos: T::from_f64(angle.cos()),
+            sin: T::from_f64(angle.sin()),
+        }
+    }
+
+    /// Returns the rotation turning the direction of `from` into the
+    /// direction of `to`, without computing any angles. Returns the identity
+    /// if either of the vectors is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{rotor::Rotor2, Fecc};
+    ///
+    /// let rotor = Rotor2::between(Fecc::new(2.0, 0.0), Fecc::new(0.0, -3.0));
+    /// assert_approx_eq!(f64, rotor.angle(), -90.0_f64.to_radians());
+    /// ```
+    pub fn between(from: Vecc<T>, to: Vecc<T>) -> Self {
+        Self {
+            cos: from.dot(to),
+            sin: from.cross(to),
+        }
+        .normalize()
+    }
+
+    /// Returns the cosine of the angle.
+    pub fn cos(&self) -> T {
+        self.cos
+    }
+
+    /// Returns the sine of the angle.
+    pub fn sin(&self) -> T {
+        self.sin
+    }
+
+    /// Returns the angle in the range `[-π, π]`.
+    pub fn angle(&self) -> T {
+        self.sin.atan2(self.cos)
+    }
+
+    /// Returns the rotation in the opposite direction.
+    pub fn inverse(self) -> Self {
+        Self {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    /// Rescales the rotor to unit length, removing the error accumulated by
+    /// many compositions. Returns the identity if the rotor is zero.
+    pub fn normalize(self) -> Self {
+        let mag = self.cos.hypot(self.sin);
+
+        if mag == T::ZERO {
+            Self::identity()
+        } else {
+            Self {
+                cos: self.cos / mag,
+                sin: self.sin / mag,
+            }
+        }
+    }
+}
+
+impl<T> From<Rotor2<T>> for Mat2<T>
+where
+    T: Float,
+{
+    fn from(Rotor2 { cos, sin }: Rotor2<T>) -> Self {
+        Mat2::from_cols(Vecc::new(cos, sin), Vecc::new(-sin, cos))
+    }
+}
+
+/// Composition. The angles add up.
+impl<T> Mul<Rotor2<T>> for Rotor2<T>
+where
+    T: Float,
+{
+    type Output = Rotor2<T>;
+
+    fn mul(self, rhs: Rotor2<T>) -> Rotor2<T> {
+        Rotor2 {
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.sin * rhs.cos + self.cos * rhs.sin,
+        }
+    }
+}
+
+/// Rotates the vector.
+impl<T> Mul<Vecc<T>> for Rotor2<T>
+where
+    T: Float,
+{
+    type Output = Vecc<T>;
+
+    fn mul(self, rhs: Vecc<T>) -> Vecc<T> {
+        Vecc {
+            x: self.cos * rhs.x - self.sin * rhs.y,
+            y: self.sin * rhs.x + self.cos * rhs.y,
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Line segment.
+
+use crate::Fecc;
+
+/// Line segment between two points.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{Fecc, Segment};
+///
+/// let segment = Segment(Fecc::new(0.0, 0.0), Fecc::new(10.0, 0.0));
+/// let closest = segment.closest_point(Fecc::new(15.0, 5.0));
+///
+/// assert_approx_eq!(f64, closest.x, 10.0);
+/// assert_approx_eq!(f64, closest.y, 0.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Segment(pub Fecc, pub Fecc);
+
+impl Segment {
+    /// Constructs a new segment.
+    pub fn new(start: Fecc, end: Fecc) -> Self {
+        Self(start, end)
+    }
+
+    /// Returns the length of the segment.
+    pub fn length(&self) -> f64 {
+        self.0.dist(self.1)
+    }
+
+    /// Returns the point halfway between the ends.
+    pub fn midpoint(&self) -> Fecc {
+        self.0.lerp(self.1, 0.5)
+    }
+
+    /// Returns the point on the segment closest to `point`.
+    pub fn closest_point(&self, point: Fecc) -> Fecc {
+        let Segment(a, b) = *self;
+        let ab = b - a;
+
+        if ab.is_zero() {
+            a
+        } else {
+            let t = ((point - a).dot(ab) / ab.mag_squared()).clamp(0.0, 1.0);
+
+            a + ab * t
+        }
+    }
+
+    /// Returns the distance from the point to the segment.
+    pub fn distance_to_point(&self, point: Fecc) -> f64 {
+        self.closest_point(point).dist(point)
+    }
+
+    /// Returns the point where the segments intersect or `None` if they
+    /// don't. Segments touching at an end intersect.
+    ///
+    /// Parallel segments only intersect if they're collinear and overlap.
+    /// The overlap is a segment, so its point closest to the start of `self`
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{Fecc, Segment};
+    ///
+    /// let wall = Segment(Fecc::new(2.0, -5.0), Fecc::new(2.0, 5.0));
+    /// let sight = Segment(Fecc::new(0.0, 0.0), Fecc::new(4.0, 2.0));
+    ///
+    /// let hit = sight.intersect(&wall).unwrap();
+    /// assert_approx_eq!(f64, hit.x, 2.0);
+    /// assert_approx_eq!(f64, hit.y, 1.0);
+    ///
+    /// // Parallel.
+    /// let other = Segment(Fecc::new(3.0, -5.0), Fecc::new(3.0, 5.0));
+    /// assert!(wall.intersect(&other).is_none());
+    ///
+    /// // Collinear and overlapping.
+    /// let a = Segment(Fecc::new(0.0, 0.0), Fecc::new(4.0, 0.0));
+    /// let b = Segment(Fecc::new(6.0, 0.0), Fecc::new(2.0, 0.0));
+    /// assert_eq!(a.intersect(&b), Some(Fecc::new(2.0, 0.0)));
+    /// assert_eq!(b.intersect(&a), Some(Fecc::new(4.0, 0.0)));
+    ///
+    /// // Collinear and disjoint.
+    /// let c = Segment(Fecc::new(5.0, 0.0), Fecc::new(9.0, 0.0));
+    /// assert!(a.intersect(&c).is_none());
+    /// ```
+    pub fn intersect(&self, other: &Segment) -> Option<Fecc> {
+        let Segment(a, b) = *self;
+        let Segment(c, d) = *other;
+        let (r, s) = (b - a, d - c);
+        let denominator = r.cross(s);
+        let offset = c - a;
+
+        if denominator != 0.0 {
+            let t = offset.cross(s) / denominator;
+            let u = offset.cross(r) / denominator;
+
+            return if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+                Some(a + r * t)
+            } else {
+                None
+            };
+        }
+
+        if r.is_zero() {
+            return if other.closest_point(a) == a {
+                Some(a)
+            } else {
+                None
+            };
+        }
+
+        if offset.cross(r) != 0.0 {
+            return None;
+        }
+
+        // Collinear. Find the overlap along `self`.
+        let t0 = offset.dot(r) / r.mag_squared();
+        let t1 = t0 + s.dot(r) / r.mag_squared();
+        let start = t0.min(t1).max(0.0);
+        let end = t0.max(t1).min(1.0);
+
+        if start <= end {
+            Some(a + r * start)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Alternative [serde](https://serde.rs/) representations of
+//! [`Vecc`](crate::vecc::Vecc).
+//!
+//! By default vectors are serialized as structs (`{"x": 1.0, "y": 2.0}` in
+//! JSON), which also works with `#[serde(flatten)]`. Deserialization accepts
+//! both the struct and the `[x, y]` form in self-describing formats.
+//!
+//! The modules in this one are meant to be used with `#[serde(with = ...)]`
+//! to serialize vectors as `[x, y]` instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use veccentric::Fecc;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Level {
+//!     #[serde(with = "veccentric::serde::tuple")]
+//!     spawn: Fecc,
+//!
+//!     #[serde(with = "veccentric::serde::tuple_vec")]
+//!     enemies: Vec<Fecc>,
+//!
+//!     exit: Fecc,
+//! }
+//!
+//! let level = Level {
+//!     spawn: Fecc::new(1.0, 2.0),
+//!     enemies: vec![Fecc::new(3.0, 4.0)],
+//!     exit: Fecc::new(5.0, 6.0),
+//! };
+//! let json = serde_json::to_string(&level).unwrap();
+//!
+//! assert_eq!(
+//!     json,
+//!     r#"{"spawn":[1.0,2.0],"enemies":[[3.0,4.0]],"exit":{"x":5.0,"y":6.0}}"#
+//! );
+//!
+//! let level: Level = serde_json::from_str(&json).unwrap();
+//! assert_eq!(level.spawn, Fecc::new(1.0, 2.0));
+//! ```
+
+/// Serializes a vector as an `[x, y]` tuple.
+pub mod tuple {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Vecc;
+
+    /// Serializes the vector as an `[x, y]` tuple.
+    pub fn serialize<T, S>(
+        vecc: &Vecc<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        (&vecc.x, &vecc.y).serialize(serializer)
+    }
+
+    /// Deserializes the vector from an `[x, y]` tuple.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vecc<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let (x, y) = <(T, T)>::deserialize(deserializer)?;
+
+        Ok(Vecc::new(x, y))
+    }
+}
+
+/// Serializes a `Vec` of vectors as a list of `[x, y]` tuples.
+pub mod tuple_vec {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Vecc;
+
+    /// Serializes the vectors as a list of `[x, y]` tuples.
+    pub fn serialize<T, S>(
+        veccs: &[Vecc<T>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        serializer.collect_seq(veccs.iter().map(|vecc| (&vecc.x, &vecc.y)))
+    }
+
+    /// Deserializes the vectors from a list of `[x, y]` tuples.
+    pub fn deserialize<'de, T, D>(
+        deserializer: D,
+    ) -> Result<Vec<Vecc<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let pairs = Vec::<(T, T)>::deserialize(deserializer)?;
+
+        Ok(pairs.into_iter().map(|(x, y)| Vecc::new(x, y)).collect())
+    }
+}
@@ -0,0 +1,860 @@
+//! Traits shared by the shape types.
+//!
+//! # Examples
+//!
+//! Sorting heterogeneous shapes by area.
+//!
+//! ```
+//! use veccentric::{shape::Measure, Aabb, Circle, Fecc, Triangle};
+//!
+//! let mut shapes: Vec<Box<dyn Measure>> = vec![
+//!     Box::new(Circle::new(Fecc::zero(), 1.0)),
+//!     Box::new(Aabb::new(Fecc::zero(), Fecc::new(1.0, 1.0))),
+//!     Box::new(Triangle(
+//!         Fecc::zero(),
+//!         Fecc::new(4.0, 0.0),
+//!         Fecc::new(0.0, 4.0),
+//!     )),
+//! ];
+//! shapes.sort_by(|a, b| a.area().total_cmp(&b.area()));
+//!
+//! assert_eq!(shapes[0].area(), 1.0);
+//! assert_eq!(shapes[2].area(), 8.0);
+//! ```
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::{
+    transform::Transform2, Aabb, Arc, Circle, Fecc, Polygon, Polyline, Segment,
+    Triangle,
+};
+
+/// Shapes with an area and a perimeter.
+pub trait Measure {
+    /// Returns the area of the shape.
+    fn area(&self) -> f64;
+
+    /// Returns the length of the shape's boundary.
+    fn perimeter(&self) -> f64;
+}
+
+impl Measure for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+}
+
+impl Measure for Aabb {
+    fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width() + self.height())
+    }
+}
+
+impl Measure for Triangle {
+    fn area(&self) -> f64 {
+        let Triangle(a, b, c) = *self;
+
+        (b - a).cross(c - a).abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        let Triangle(a, b, c) = *self;
+
+        a.dist(b) + b.dist(c) + c.dist(a)
+    }
+}
+
+impl Measure for Polygon {
+    /// Returns the area of a simple polygon.
+    fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.edges().map(|Segment(a, b)| a.dist(b)).sum()
+    }
+}
+
+/// Shapes which can be tested for overlap with `Rhs`. Shapes which only
+/// touch are considered intersecting.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{shape::Intersects, Aabb, Circle, Fecc, Segment};
+///
+/// let wall = Segment(Fecc::new(0.0, -5.0), Fecc::new(0.0, 5.0));
+/// let ball = Circle::new(Fecc::new(1.0, 0.0), 2.0);
+/// let platform = Aabb::new(Fecc::new(5.0, 5.0), Fecc::new(6.0, 6.0));
+///
+/// assert!(ball.intersects(&wall));
+/// assert!(!ball.intersects(&platform));
+/// assert!(!wall.intersects(&platform));
+/// ```
+///
+/// Polygons don't have to be convex, only simple.
+///
+/// ```
+/// use veccentric::{shape::Intersects, Aabb, Circle, Fecc, Polygon};
+///
+/// // A U-shaped polygon.
+/// let cup = Polygon(vec![
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(3.0, 0.0),
+///     Fecc::new(3.0, 3.0),
+///     Fecc::new(2.0, 3.0),
+///     Fecc::new(2.0, 1.0),
+///     Fecc::new(1.0, 1.0),
+///     Fecc::new(1.0, 3.0),
+///     Fecc::new(0.0, 3.0),
+/// ]);
+/// // Inside the cup's opening, touching neither of its sides.
+/// let bar = Polygon(vec![
+///     Fecc::new(1.2, 1.5),
+///     Fecc::new(1.8, 1.5),
+///     Fecc::new(1.8, 4.0),
+///     Fecc::new(1.2, 4.0),
+/// ]);
+///
+/// let ball = Circle::new(Fecc::new(1.5, 2.0), 0.4);
+/// // Reaching into the cup's bottom.
+/// let peg = Aabb::new(Fecc::new(1.5, 0.5), Fecc::new(1.8, 2.0));
+///
+/// assert!(!cup.intersects(&bar));
+/// assert!(!cup.intersects(&ball));
+/// assert!(cup.intersects(&peg));
+/// ```
+pub trait Intersects<Rhs = Self> {
+    /// Checks whether the shapes overlap.
+    fn intersects(&self, rhs: &Rhs) -> bool;
+}
+
+impl Intersects for Circle {
+    fn intersects(&self, rhs: &Circle) -> bool {
+        let radii = self.radius + rhs.radius;
+
+        self.center.dist_squared(rhs.center) <= radii * radii
+    }
+}
+
+impl Intersects<Aabb> for Circle {
+    fn intersects(&self, rhs: &Aabb) -> bool {
+        let closest = self.center.clamp(rhs.min, rhs.max);
+
+        closest.dist_squared(self.center) <= self.radius * self.radius
+    }
+}
+
+impl Intersects<Segment> for Circle {
+    fn intersects(&self, rhs: &Segment) -> bool {
+        let closest = rhs.closest_point(self.center);
+
+        closest.dist_squared(self.center) <= self.radius * self.radius
+    }
+}
+
+impl Intersects for Aabb {
+    fn intersects(&self, rhs: &Aabb) -> bool {
+        Aabb::intersects(self, rhs)
+    }
+}
+
+impl Intersects<Circle> for Aabb {
+    fn intersects(&self, rhs: &Circle) -> bool {
+        rhs.intersects(self)
+    }
+}
+
+impl Intersects<Segment> for Aabb {
+    fn intersects(&self, rhs: &Segment) -> bool {
+        rhs.intersects(self)
+    }
+}
+
+impl Intersects for Segment {
+    fn intersects(&self, rhs: &Segment) -> bool {
+        let Segment(a, b) = *self;
+        let Segment(c, d) = *rhs;
+        let d1 = (b - a).cross(c - a);
+        let d2 = (b - a).cross(d - a);
+        let d3 = (d - c).cross(a - c);
+        let d4 = (d - c).cross(b - c);
+
+        if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+            return true;
+        }
+
+        // Endpoints lying on the other segment.
+        let on = |p: Fecc, Segment(s, e): Segment, cross: f64| {
+            cross == 0.0 && p.clamp(s.min(e), s.max(e)) == p
+        };
+
+        on(c, *self, d1)
+            || on(d, *self, d2)
+            || on(a, *rhs, d3)
+            || on(b, *rhs, d4)
+    }
+}
+
+impl Intersects<Circle> for Segment {
+    fn intersects(&self, rhs: &Circle) -> bool {
+        rhs.intersects(self)
+    }
+}
+
+impl Intersects<Aabb> for Segment {
+    fn intersects(&self, rhs: &Aabb) -> bool {
+        // Clip the segment to the box's slabs (Liang–Barsky).
+        let Segment(start, end) = *self;
+        let along = end - start;
+        let (mut t_min, mut t_max) = (0.0_f64, 1.0_f64);
+        let slabs = [
+            (start.x, along.x, rhs.min.x, rhs.max.x),
+            (start.y, along.y, rhs.min.y, rhs.max.y),
+        ];
+
+        for &(origin, direction, min, max) in &slabs {
+            if direction == 0.0 {
+                if origin < min || origin > max {
+                    return false;
+                }
+            } else {
+                let t1 = (min - origin) / direction;
+                let t2 = (max - origin) / direction;
+
+                t_min = t_min.max(t1.min(t2));
+                t_max = t_max.min(t1.max(t2));
+
+                if t_min > t_max {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+// Two shapes overlap if their boundaries cross or if one lies inside the
+// other, so the polygon impls work for any simple polygon, convex or not.
+impl Intersects for Polygon {
+    fn intersects(&self, rhs: &Polygon) -> bool {
+        let contains_first = |a: &Polygon, b: &Polygon| {
+            b.0.first().is_some_and(|&vertex| a.contains(vertex))
+        };
+
+        contains_first(self, rhs)
+            || contains_first(rhs, self)
+            || self
+                .edges()
+                .any(|edge| rhs.edges().any(|other| edge.intersects(&other)))
+    }
+}
+
+impl Intersects<Circle> for Polygon {
+    fn intersects(&self, rhs: &Circle) -> bool {
+        self.contains(rhs.center)
+            || self.edges().any(|edge| rhs.intersects(&edge))
+    }
+}
+
+impl Intersects<Aabb> for Polygon {
+    fn intersects(&self, rhs: &Aabb) -> bool {
+        self.contains(rhs.min) || self.edges().any(|edge| edge.intersects(rhs))
+    }
+}
+
+impl Intersects<Segment> for Polygon {
+    fn intersects(&self, rhs: &Segment) -> bool {
+        self.contains(rhs.0) || self.edges().any(|edge| edge.intersects(rhs))
+    }
+}
+
+impl Intersects for Triangle {
+    fn intersects(&self, rhs: &Triangle) -> bool {
+        Polygon::from(*self).intersects(&Polygon::from(*rhs))
+    }
+}
+
+// The overlap is symmetric, so some impls delegate to the other direction.
+macro_rules! reverse_intersects {
+    ($lhs:ty, $rhs:ty) => {
+        impl Intersects<$rhs> for $lhs {
+            fn intersects(&self, rhs: &$rhs) -> bool {
+                rhs.intersects(self)
+            }
+        }
+    };
+}
+
+reverse_intersects!(Circle, Polygon);
+reverse_intersects!(Aabb, Polygon);
+reverse_intersects!(Segment, Polygon);
+
+// A triangle is tested as a polygon.
+macro_rules! triangle_intersects {
+    ($($shape:ty),*) => {
+        $(
+            impl Intersects<$shape> for Triangle {
+                fn intersects(&self, rhs: &$shape) -> bool {
+                    Polygon::from(*self).intersects(rhs)
+                }
+            }
+
+            reverse_intersects!($shape, Triangle);
+        )*
+    };
+}
+
+triangle_intersects!(Circle, Aabb, Segment, Polygon);
+
+/// Shapes which can tell whether a point lies inside them. Points on the
+/// boundary are considered inside.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{shape::Contains, Aabb, Circle, Fecc, Polygon};
+///
+/// let shapes: Vec<Box<dyn Contains>> = vec![
+///     Box::new(Circle::new(Fecc::new(0.0, 0.0), 1.0)),
+///     Box::new(Aabb::new(Fecc::new(5.0, 5.0), Fecc::new(6.0, 6.0))),
+///     Box::new(Polygon(vec![
+///         Fecc::new(10.0, 0.0),
+///         Fecc::new(12.0, 0.0),
+///         Fecc::new(11.0, 2.0),
+///     ])),
+/// ];
+/// let cursor = Fecc::new(5.5, 5.5);
+///
+/// let hit = shapes.iter().position(|shape| shape.contains(cursor));
+///
+/// assert_eq!(hit, Some(1));
+/// ```
+pub trait Contains {
+    /// Checks whether the point lies inside the shape.
+    fn contains(&self, point: Fecc) -> bool;
+}
+
+impl Contains for Circle {
+    fn contains(&self, point: Fecc) -> bool {
+        self.center.dist_squared(point) <= self.radius * self.radius
+    }
+}
+
+impl Contains for Aabb {
+    fn contains(&self, point: Fecc) -> bool {
+        self.clamp_point(point) == point
+    }
+}
+
+impl Contains for Triangle {
+    fn contains(&self, point: Fecc) -> bool {
+        let Triangle(a, b, c) = *self;
+        let d1 = (b - a).cross(point - a);
+        let d2 = (c - b).cross(point - b);
+        let d3 = (a - c).cross(point - c);
+        let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+        let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+
+        !(has_negative && has_positive)
+    }
+}
+
+/// Works for any simple polygon, convex or not.
+impl Contains for Polygon {
+    fn contains(&self, point: Fecc) -> bool {
+        let mut inside = false;
+
+        for Segment(a, b) in self.edges() {
+            if Segment(a, b).closest_point(point) == point {
+                return true;
+            }
+
+            // Count the edges crossed by a ray going right from the point.
+            if (a.y > point.y) != (b.y > point.y) {
+                let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+
+                if point.x < x {
+                    inside = !inside;
+                }
+            }
+        }
+
+        inside
+    }
+}
+
+/// Shapes with a bounding box, e.g. for indexing them in spatial
+/// structures.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{shape::Bounded, Fecc, Polyline};
+///
+/// let path = Polyline(vec![
+///     Fecc::new(1.0, 5.0),
+///     Fecc::new(-2.0, 0.0),
+///     Fecc::new(3.0, 1.0),
+/// ]);
+/// let aabb = path.aabb();
+///
+/// assert_eq!(aabb.min, Fecc::new(-2.0, 0.0));
+/// assert_eq!(aabb.max, Fecc::new(3.0, 5.0));
+/// ```
+pub trait Bounded {
+    /// Returns the smallest axis-aligned box containing the shape.
+    fn aabb(&self) -> Aabb;
+}
+
+impl Bounded for Aabb {
+    fn aabb(&self) -> Aabb {
+        *self
+    }
+}
+
+impl Bounded for Circle {
+    fn aabb(&self) -> Aabb {
+        Circle::aabb(self)
+    }
+}
+
+impl Bounded for Segment {
+    fn aabb(&self) -> Aabb {
+        Aabb::new(self.0, self.1)
+    }
+}
+
+impl Bounded for Triangle {
+    fn aabb(&self) -> Aabb {
+        bounding_box(&[self.0, self.1, self.2])
+    }
+}
+
+/// # Panics
+///
+/// Panics if the polygon has no vertices.
+impl Bounded for Polygon {
+    fn aabb(&self) -> Aabb {
+        bounding_box(&self.0)
+    }
+}
+
+/// # Panics
+///
+/// Panics if the polyline has no points.
+impl Bounded for Polyline {
+    fn aabb(&self) -> Aabb {
+        bounding_box(&self.0)
+    }
+}
+
+impl Bounded for Arc {
+    fn aabb(&self) -> Aabb {
+        let (start, end) = (*self.start, *self.end());
+        let (from, to) = (start.min(end), start.max(end));
+        let mut points = vec![self.point_at(0.0), self.point_at(1.0)];
+
+        // The extremes along the axes, if the arc passes through them.
+        let mut angle = (from / FRAC_PI_2).ceil() * FRAC_PI_2;
+
+        while angle <= to {
+            points.push(self.center + Fecc::from_angle(angle) * self.radius);
+            angle += FRAC_PI_2;
+        }
+
+        bounding_box(&points)
+    }
+}
+
+fn bounding_box(points: &[Fecc]) -> Aabb {
+    assert!(!points.is_empty(), "no points to bound");
+
+    let (min, max) = points[1..]
+        .iter()
+        .fold((points[0], points[0]), |(min, max), &p| {
+            (min.min(p), max.max(p))
+        });
+
+    Aabb { min, max }
+}
+
+/// Shapes (and points) whose distance to `Rhs` can be measured. The distance
+/// is zero if the shapes intersect.
+///
+/// Distances between convex shapes are computed with the
+/// [GJK algorithm](https://en.wikipedia.org/wiki/Gilbert%E2%80%93Johnson%E2%80%93Keerthi_distance_algorithm),
+/// so [`Polygon`](crate::polygon::Polygon)s must be convex, except when
+/// measuring the distance to a point or a circle. The distance to an empty
+/// polygon or polyline is infinite.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{shape::DistanceTo, Aabb, Circle, Fecc, Polygon};
+///
+/// let door = Aabb::new(Fecc::new(10.0, 0.0), Fecc::new(11.0, 3.0));
+/// let player = Circle::new(Fecc::new(5.0, 1.0), 1.0);
+///
+/// assert_approx_eq!(f64, player.distance(&door), 4.0);
+/// assert!(player.distance(&door) < 5.0);
+///
+/// let triangle = Polygon(vec![
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(2.0, 0.0),
+///     Fecc::new(0.0, 2.0),
+/// ]);
+/// let square = Polygon(vec![
+///     Fecc::new(2.0, 2.0),
+///     Fecc::new(3.0, 2.0),
+///     Fecc::new(3.0, 3.0),
+///     Fecc::new(2.0, 3.0),
+/// ]);
+///
+/// assert_approx_eq!(f64, triangle.distance(&square), 2.0_f64.sqrt());
+/// ```
+pub trait DistanceTo<Rhs> {
+    /// Returns the distance between the closest points of the shapes.
+    fn distance(&self, rhs: &Rhs) -> f64;
+}
+
+impl DistanceTo<Fecc> for Fecc {
+    fn distance(&self, rhs: &Fecc) -> f64 {
+        self.dist(*rhs)
+    }
+}
+
+impl DistanceTo<Circle> for Fecc {
+    fn distance(&self, rhs: &Circle) -> f64 {
+        (self.dist(rhs.center) - rhs.radius).max(0.0)
+    }
+}
+
+impl DistanceTo<Aabb> for Fecc {
+    fn distance(&self, rhs: &Aabb) -> f64 {
+        self.dist(self.clamp(rhs.min, rhs.max))
+    }
+}
+
+impl DistanceTo<Segment> for Fecc {
+    fn distance(&self, rhs: &Segment) -> f64 {
+        self.dist(rhs.closest_point(*self))
+    }
+}
+
+impl DistanceTo<Triangle> for Fecc {
+    fn distance(&self, rhs: &Triangle) -> f64 {
+        self.distance(&Polygon::from(*rhs))
+    }
+}
+
+impl DistanceTo<Polygon> for Fecc {
+    fn distance(&self, rhs: &Polygon) -> f64 {
+        if rhs.contains(*self) {
+            0.0
+        } else {
+            rhs.edges()
+                .map(|edge| self.distance(&edge))
+                .fold(f64::INFINITY, f64::min)
+        }
+    }
+}
+
+impl DistanceTo<Polyline> for Fecc {
+    fn distance(&self, rhs: &Polyline) -> f64 {
+        if rhs.0.is_empty() {
+            f64::INFINITY
+        } else {
+            self.dist(rhs.nearest_point(*self))
+        }
+    }
+}
+
+impl DistanceTo<Circle> for Circle {
+    fn distance(&self, rhs: &Circle) -> f64 {
+        (self.center.dist(rhs.center) - self.radius - rhs.radius).max(0.0)
+    }
+}
+
+// The distance is symmetric, so most impls delegate to the other direction.
+macro_rules! reverse_distance {
+    ($lhs:ty, $rhs:ty) => {
+        impl DistanceTo<$rhs> for $lhs {
+            fn distance(&self, rhs: &$rhs) -> f64 {
+                rhs.distance(self)
+            }
+        }
+    };
+}
+
+// A circle is a point inflated by its radius.
+macro_rules! circle_distance {
+    ($($shape:ty),*) => {
+        $(
+            impl DistanceTo<$shape> for Circle {
+                fn distance(&self, rhs: &$shape) -> f64 {
+                    (self.center.distance(rhs) - self.radius).max(0.0)
+                }
+            }
+
+            reverse_distance!($shape, Circle);
+            reverse_distance!($shape, Fecc);
+        )*
+    };
+}
+
+circle_distance!(Aabb, Segment, Triangle, Polygon, Polyline);
+reverse_distance!(Circle, Fecc);
+
+macro_rules! convex_distance {
+    ($lhs:ty, $rhs:ty) => {
+        impl DistanceTo<$rhs> for $lhs {
+            fn distance(&self, rhs: &$rhs) -> f64 {
+                gjk_distance(&self.vertices(), &rhs.vertices())
+            }
+        }
+    };
+}
+
+convex_distance!(Aabb, Aabb);
+convex_distance!(Aabb, Segment);
+convex_distance!(Aabb, Triangle);
+convex_distance!(Aabb, Polygon);
+convex_distance!(Segment, Aabb);
+convex_distance!(Segment, Segment);
+convex_distance!(Segment, Triangle);
+convex_distance!(Segment, Polygon);
+convex_distance!(Triangle, Aabb);
+convex_distance!(Triangle, Segment);
+convex_distance!(Triangle, Triangle);
+convex_distance!(Triangle, Polygon);
+convex_distance!(Polygon, Aabb);
+convex_distance!(Polygon, Segment);
+convex_distance!(Polygon, Triangle);
+convex_distance!(Polygon, Polygon);
+
+/// Convex shapes described by their vertices.
+trait Convex {
+    fn vertices(&self) -> Vec<Fecc>;
+}
+
+impl Convex for Aabb {
+    fn vertices(&self) -> Vec<Fecc> {
+        self.corners().to_vec()
+    }
+}
+
+impl Convex for Segment {
+    fn vertices(&self) -> Vec<Fecc> {
+        vec![self.0, self.1]
+    }
+}
+
+impl Convex for Triangle {
+    fn vertices(&self) -> Vec<Fecc> {
+        vec![self.0, self.1, self.2]
+    }
+}
+
+impl Convex for Polygon {
+    fn vertices(&self) -> Vec<Fecc> {
+        self.0.clone()
+    }
+}
+
+/// Returns the distance between the convex hulls of two point sets.
+fn gjk_distance(a: &[Fecc], b: &[Fecc]) -> f64 {
+    const MAX_ITERATIONS: usize = 64;
+    const EPSILON: f64 = 1e-12;
+
+    if a.is_empty() || b.is_empty() {
+        return f64::INFINITY;
+    }
+
+    let farthest = |points: &[Fecc], direction: Fecc| {
+        points
+            .iter()
+            .copied()
+            .max_by(|p, q| p.dot(direction).total_cmp(&q.dot(direction)))
+            .unwrap()
+    };
+    // Support point of the Minkowski difference `a - b`.
+    let support =
+        |direction: Fecc| farthest(a, direction) - farthest(b, -direction);
+
+    let mut simplex = vec![support(Fecc::new(1.0, 0.0))];
+    let mut closest = simplex[0];
+
+    for _ in 0..MAX_ITERATIONS {
+        if closest.mag_squared() <= EPSILON {
+            return 0.0;
+        }
+
+        let w = support(-closest);
+
+        // No point of the difference is significantly closer.
+        if closest.mag_squared() - closest.dot(w)
+            <= EPSILON * closest.mag_squared().max(1.0)
+        {
+            break;
+        }
+
+        simplex.push(w);
+
+        let (point, reduced) = closest_to_origin(&simplex);
+        closest = point;
+        simplex = reduced;
+
+        if simplex.len() == 3 {
+            return 0.0;
+        }
+    }
+
+    closest.mag()
+}
+
+/// Returns the point of the simplex closest to the origin and the smallest
+/// sub-simplex containing it. A triangle is only returned if it contains
+/// the origin.
+fn closest_to_origin(simplex: &[Fecc]) -> (Fecc, Vec<Fecc>) {
+    match *simplex {
+        [a] => (a, vec![a]),
+        [a, b] => {
+            let along = b - a;
+            let t = if along.is_zero() {
+                0.0
+            } else {
+                (-a.dot(along) / along.mag_squared()).clamp(0.0, 1.0)
+            };
+
+            if t <= 0.0 {
+                (a, vec![a])
+            } else if t >= 1.0 {
+                (b, vec![b])
+            } else {
+                (a + along * t, vec![a, b])
+            }
+        }
+        [a, b, c] => {
+            if Triangle(a, b, c).contains(Fecc::zero()) {
+                return (Fecc::zero(), vec![a, b, c]);
+            }
+
+            [[a, b], [b, c], [c, a]]
+                .iter()
+                .map(|edge| closest_to_origin(edge))
+                .min_by(|p, q| p.0.mag_squared().total_cmp(&q.0.mag_squared()))
+                .unwrap()
+        }
+        _ => unreachable!("the simplex has at most three points"),
+    }
+}
+
+/// Shapes (and points) which can be mapped by a
+/// [`Transform2`](crate::transform::Transform2).
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{
+///     shape::{Measure, Transformable},
+///     transform::Transform2,
+///     Aabb, Angular, Fecc,
+/// };
+///
+/// let aabb = Aabb::new(Fecc::new(-1.0, -1.0), Fecc::new(1.0, 1.0));
+/// let rotated = aabb.transform(&Transform2::rotation(45.0.deg()));
+///
+/// // Rotated boxes become polygons.
+/// assert_eq!(rotated.0.len(), 4);
+/// assert_approx_eq!(f64, rotated.area(), 4.0);
+/// assert_approx_eq!(f64, rotated.0[0].x, 0.0, epsilon = 1e-12);
+/// ```
+pub trait Transformable {
+    /// The type of the transformed shape.
+    type Output;
+
+    /// Returns the transformed shape.
+    fn transform(&self, transform: &Transform2) -> Self::Output;
+}
+
+impl Transformable for Fecc {
+    type Output = Fecc;
+
+    fn transform(&self, transform: &Transform2) -> Fecc {
+        transform.apply(*self)
+    }
+}
+
+/// Circles can't be sheared or scaled non-uniformly. The radius is scaled by
+/// the square root of the absolute value of the transformation's
+/// determinant, which is exact for rotations, translations and uniform
+/// scaling.
+impl Transformable for Circle {
+    type Output = Circle;
+
+    fn transform(&self, transform: &Transform2) -> Circle {
+        Circle::new(
+            transform.apply(self.center),
+            self.radius * transform.determinant().abs().sqrt(),
+        )
+    }
+}
+
+/// Boxes become polygons, since a rotated box is no longer axis-aligned.
+/// The vertices start at `min` and go counterclockwise (before the
+/// transformation).
+impl Transformable for Aabb {
+    type Output = Polygon;
+
+    fn transform(&self, transform: &Transform2) -> Polygon {
+        Polygon(self.vertices()).transform(transform)
+    }
+}
+
+impl Transformable for Segment {
+    type Output = Segment;
+
+    fn transform(&self, transform: &Transform2) -> Segment {
+        Segment(transform.apply(self.0), transform.apply(self.1))
+    }
+}
+
+impl Transformable for Triangle {
+    type Output = Triangle;
+
+    fn transform(&self, transform: &Transform2) -> Triangle {
+        Triangle(
+            transform.apply(self.0),
+            transform.apply(self.1),
+            transform.apply(self.2),
+        )
+    }
+}
+
+impl Transformable for Polygon {
+    type Output = Polygon;
+
+    fn transform(&self, transform: &Transform2) -> Polygon {
+        self.0.iter().map(|&v| transform.apply(v)).collect()
+    }
+}
+
+impl Transformable for Polyline {
+    type Output = Polyline;
+
+    fn transform(&self, transform: &Transform2) -> Polyline {
+        self.0.iter().map(|&p| transform.apply(p)).collect()
+    }
+}
@@ -0,0 +1,137 @@
+//! Width and height.
+
+use std::ops::{Div, Mul};
+
+use crate::{Float, Vecc};
+
+/// Width and height of a window, an image or a shape.
+///
+/// It's convertible to and from [`Vecc`](crate::vecc::Vecc), but keeps
+/// sizes apart from positions in signatures and structs.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{size::Size, Vecc};
+///
+/// let window = Size::new(800, 600);
+/// let sprite = Size::new(32, 48);
+///
+/// assert_eq!(window.area(), 480_000);
+/// assert!(sprite.fits_within(window));
+/// assert_eq!(sprite * 2, Size::new(64, 96));
+/// assert_eq!(sprite.scale(Vecc::new(2, 1)), Size::new(64, 48));
+///
+/// let center = Vecc::from(window) / 2;
+/// assert_eq!(center, Vecc::new(400, 300));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Default, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Size<T> {
+    /// The width.
+    pub w: T,
+
+    /// The height.
+    pub h: T,
+}
+
+impl<T> Size<T> {
+    /// Constructs a new size.
+    pub fn new(w: T, h: T) -> Self {
+        Self { w, h }
+    }
+
+    /// Returns the area, `w * h`.
+    pub fn area(self) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.w * self.h
+    }
+
+    /// Returns `true` if neither the width nor the height is greater than
+    /// the other size's.
+    pub fn fits_within(self, other: Size<T>) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.w <= other.w && self.h <= other.h
+    }
+
+    /// Multiplies the width by `factors.x` and the height by `factors.y`.
+    pub fn scale(self, factors: Vecc<T>) -> Size<T>
+    where
+        T: Mul<Output = T>,
+    {
+        Size {
+            w: self.w * factors.x,
+            h: self.h * factors.y,
+        }
+    }
+}
+
+impl<T> Size<T>
+where
+    T: Float,
+{
+    /// Returns the ratio of the width to the height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::size::Size;
+    ///
+    /// let full_hd = Size::new(1920.0, 1080.0);
+    /// assert_approx_eq!(f64, full_hd.aspect_ratio(), 16.0 / 9.0);
+    /// ```
+    pub fn aspect_ratio(self) -> T {
+        self.w / self.h
+    }
+}
+
+impl<T> From<Vecc<T>> for Size<T> {
+    fn from(Vecc { x, y }: Vecc<T>) -> Self {
+        Self { w: x, h: y }
+    }
+}
+
+impl<T> From<Size<T>> for Vecc<T> {
+    fn from(Size { w, h }: Size<T>) -> Self {
+        Vecc { x: w, y: h }
+    }
+}
+
+impl<T> From<(T, T)> for Size<T> {
+    fn from((w, h): (T, T)) -> Self {
+        Self { w, h }
+    }
+}
+
+impl<T> Mul<T> for Size<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Size<T>;
+
+    fn mul(self, rhs: T) -> Size<T> {
+        Size {
+            w: self.w * rhs,
+            h: self.h * rhs,
+        }
+    }
+}
+
+impl<T> Div<T> for Size<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Size<T>;
+
+    fn div(self, rhs: T) -> Size<T> {
+        Size {
+            w: self.w / rhs,
+            h: self.h / rhs,
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Hierarchies of bones for articulated figures.
+//!
+//! Each bone has a transformation relative to its parent (or to the world
+//! for root bones). A bone starts at the origin of its own coordinate space
+//! (the head) and points along its X axis, so its end (the tail) is at
+//! `(length, 0.0)`.
+
+use crate::{Angle, Fecc, Transform2};
+
+/// Bone of a [`Skeleton2`](crate::skeleton::Skeleton2).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Bone {
+    /// The transformation relative to the parent's space.
+    pub local: Transform2,
+
+    /// The length of the bone.
+    pub length: f64,
+
+    parent: Option<usize>,
+}
+
+impl Bone {
+    /// Returns the index of the parent bone, `None` for root bones. The
+    /// parent can only be set when the bone is added, so that it always
+    /// precedes the bone.
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+}
+
+/// Hierarchy of bones. Parents always precede their children, so the world
+/// transformations can be computed in a single pass.
+///
+/// # Examples
+///
+/// An arm made of two bones.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{skeleton::Skeleton2, Angular, Fecc, Transform2};
+///
+/// let mut skeleton = Skeleton2::new();
+/// let shoulder = Transform2::translation(Fecc::new(0.0, 5.0));
+/// let upper = skeleton.add_bone(None, shoulder, 2.0);
+/// let elbow = Transform2::translation(Fecc::new(2.0, 0.0))
+///     * Transform2::rotation(90.0.deg());
+/// let lower = skeleton.add_bone(Some(upper), elbow, 1.0);
+///
+/// assert_eq!(skeleton.bone(lower).parent(), Some(upper));
+///
+/// let hand = skeleton.tail(lower);
+/// assert_approx_eq!(f64, hand.x, 2.0);
+/// assert_approx_eq!(f64, hand.y, 6.0);
+///
+/// // Rotating the upper bone moves the lower one as well.
+/// let lowered = shoulder * Transform2::rotation(-90.0.deg());
+/// skeleton.bone_mut(upper).local = lowered;
+///
+/// let hand = skeleton.tail(lower);
+/// assert_approx_eq!(f64, hand.x, 1.0);
+/// assert_approx_eq!(f64, hand.y, 3.0);
+/// ```
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct Skeleton2 {
+    bones: Vec<Bone>,
+}
+
+impl Skeleton2 {
+    /// Constructs a new skeleton without any bones.
+    pub fn new() -> Self {
+        Self { bones: Vec::new() }
+    }
+
+    /// Adds a bone and returns its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent doesn't exist.
+    pub fn add_bone(
+        &mut self,
+        parent: Option<usize>,
+        local: Transform2,
+        length: f64,
+    ) -> usize {
+        if let Some(parent) = parent {
+            assert!(parent < self.bones.len(), "parent bone doesn't exist");
+        }
+
+        self.bones.push(Bone {
+            local,
+            length,
+            parent,
+        });
+
+        self.bones.len() - 1
+    }
+
+    /// Returns the bones in the order in which they were added.
+    pub fn bones(&self) -> &[Bone] {
+        &self.bones
+    }
+
+    /// Returns the bone with the specified index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bone doesn't exist.
+    pub fn bone(&self, bone: usize) -> &Bone {
+        &self.bones[bone]
+    }
+
+    /// Returns the bone with the specified index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bone doesn't exist.
+    pub fn bone_mut(&mut self, bone: usize) -> &mut Bone {
+        &mut self.bones[bone]
+    }
+
+    /// Returns the transformation from the bone's space to the world.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bone doesn't exist.
+    pub fn world_transform(&self, bone: usize) -> Transform2 {
+        let mut transform = self.bones[bone].local;
+        let mut parent = self.bones[bone].parent;
+
+        while let Some(index) = parent {
+            transform = self.bones[index].local * transform;
+            parent = self.bones[index].parent;
+        }
+
+        transform
+    }
+
+    /// Returns the world transformations of all the bones, in the same
+    /// order as [`bones`](crate::skeleton::Skeleton2::bones).
+    pub fn world_transforms(&self) -> Vec<Transform2> {
+        let mut transforms: Vec<Transform2> =
+            Vec::with_capacity(self.bones.len());
+
+        for bone in &self.bones {
+            let transform = match bone.parent {
+                Some(parent) => transforms[parent] * bone.local,
+                None => bone.local,
+            };
+
+            transforms.push(transform);
+        }
+
+        transforms
+    }
+
+    /// Returns the world position of a point attached to the bone, e.g. a
+    /// sprite or a weapon held in a hand.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bone doesn't exist.
+    pub fn attach_point(&self, bone: usize, point: Fecc) -> Fecc {
+        self.world_transform(bone).apply(point)
+    }
+
+    /// Returns the world position of the start of the bone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bone doesn't exist.
+    pub fn head(&self, bone: usize) -> Fecc {
+        self.attach_point(bone, Fecc::zero())
+    }
+
+    /// Returns the world position of the end of the bone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bone doesn't exist.
+    pub fn tail(&self, bone: usize) -> Fecc {
+        self.attach_point(bone, Fecc::new(self.bones[bone].length, 0.0))
+    }
+
+    /// Rotates the bones so that each of them points from one joint to the
+    /// next, e.g. after solving the joints with
+    /// [`ik::fabrik`](crate::ik::fabrik). `bones` must form a chain, each
+    /// one the parent of the next. The linear parts of their local
+    /// transformations are replaced with rotations, the translations are
+    /// kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there isn't exactly one joint more than there are bones or
+    /// if a bone doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{ik, skeleton::Skeleton2, Fecc, Transform2};
+    ///
+    /// let mut skeleton = Skeleton2::new();
+    /// let upper = skeleton.add_bone(None, Transform2::identity(), 1.0);
+    /// let elbow = Transform2::translation(Fecc::new(1.0, 0.0));
+    /// let lower = skeleton.add_bone(Some(upper), elbow, 1.0);
+    ///
+    /// let mut joints = [
+    ///     skeleton.head(upper),
+    ///     skeleton.head(lower),
+    ///     skeleton.tail(lower),
+    /// ];
+    /// let target = Fecc::new(0.5, 1.5);
+    /// ik::fabrik(&mut joints, &[1.0, 1.0], target, 20);
+    ///
+    /// skeleton.pose_chain(&[upper, lower], &joints);
+    ///
+    /// assert_approx_eq!(f64, skeleton.tail(lower).x, 0.5, epsilon = 1e-6);
+    /// assert_approx_eq!(f64, skeleton.tail(lower).y, 1.5, epsilon = 1e-6);
+    /// ```
+    pub fn pose_chain(&mut self, bones: &[usize], joints: &[Fecc]) {
+        assert_eq!(bones.len() + 1, joints.len());
+
+        for (i, &bone) in bones.iter().enumerate() {
+            let parent_angle = match self.bones[bone].parent {
+                Some(parent) => self.world_transform(parent).x_axis.angle(),
+                None => 0.0,
+            };
+            let angle = (joints[i + 1] - joints[i]).angle() - parent_angle;
+            let local = &mut self.bones[bone].local;
+
+            *local = Transform2::translation(local.translation)
+                * Transform2::rotation(Angle::from(angle));
+        }
+    }
+}
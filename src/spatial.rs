@@ -0,0 +1,107 @@
+//! Spatial indexing.
+
+use std::collections::HashMap;
+
+use crate::{Fecc, Vecc};
+
+/// Uniform grid bucketing points by the cell they fall into, for fast
+/// neighbor queries.
+///
+/// Queries are fastest when `cell_size` is close to the typical query
+/// radius.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{spatial::SpatialHash, Fecc};
+///
+/// let points = [
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(1.0, 1.0),
+///     Fecc::new(50.0, 50.0),
+/// ];
+/// let hash = SpatialHash::from_points(5.0, &points);
+///
+/// let mut near: Vec<usize> = hash.query(Fecc::new(0.5, 0.0), 2.0).collect();
+/// near.sort_unstable();
+///
+/// assert_eq!(near, vec![0, 1]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpatialHash {
+    cell_size: f64,
+    cells: HashMap<Vecc<i64>, Vec<(usize, Fecc)>>,
+}
+
+impl SpatialHash {
+    /// Constructs a new, empty hash.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` isn't positive.
+    pub fn new(cell_size: f64) -> Self {
+        assert!(cell_size > 0.0, "the cell size must be positive");
+
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Constructs a new hash containing the points, indexed by their
+    /// positions in the slice.
+    pub fn from_points(cell_size: f64, points: &[Fecc]) -> Self {
+        let mut hash = Self::new(cell_size);
+
+        for (ix, &point) in points.iter().enumerate() {
+            hash.insert(ix, point);
+        }
+
+        hash
+    }
+
+    /// Returns the size of the cells.
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    /// Inserts a point with the specified index.
+    pub fn insert(&mut self, ix: usize, point: Fecc) {
+        self.cells
+            .entry(self.cell(point))
+            .or_default()
+            .push((ix, point));
+    }
+
+    /// Removes all points, keeping the allocated memory.
+    pub fn clear(&mut self) {
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+    }
+
+    /// Returns the indices of the points within `radius` of `position`.
+    pub fn query(
+        &self,
+        position: Fecc,
+        radius: f64,
+    ) -> impl Iterator<Item = usize> + '_ {
+        let extent = Fecc::new(radius, radius);
+        let min = self.cell(position - extent);
+        let max = self.cell(position + extent);
+        let radius_squared = radius * radius;
+
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| Vecc::new(x, y)))
+            .filter_map(move |cell| self.cells.get(&cell))
+            .flatten()
+            .filter(move |(_, point)| {
+                point.dist_squared(position) <= radius_squared
+            })
+            .map(|&(ix, _)| ix)
+    }
+
+    fn cell(&self, point: Fecc) -> Vecc<i64> {
+        (point / self.cell_size).floor()
+    }
+}
@@ -0,0 +1,819 @@
+//! Steering behaviors, as described by Craig Reynolds in
+//! [Steering Behaviors For Autonomous Characters](https://www.red3d.com/cwr/steer/).
+//!
+//! Each behavior returns a steering force. Apply it to the agent the same
+//! way as any other force.
+//!
+//! # Examples
+//!
+//! ```
+//! use veccentric::{steering::{self, Params}, Fecc};
+//!
+//! let params = Params {
+//!     max_speed: 10.0,
+//!     max_force: 5.0,
+//! };
+//!
+//! let position = Fecc::new(0.0, 0.0);
+//! let velocity = Fecc::new(0.0, 0.0);
+//! let target = Fecc::new(100.0, 0.0);
+//!
+//! let force = steering::seek(position, velocity, target, &params);
+//!
+//! assert!(force.x > 0.0);
+//! assert!(force.mag() <= params.max_force);
+//! ```
+
+#[cfg(feature = "random")]
+use rand::Rng;
+use std::cmp::Reverse;
+
+#[cfg(feature = "noise")]
+use crate::noise::Perlin;
+use crate::{interpolate, Aabb, Circle, Fecc, Polyline, Ray, RayHit, Segment};
+
+/// Limits of the agent.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Params {
+    /// The maximal speed of the agent.
+    pub max_speed: f64,
+
+    /// The maximal magnitude of the steering force.
+    pub max_force: f64,
+}
+
+/// Steers the agent towards the target at full speed.
+pub fn seek(
+    position: Fecc,
+    velocity: Fecc,
+    target: Fecc,
+    params: &Params,
+) -> Fecc {
+    let desired = (target - position).normalize() * params.max_speed;
+
+    (desired - velocity).limit(params.max_force)
+}
+
+/// Steers the agent away from the target at full speed.
+pub fn flee(
+    position: Fecc,
+    velocity: Fecc,
+    target: Fecc,
+    params: &Params,
+) -> Fecc {
+    let desired = (position - target).normalize() * params.max_speed;
+
+    (desired - velocity).limit(params.max_force)
+}
+
+/// Steers the agent towards the target, slowing down within
+/// `slowing_radius` so that it stops at the target instead of overshooting.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{steering::{self, Params}, Fecc};
+///
+/// let params = Params {
+///     max_speed: 10.0,
+///     max_force: 100.0,
+/// };
+///
+/// // Close to the target and fast — brake.
+/// let force = steering::arrive(
+///     Fecc::new(99.0, 0.0),
+///     Fecc::new(10.0, 0.0),
+///     Fecc::new(100.0, 0.0),
+///     20.0,
+///     &params,
+/// );
+///
+/// assert!(force.x < 0.0);
+/// ```
+pub fn arrive(
+    position: Fecc,
+    velocity: Fecc,
+    target: Fecc,
+    slowing_radius: f64,
+    params: &Params,
+) -> Fecc {
+    let offset = target - position;
+    let dist = offset.mag();
+    let speed = if dist < slowing_radius {
+        params.max_speed * dist / slowing_radius
+    } else {
+        params.max_speed
+    };
+    let desired = offset.normalize() * speed;
+
+    (desired - velocity).limit(params.max_force)
+}
+
+/// Steers the agent towards the position the target will reach by the time
+/// the agent gets there, assuming the target keeps its velocity.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{steering::{self, Params}, Fecc};
+///
+/// let params = Params {
+///     max_speed: 10.0,
+///     max_force: 100.0,
+/// };
+///
+/// // The target is moving upwards, so the pursuer aims above it.
+/// let force = steering::pursue(
+///     Fecc::new(0.0, 0.0),
+///     Fecc::zero(),
+///     Fecc::new(100.0, 0.0),
+///     Fecc::new(0.0, 5.0),
+///     &params,
+/// );
+///
+/// assert!(force.y > 0.0);
+/// ```
+pub fn pursue(
+    position: Fecc,
+    velocity: Fecc,
+    target_position: Fecc,
+    target_velocity: Fecc,
+    params: &Params,
+) -> Fecc {
+    let prediction =
+        predict(position, target_position, target_velocity, params);
+
+    seek(position, velocity, prediction, params)
+}
+
+/// Steers the agent away from the position the target will reach by the
+/// time the agent could get there, assuming the target keeps its velocity.
+pub fn evade(
+    position: Fecc,
+    velocity: Fecc,
+    target_position: Fecc,
+    target_velocity: Fecc,
+    params: &Params,
+) -> Fecc {
+    let prediction =
+        predict(position, target_position, target_velocity, params);
+
+    flee(position, velocity, prediction, params)
+}
+
+/// Extrapolates the target's position over the time the agent needs to reach
+/// it at full speed.
+fn predict(
+    position: Fecc,
+    target_position: Fecc,
+    target_velocity: Fecc,
+    params: &Params,
+) -> Fecc {
+    let time = if params.max_speed > 0.0 {
+        position.dist(target_position) / params.max_speed
+    } else {
+        0.0
+    };
+
+    target_position + target_velocity * time
+}
+
+/// Steers the agent so that it stays within `radius` of the path while
+/// moving along it.
+///
+/// The agent's position is predicted `look_ahead` units ahead. If the
+/// prediction lies outside the corridor, the agent seeks a point further
+/// along the path. Otherwise no steering is needed and a zero vector is
+/// returned. The agent follows the path in the order of its points.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{steering::{self, Params}, Fecc, Polyline};
+///
+/// let params = Params {
+///     max_speed: 10.0,
+///     max_force: 100.0,
+/// };
+/// let path = Polyline(vec![Fecc::new(0.0, 0.0), Fecc::new(100.0, 0.0)]);
+///
+/// // The agent is heading away from the path.
+/// let force = steering::follow_path(
+///     Fecc::new(10.0, 3.0),
+///     Fecc::new(5.0, 5.0),
+///     &path,
+///     5.0,
+///     2.0,
+///     &params,
+/// );
+///
+/// assert!(force.y < 0.0);
+///
+/// // The agent is moving along the path.
+/// let force = steering::follow_path(
+///     Fecc::new(10.0, 1.0),
+///     Fecc::new(10.0, 0.0),
+///     &path,
+///     5.0,
+///     2.0,
+///     &params,
+/// );
+///
+/// assert!(force.is_zero());
+/// ```
+pub fn follow_path(
+    position: Fecc,
+    velocity: Fecc,
+    path: &Polyline,
+    look_ahead: f64,
+    radius: f64,
+    params: &Params,
+) -> Fecc {
+    let prediction = position + velocity.normalize() * look_ahead;
+    let (ix, nearest) = match path.nearest_segment(prediction) {
+        Some(nearest) => nearest,
+        None => return Fecc::zero(),
+    };
+
+    if nearest.dist(prediction) <= radius {
+        Fecc::zero()
+    } else {
+        let direction = (path.0[ix + 1] - path.0[ix]).normalize();
+        let target = nearest + direction * look_ahead;
+
+        seek(position, velocity, target, params)
+    }
+}
+
+/// Obstacle for [`avoid_obstacles`](crate::steering::avoid_obstacles).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Obstacle {
+    /// Round obstacle, e.g. a pillar or another agent.
+    Circle(Circle),
+
+    /// Wall.
+    Segment(Segment),
+}
+
+impl Obstacle {
+    fn cast(&self, ray: &Ray) -> Option<RayHit> {
+        match self {
+            Obstacle::Circle(circle) => ray.cast_circle(circle),
+            Obstacle::Segment(segment) => ray.cast_segment(segment),
+        }
+    }
+}
+
+/// Steers the agent sideways to avoid obstacles in front of it.
+///
+/// Three whiskers are probed: one `look_ahead` units long pointing straight
+/// ahead and two half as long, pointing 30° to each side. The force is
+/// perpendicular to the agent's velocity and grows as the closest hit gets
+/// nearer. If no whisker hits an obstacle, a zero vector is returned.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{
+///     steering::{self, Obstacle, Params},
+///     Circle, Fecc,
+/// };
+///
+/// let params = Params {
+///     max_speed: 10.0,
+///     max_force: 5.0,
+/// };
+/// // The pillar is slightly below the agent's course.
+/// let obstacles = [Obstacle::Circle(Circle::new(Fecc::new(10.0, -1.0), 2.0))];
+///
+/// let force = steering::avoid_obstacles(
+///     Fecc::zero(),
+///     Fecc::new(1.0, 0.0),
+///     &obstacles,
+///     20.0,
+///     &params,
+/// );
+///
+/// assert!(force.y > 0.0);
+/// assert!(force.x.abs() < 1e-9);
+/// ```
+pub fn avoid_obstacles(
+    position: Fecc,
+    velocity: Fecc,
+    obstacles: &[Obstacle],
+    look_ahead: f64,
+    params: &Params,
+) -> Fecc {
+    const WHISKER_ANGLE: f64 = std::f64::consts::PI / 6.0;
+
+    if velocity.is_zero() {
+        return Fecc::zero();
+    }
+
+    let heading = velocity.normalize();
+    let left = Fecc::new(-heading.y, heading.x);
+    let whiskers = [
+        (heading, look_ahead, 0.0),
+        (heading.rotate(WHISKER_ANGLE), look_ahead / 2.0, -1.0),
+        (heading.rotate(-WHISKER_ANGLE), look_ahead / 2.0, 1.0),
+    ];
+
+    let closest = whiskers
+        .iter()
+        .flat_map(|&(direction, length, side)| {
+            let ray = Ray::new(position, direction);
+
+            obstacles
+                .iter()
+                .filter_map(move |obstacle| obstacle.cast(&ray))
+                .filter(move |hit| hit.distance <= length)
+                .map(move |hit| (hit, length, side))
+        })
+        .min_by(|(a, ..), (b, ..)| a.distance.total_cmp(&b.distance));
+
+    match closest {
+        Some((hit, length, side)) => {
+            // Turn away from the surface; if hit head-on, turn away from the
+            // whisker (or left if it's the middle one).
+            let lateral = hit.normal.dot(left);
+            let sign = if lateral.abs() > 1e-9 {
+                lateral.signum()
+            } else if side != 0.0 {
+                side
+            } else {
+                1.0
+            };
+            let strength = 1.0 - hit.distance / length;
+
+            left * sign * strength * params.max_force
+        }
+        None => Fecc::zero(),
+    }
+}
+
+/// Steers the agent back inside `bounds`.
+///
+/// Within `margin` of an edge the agent is steered towards a velocity
+/// pointing inwards at full speed. The closer to the edge, the stronger the
+/// steering — it grows smoothly from zero at `margin` from the edge to full
+/// at the edge and beyond. Far from the edges a zero vector is returned.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{steering::{self, Params}, Aabb, Fecc};
+///
+/// let params = Params {
+///     max_speed: 10.0,
+///     max_force: 5.0,
+/// };
+/// let bounds = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(100.0, 100.0));
+///
+/// // Close to the right edge, moving right.
+/// let force = steering::contain(
+///     Fecc::new(98.0, 50.0),
+///     Fecc::new(5.0, 0.0),
+///     bounds,
+///     10.0,
+///     &params,
+/// );
+/// assert!(force.x < 0.0);
+///
+/// // In the middle.
+/// let force = steering::contain(
+///     Fecc::new(50.0, 50.0),
+///     Fecc::new(5.0, 0.0),
+///     bounds,
+///     10.0,
+///     &params,
+/// );
+/// assert!(force.is_zero());
+/// ```
+pub fn contain(
+    position: Fecc,
+    velocity: Fecc,
+    bounds: Aabb,
+    margin: f64,
+    params: &Params,
+) -> Fecc {
+    let proximity = |dist_to_edge: f64| {
+        if margin > 0.0 {
+            interpolate::smoothstep(0.0, 1.0, 1.0 - dist_to_edge / margin)
+        } else if dist_to_edge < 0.0 {
+            1.0
+        } else {
+            0.0
+        }
+    };
+    let steer_axis = |position: f64, velocity: f64, min: f64, max: f64| {
+        let low = proximity(position - min);
+        let high = proximity(max - position);
+        let velocity = interpolate::lerp(velocity, params.max_speed, low);
+
+        interpolate::lerp(velocity, -params.max_speed, high)
+    };
+
+    let desired = Fecc::new(
+        steer_axis(position.x, velocity.x, bounds.min.x, bounds.max.x),
+        steer_axis(position.y, velocity.y, bounds.min.y, bounds.max.y),
+    );
+
+    (desired - velocity).limit(params.max_force)
+}
+
+/// Steers the agent to keep a fixed offset from the leader. `offset` is
+/// expressed in the leader's local space — `x` points in the direction the
+/// leader is moving and `y` to its left.
+///
+/// The agent arrives at the position the offset point will reach by the time
+/// the agent gets there, so formations hold even when the leader turns.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{steering::{self, Params}, Fecc};
+///
+/// let params = Params {
+///     max_speed: 10.0,
+///     max_force: 100.0,
+/// };
+///
+/// // Escort flying 5 units to the left of the leader, which is heading up.
+/// let force = steering::offset_pursuit(
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(0.0, 5.0),
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(0.0, 5.0),
+///     Fecc::new(0.0, 5.0),
+///     2.0,
+///     &params,
+/// );
+///
+/// assert!(force.x < 0.0);
+/// ```
+pub fn offset_pursuit(
+    position: Fecc,
+    velocity: Fecc,
+    leader_position: Fecc,
+    leader_velocity: Fecc,
+    offset: Fecc,
+    slowing_radius: f64,
+    params: &Params,
+) -> Fecc {
+    let heading = if leader_velocity.is_zero() {
+        0.0
+    } else {
+        leader_velocity.angle()
+    };
+    let target = leader_position + offset.rotate(heading);
+    let prediction = predict(position, target, leader_velocity, params);
+
+    arrive(position, velocity, prediction, slowing_radius, params)
+}
+
+/// Steers the agent to follow the leader at `distance` behind it. If the
+/// agent gets in front of the leader, it also steers out of the leader's way.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{steering::{self, Params}, Fecc};
+///
+/// let params = Params {
+///     max_speed: 10.0,
+///     max_force: 100.0,
+/// };
+///
+/// // The leader is heading right and the follower is ahead of it.
+/// let force = steering::follow_leader(
+///     Fecc::new(10.0, 0.0),
+///     Fecc::zero(),
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(5.0, 0.0),
+///     3.0,
+///     &params,
+/// );
+///
+/// assert!(force.x < 0.0);
+/// ```
+pub fn follow_leader(
+    position: Fecc,
+    velocity: Fecc,
+    leader_position: Fecc,
+    leader_velocity: Fecc,
+    distance: f64,
+    params: &Params,
+) -> Fecc {
+    let heading = leader_velocity.normalize();
+    let behind = leader_position - heading * distance;
+    let ahead = leader_position + heading * distance;
+
+    let mut force = arrive(position, velocity, behind, distance, params);
+
+    if position.dist(ahead) < distance
+        || position.dist(leader_position) < distance
+    {
+        force +=
+            evade(position, velocity, leader_position, leader_velocity, params);
+    }
+
+    force.limit(params.max_force)
+}
+
+/// Random, yet smooth, wandering.
+///
+/// A circle is projected in front of the agent and the target is a point on
+/// that circle. Each step the target moves only slightly along the circle, so
+/// the agent changes its direction gradually instead of jittering.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Wander {
+    /// The distance from the agent to the center of the circle.
+    pub distance: f64,
+
+    /// The radius of the circle.
+    pub radius: f64,
+
+    /// How fast the target moves along the circle. For
+    /// [`steer`](crate::steering::Wander::steer) it is the maximal change of
+    /// the target's angle per step (in radians), for
+    /// [`steer_noise`](crate::steering::Wander::steer_noise) it is the
+    /// frequency of the noise.
+    pub jitter: f64,
+
+    angle: f64,
+}
+
+impl Wander {
+    /// Constructs a new wander behavior.
+    pub fn new(distance: f64, radius: f64, jitter: f64) -> Self {
+        Self {
+            distance,
+            radius,
+            jitter,
+            angle: 0.0,
+        }
+    }
+
+    /// Moves the target randomly along the circle and returns the steering
+    /// force.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    /// use veccentric::{
+    ///     steering::{Params, Wander},
+    ///     Fecc,
+    /// };
+    ///
+    /// let mut rng = SmallRng::from_seed([0xdd; 32]);
+    /// let mut wander = Wander::new(10.0, 5.0, 0.3);
+    /// let params = Params {
+    ///     max_speed: 10.0,
+    ///     max_force: 5.0,
+    /// };
+    /// let velocity = Fecc::new(1.0, 0.0);
+    ///
+    /// for _ in 0..100 {
+    ///     let force = wander.steer(velocity, &mut rng, &params);
+    ///
+    ///     // The target is always in front of the agent.
+    ///     assert!(force.x > 0.0);
+    ///     assert!(force.mag() <= params.max_force + 1e-9);
+    /// }
+    /// ```
+    #[cfg(feature = "random")]
+    #[doc(cfg(feature = "random"))]
+    pub fn steer<R>(
+        &mut self,
+        velocity: Fecc,
+        rng: &mut R,
+        params: &Params,
+    ) -> Fecc
+    where
+        R: Rng,
+    {
+        self.angle += rng.gen_range(-1.0..=1.0) * self.jitter;
+
+        self.force(velocity, params)
+    }
+
+    /// Moves the target along the circle following a noise curve and returns
+    /// the steering force. `time` should increase steadily. Give each agent a
+    /// different time offset, so that they don't wander in unison.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{
+    ///     noise::Perlin,
+    ///     steering::{Params, Wander},
+    ///     Fecc,
+    /// };
+    ///
+    /// let perlin = Perlin::new(0);
+    /// let mut wander = Wander::new(10.0, 5.0, 0.5);
+    /// let params = Params {
+    ///     max_speed: 10.0,
+    ///     max_force: 5.0,
+    /// };
+    /// let velocity = Fecc::new(1.0, 0.0);
+    ///
+    /// let a = wander.steer_noise(velocity, &perlin, 0.0, &params);
+    /// let b = wander.steer_noise(velocity, &perlin, 0.01, &params);
+    ///
+    /// assert!(a.dist(b) < 0.5);
+    /// ```
+    #[cfg(feature = "noise")]
+    #[doc(cfg(feature = "noise"))]
+    pub fn steer_noise(
+        &mut self,
+        velocity: Fecc,
+        perlin: &Perlin,
+        time: f64,
+        params: &Params,
+    ) -> Fecc {
+        self.angle = perlin.noise1(time * self.jitter) * std::f64::consts::PI;
+
+        self.force(velocity, params)
+    }
+
+    /// Returns the steering force towards the current target without moving
+    /// it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{
+    ///     steering::{Params, Wander},
+    ///     Fecc,
+    /// };
+    ///
+    /// let wander = Wander::new(10.0, 5.0, 0.3);
+    /// let params = Params {
+    ///     max_speed: 10.0,
+    ///     max_force: 5.0,
+    /// };
+    ///
+    /// // The target starts straight ahead, so the agent just speeds up.
+    /// let force = wander.force(Fecc::new(1.0, 0.0), &params);
+    ///
+    /// assert_approx_eq!(f64, force.x, 5.0);
+    /// assert_approx_eq!(f64, force.y, 0.0);
+    /// ```
+    pub fn force(&self, velocity: Fecc, params: &Params) -> Fecc {
+        let heading = if velocity.is_zero() {
+            0.0
+        } else {
+            velocity.angle()
+        };
+        let target = Fecc::from_angle(heading) * self.distance
+            + Fecc::from_angle(heading + self.angle) * self.radius;
+        let desired = target.normalize() * params.max_speed;
+
+        (desired - velocity).limit(params.max_force)
+    }
+}
+
+/// How a [`SteeringPipeline`](crate::steering::SteeringPipeline) combines
+/// forces.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub enum Combine {
+    /// Sum of the weighted forces, truncated to `max_force`.
+    #[default]
+    WeightedSum,
+
+    /// Weighted average of the forces, truncated to `max_force`.
+    WeightedAverage,
+
+    /// Weighted forces are added in the order of decreasing priority until
+    /// the total reaches `max_force`. The last force to fit is scaled down
+    /// so that the total stays within `max_force` and the rest are ignored.
+    /// This way important behaviors (e.g. avoiding obstacles) can't be
+    /// drowned out by less important ones.
+    Prioritized,
+}
+
+/// Combines the forces of multiple behaviors.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{
+///     steering::{Combine, SteeringPipeline},
+///     Fecc,
+/// };
+///
+/// let avoid = Fecc::new(0.0, 8.0);
+/// let seek = Fecc::new(10.0, 0.0);
+///
+/// let mut pipeline = SteeringPipeline::new(10.0, Combine::Prioritized);
+/// pipeline
+///     .add_with_priority(avoid, 1.0, 1)
+///     .add_with_priority(seek, 1.0, 0);
+/// let force = pipeline.result();
+///
+/// // Avoidance comes first, seeking gets the rest of the budget.
+/// assert_approx_eq!(f64, force.y, 8.0);
+/// assert_approx_eq!(f64, force.x, 6.0);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct SteeringPipeline {
+    /// The maximal magnitude of the resulting force.
+    pub max_force: f64,
+
+    /// The way the forces are combined.
+    pub combine: Combine,
+
+    forces: Vec<(Fecc, f64, i32)>,
+}
+
+impl SteeringPipeline {
+    /// Constructs a new, empty pipeline.
+    pub fn new(max_force: f64, combine: Combine) -> Self {
+        Self {
+            max_force,
+            combine,
+            forces: vec![],
+        }
+    }
+
+    /// Adds a weighted force with priority `0`.
+    pub fn add(&mut self, force: Fecc, weight: f64) -> &mut Self {
+        self.add_with_priority(force, weight, 0)
+    }
+
+    /// Adds a weighted force with the specified priority. Higher priorities
+    /// come first. Priorities are only taken into account by
+    /// [`Combine::Prioritized`](crate::steering::Combine::Prioritized).
+    pub fn add_with_priority(
+        &mut self,
+        force: Fecc,
+        weight: f64,
+        priority: i32,
+    ) -> &mut Self {
+        self.forces.push((force, weight, priority));
+
+        self
+    }
+
+    /// Removes all forces.
+    pub fn clear(&mut self) {
+        self.forces.clear();
+    }
+
+    /// Returns the combined force.
+    pub fn result(&self) -> Fecc {
+        let weighted = || self.forces.iter().map(|&(f, w, _)| f * w);
+
+        match self.combine {
+            Combine::WeightedSum => weighted()
+                .fold(Fecc::zero(), |sum, f| sum + f)
+                .limit(self.max_force),
+            Combine::WeightedAverage => {
+                let total: f64 = self.forces.iter().map(|&(_, w, _)| w).sum();
+
+                if total == 0.0 {
+                    Fecc::zero()
+                } else {
+                    (weighted().fold(Fecc::zero(), |sum, f| sum + f) / total)
+                        .limit(self.max_force)
+                }
+            }
+            Combine::Prioritized => {
+                let mut forces = self.forces.clone();
+                // Stable, so forces with equal priorities keep their order.
+                forces.sort_by_key(|&(_, _, priority)| Reverse(priority));
+
+                let mut sum = Fecc::zero();
+
+                for (force, weight, _) in forces {
+                    if sum.mag() >= self.max_force {
+                        break;
+                    }
+
+                    let force = force * weight;
+
+                    // Largest `s` in `[0.0, 1.0]` such that
+                    // `|sum + force * s| <= max_force`.
+                    let a = force.mag_squared();
+                    let b = 2.0 * sum.dot(force);
+                    let c = sum.mag_squared() - self.max_force.powi(2);
+
+                    if a == 0.0 {
+                        continue;
+                    }
+
+                    let s = (-b + (b * b - 4.0 * a * c).max(0.0).sqrt())
+                        / (2.0 * a);
+
+                    sum += force * s.clamp(0.0, 1.0);
+                }
+
+                sum
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Compensated summation of vectors.
+//!
+//! Adding many small vectors to a large one with `+=` loses the low bits of
+//! each small vector, and the error grows with the number of terms.
+//! [`KahanSum`](crate::summation::KahanSum) keeps track of the lost bits and
+//! adds them back, so the error stays close to the rounding error of a
+//! single addition regardless of the number of terms.
+
+use std::{iter::FromIterator, ops::AddAssign};
+
+use crate::Fecc;
+
+/// Accumulator summing vectors with
+/// [Kahan–Babuška–Neumaier](https://en.wikipedia.org/wiki/Kahan_summation_algorithm#Further_enhancements)
+/// compensated summation.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{summation::KahanSum, Fecc};
+///
+/// let tiny = Fecc::new(1e-16, 0.0);
+/// let mut naive = Fecc::new(1.0, 0.0);
+/// let mut compensated = KahanSum::new();
+/// compensated += Fecc::new(1.0, 0.0);
+///
+/// for _ in 0..1000 {
+///     naive += tiny;
+///     compensated += tiny;
+/// }
+///
+/// // Each tiny term is lost when added naively.
+/// assert_eq!(naive.x, 1.0);
+/// assert_approx_eq!(f64, compensated.value().x, 1.0 + 1e-13);
+/// ```
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct KahanSum {
+    sum: Fecc,
+    compensation: Fecc,
+}
+
+impl KahanSum {
+    /// Constructs a new accumulator with the sum of zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a vector to the sum.
+    pub fn add(&mut self, term: Fecc) {
+        let sum = self.sum + term;
+        let lost = |sum: f64, a: f64, b: f64| {
+            if a.abs() >= b.abs() {
+                (a - sum) + b
+            } else {
+                (b - sum) + a
+            }
+        };
+
+        self.compensation += Fecc::new(
+            lost(sum.x, self.sum.x, term.x),
+            lost(sum.y, self.sum.y, term.y),
+        );
+        self.sum = sum;
+    }
+
+    /// Returns the sum of the vectors added so far.
+    pub fn value(&self) -> Fecc {
+        self.sum + self.compensation
+    }
+}
+
+impl AddAssign<Fecc> for KahanSum {
+    fn add_assign(&mut self, term: Fecc) {
+        self.add(term);
+    }
+}
+
+impl Extend<Fecc> for KahanSum {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Fecc>,
+    {
+        for term in iter {
+            self.add(term);
+        }
+    }
+}
+
+impl FromIterator<Fecc> for KahanSum {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Fecc>,
+    {
+        let mut sum = Self::new();
+        sum.extend(iter);
+
+        sum
+    }
+}
+
+/// Returns the sum of the vectors, computed with
+/// [`KahanSum`](crate::summation::KahanSum).
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{summation, Fecc};
+///
+/// let terms = [
+///     Fecc::new(1e16, 0.0),
+///     Fecc::new(1.0, 0.0),
+///     Fecc::new(-1e16, 0.0),
+/// ];
+///
+/// let sum = summation::sum_compensated(terms.iter().copied());
+///
+/// assert_approx_eq!(f64, sum.x, 1.0);
+/// ```
+pub fn sum_compensated<I>(iter: I) -> Fecc
+where
+    I: IntoIterator<Item = Fecc>,
+{
+    iter.into_iter().collect::<KahanSum>().value()
+}
@@ -0,0 +1,185 @@
+//! Keyframe animation.
+
+use crate::interpolate::{self, Interpolate};
+
+/// How a [`Timeline`](crate::timeline::Timeline) behaves after its last
+/// keyframe.
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub enum Repeat {
+    /// Holds the last value.
+    #[default]
+    Once,
+
+    /// Starts over from the first keyframe.
+    Loop,
+
+    /// Plays backwards to the first keyframe, then forwards again and so on.
+    PingPong,
+}
+
+/// A value at a specific point in time.
+#[derive(Copy, Clone, Debug)]
+pub struct Keyframe<T> {
+    /// The time of the keyframe.
+    pub time: f64,
+
+    /// The value at that time.
+    pub value: T,
+
+    /// The easing applied when transitioning from the previous keyframe to
+    /// this one. Any function from the [`ease`](crate::ease) module can be
+    /// used.
+    pub easing: fn(f64) -> f64,
+}
+
+/// A sequence of keyframes which can be sampled at any point in time.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{
+///     ease,
+///     timeline::{Repeat, Timeline},
+///     Fecc,
+/// };
+///
+/// let timeline = Timeline::new()
+///     .keyframe(0.0, Fecc::new(0.0, 0.0), ease::linear)
+///     .keyframe(1.0, Fecc::new(10.0, 0.0), ease::linear)
+///     .keyframe(2.0, Fecc::new(10.0, 10.0), ease::in_out_cubic)
+///     .repeat(Repeat::PingPong);
+///
+/// let p = timeline.sample(0.5).unwrap();
+/// assert_approx_eq!(f64, p.x, 5.0);
+///
+/// let p = timeline.sample(1.5).unwrap();
+/// assert_approx_eq!(f64, p.y, 5.0);
+///
+/// // Going back.
+/// let p = timeline.sample(3.5).unwrap();
+/// assert_approx_eq!(f64, p.x, 5.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Timeline<T> {
+    keyframes: Vec<Keyframe<T>>,
+    repeat: Repeat,
+}
+
+impl<T> Timeline<T>
+where
+    T: Interpolate,
+{
+    /// Constructs a new timeline without keyframes.
+    pub fn new() -> Self {
+        Self {
+            keyframes: vec![],
+            repeat: Repeat::Once,
+        }
+    }
+
+    /// Adds a keyframe.
+    pub fn keyframe(
+        mut self,
+        time: f64,
+        value: T,
+        easing: fn(f64) -> f64,
+    ) -> Self {
+        self.push(Keyframe {
+            time,
+            value,
+            easing,
+        });
+
+        self
+    }
+
+    /// Sets the repeat mode.
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+
+        self
+    }
+
+    /// Adds a keyframe, keeping the keyframes sorted by time.
+    pub fn push(&mut self, keyframe: Keyframe<T>) {
+        let ix = self.keyframes.partition_point(|k| k.time <= keyframe.time);
+        self.keyframes.insert(ix, keyframe);
+    }
+
+    /// Returns the keyframes sorted by time.
+    pub fn keyframes(&self) -> &[Keyframe<T>] {
+        &self.keyframes
+    }
+
+    /// Returns the time between the first and the last keyframe.
+    pub fn duration(&self) -> f64 {
+        match (self.keyframes.first(), self.keyframes.last()) {
+            (Some(first), Some(last)) => last.time - first.time,
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the value at the specified time or `None` if the timeline
+    /// has no keyframes.
+    pub fn sample(&self, time: f64) -> Option<T> {
+        let first = self.keyframes.first()?;
+        let time = self.wrap(time);
+        let ix = self.keyframes.partition_point(|k| k.time <= time);
+
+        if ix == 0 {
+            return Some(first.value);
+        }
+
+        let previous = &self.keyframes[ix - 1];
+
+        match self.keyframes.get(ix) {
+            Some(next) => {
+                let t = (time - previous.time) / (next.time - previous.time);
+
+                Some(interpolate::lerp(
+                    previous.value,
+                    next.value,
+                    (next.easing)(t),
+                ))
+            }
+            None => Some(previous.value),
+        }
+    }
+
+    /// Maps time onto the span of the keyframes according to the repeat mode.
+    fn wrap(&self, time: f64) -> f64 {
+        let start = match self.keyframes.first() {
+            Some(first) => first.time,
+            None => return time,
+        };
+        let duration = self.duration();
+
+        if duration <= 0.0 {
+            return start;
+        }
+
+        match self.repeat {
+            Repeat::Once => time,
+            Repeat::Loop => start + (time - start).rem_euclid(duration),
+            Repeat::PingPong => {
+                let t = (time - start).rem_euclid(2.0 * duration);
+
+                if t > duration {
+                    start + 2.0 * duration - t
+                } else {
+                    start + t
+                }
+            }
+        }
+    }
+}
+
+impl<T> Default for Timeline<T>
+where
+    T: Interpolate,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,69 @@
+//! Wrap-around worlds, where leaving through one edge means entering through
+//! the opposite one.
+
+use crate::Fecc;
+
+/// Rectangular world spanning `[0, size.x) × [0, size.y)` with opposite
+/// edges glued together.
+///
+/// [`Fecc`](crate::fecc::Fecc)'s `%` wraps positions, but the plain
+/// difference of two wrapped positions may point the long way around.
+/// [`shortest_offset`](crate::torus::Torus::shortest_offset) follows the
+/// [minimum image convention](https://en.wikipedia.org/wiki/Periodic_boundary_conditions)
+/// instead, so seeking and gravity work across the edges.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{torus::Torus, Fecc};
+///
+/// let world = Torus::new(Fecc::new(100.0, 100.0));
+/// let ship = world.wrap(Fecc::new(105.0, -5.0));
+///
+/// assert_approx_eq!(f64, ship.x, 5.0);
+/// assert_approx_eq!(f64, ship.y, 95.0);
+///
+/// // The asteroid is closer through the left edge.
+/// let asteroid = Fecc::new(90.0, 95.0);
+/// let offset = world.shortest_offset(ship, asteroid);
+///
+/// assert_approx_eq!(f64, offset.x, -15.0);
+/// assert_approx_eq!(f64, offset.y, 0.0);
+/// assert_approx_eq!(f64, world.dist(ship, asteroid), 15.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Torus {
+    /// The width and the height of the world.
+    pub size: Fecc,
+}
+
+impl Torus {
+    /// Constructs a new world.
+    pub fn new(size: Fecc) -> Self {
+        Self { size }
+    }
+
+    /// Returns the position wrapped into the world.
+    pub fn wrap(&self, position: Fecc) -> Fecc {
+        position % self.size
+    }
+
+    /// Returns the shortest vector from `a` to `b`, possibly crossing the
+    /// edges. Its components lie in `[-size / 2, size / 2)`.
+    pub fn shortest_offset(&self, a: Fecc, b: Fecc) -> Fecc {
+        let half = self.size / 2.0;
+
+        (b - a + half) % self.size - half
+    }
+
+    /// Returns the shortest distance between two positions.
+    pub fn dist(&self, a: Fecc, b: Fecc) -> f64 {
+        self.shortest_offset(a, b).mag()
+    }
+
+    /// Returns the square of the shortest distance between two positions.
+    pub fn dist_squared(&self, a: Fecc, b: Fecc) -> f64 {
+        self.shortest_offset(a, b).mag_squared()
+    }
+}
@@ -0,0 +1,182 @@
+//! Affine transformations.
+
+use crate::{matrix::Mat2, Angle, Fecc};
+
+/// Affine transformation of the plane — a linear map (rotation, scale,
+/// shear) followed by a translation.
+///
+/// The linear part is stored as the images of the unit vectors, i.e. the
+/// columns of the matrix.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{transform::Transform2, Angular, Fecc};
+///
+/// let transform = Transform2::rotation(90.0.deg());
+/// let moved = Transform2::translation(Fecc::new(10.0, 0.0)) * transform;
+///
+/// let p = moved.apply(Fecc::new(1.0, 0.0));
+///
+/// assert_approx_eq!(f64, p.x, 10.0);
+/// assert_approx_eq!(f64, p.y, 1.0);
+/// ```
+///
+/// Mapping between world and screen space.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{transform::Transform2, Fecc};
+///
+/// // The screen is 800×600 pixels with the Y axis pointing down, one world
+/// // unit is 50 pixels and the world's origin is in the center.
+/// let world_to_screen = Transform2::scale(Fecc::new(50.0, -50.0))
+///     .then(Transform2::translation(Fecc::new(400.0, 300.0)));
+/// let screen_to_world = world_to_screen.inverse().unwrap();
+///
+/// let pixel = world_to_screen.apply(Fecc::new(2.0, 1.0));
+/// assert_approx_eq!(f64, pixel.x, 500.0);
+/// assert_approx_eq!(f64, pixel.y, 250.0);
+///
+/// let point = screen_to_world.apply(Fecc::new(0.0, 0.0));
+/// assert_approx_eq!(f64, point.x, -8.0);
+/// assert_approx_eq!(f64, point.y, 6.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Transform2 {
+    /// The image of the unit X vector.
+    pub x_axis: Fecc,
+
+    /// The image of the unit Y vector.
+    pub y_axis: Fecc,
+
+    /// The translation applied after the linear part.
+    pub translation: Fecc,
+}
+
+impl Transform2 {
+    /// Returns the transformation leaving every point in place.
+    pub fn identity() -> Self {
+        Self {
+            x_axis: Fecc::new(1.0, 0.0),
+            y_axis: Fecc::new(0.0, 1.0),
+            translation: Fecc::zero(),
+        }
+    }
+
+    /// Returns a translation by `offset`.
+    pub fn translation(offset: Fecc) -> Self {
+        Self {
+            translation: offset,
+            ..Self::identity()
+        }
+    }
+
+    /// Returns a counterclockwise rotation about the origin.
+    pub fn rotation<A>(angle: A) -> Self
+    where
+        A: Into<Angle>,
+    {
+        let angle = *angle.into();
+        let (sin, cos) = angle.sin_cos();
+
+        Self {
+            x_axis: Fecc::new(cos, sin),
+            y_axis: Fecc::new(-sin, cos),
+            translation: Fecc::zero(),
+        }
+    }
+
+    /// Returns a scaling about the origin, by `factors.x` along the X axis
+    /// and by `factors.y` along the Y axis.
+    pub fn scale(factors: Fecc) -> Self {
+        Self {
+            x_axis: Fecc::new(factors.x, 0.0),
+            y_axis: Fecc::new(0.0, factors.y),
+            translation: Fecc::zero(),
+        }
+    }
+
+    /// Returns a shear, moving points along the X axis by `factors.x`
+    /// times their Y coordinate and along the Y axis by `factors.y` times
+    /// their X coordinate.
+    pub fn shear(factors: Fecc) -> Self {
+        Self {
+            x_axis: Fecc::new(1.0, factors.y),
+            y_axis: Fecc::new(factors.x, 1.0),
+            translation: Fecc::zero(),
+        }
+    }
+
+    /// Returns the transformation applying `self` first and then `next`,
+    /// i.e. `next * self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::{transform::Transform2, Angular, Fecc};
+    ///
+    /// let transform = Transform2::translation(Fecc::new(1.0, 0.0))
+    ///     .then(Transform2::rotation(90.0.deg()))
+    ///     .then(Transform2::scale(Fecc::new(2.0, 2.0)));
+    ///
+    /// let p = transform.apply(Fecc::zero());
+    ///
+    /// assert_approx_eq!(f64, p.x, 0.0, epsilon = 1e-9);
+    /// assert_approx_eq!(f64, p.y, 2.0);
+    /// ```
+    pub fn then(self, next: Transform2) -> Self {
+        next * self
+    }
+
+    /// Returns the inverse transformation or `None` if the transformation
+    /// collapses the plane (its determinant is zero).
+    pub fn inverse(&self) -> Option<Self> {
+        let linear = Mat2::from_cols(self.x_axis, self.y_axis).inverse()?;
+
+        Some(Self {
+            x_axis: linear.x_axis,
+            y_axis: linear.y_axis,
+            translation: -(linear * self.translation),
+        })
+    }
+
+    /// Transforms a point.
+    pub fn apply(&self, point: Fecc) -> Fecc {
+        self.apply_vector(point) + self.translation
+    }
+
+    /// Transforms a vector, i.e. applies only the linear part, without the
+    /// translation.
+    pub fn apply_vector(&self, vector: Fecc) -> Fecc {
+        self.x_axis * vector.x + self.y_axis * vector.y
+    }
+
+    /// Returns the determinant of the linear part — the factor by which
+    /// areas are scaled. It's negative if the transformation flips the
+    /// plane.
+    pub fn determinant(&self) -> f64 {
+        self.x_axis.cross(self.y_axis)
+    }
+}
+
+impl Default for Transform2 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Composition. `(a * b).apply(p)` is equal to `a.apply(b.apply(p))`.
+impl std::ops::Mul<Transform2> for Transform2 {
+    type Output = Transform2;
+
+    fn mul(self, rhs: Transform2) -> Transform2 {
+        Transform2 {
+            x_axis: self.apply_vector(rhs.x_axis),
+            y_axis: self.apply_vector(rhs.y_axis),
+            translation: self.apply(rhs.translation),
+        }
+    }
+}
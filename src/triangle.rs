@@ -0,0 +1,40 @@
+//! Triangle.
+
+use crate::{Fecc, Polygon};
+
+/// Triangle described by its vertices.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{Fecc, Triangle};
+///
+/// let triangle = Triangle(
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(3.0, 0.0),
+///     Fecc::new(0.0, 3.0),
+/// );
+///
+/// assert_approx_eq!(f64, triangle.centroid().x, 1.0);
+/// ```
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub struct Triangle(pub Fecc, pub Fecc, pub Fecc);
+
+impl Triangle {
+    /// Constructs a new triangle.
+    pub fn new(a: Fecc, b: Fecc, c: Fecc) -> Self {
+        Self(a, b, c)
+    }
+
+    /// Returns the average of the vertices.
+    pub fn centroid(&self) -> Fecc {
+        (self.0 + self.1 + self.2) / 3.0
+    }
+}
+
+impl From<Triangle> for Polygon {
+    fn from(Triangle(a, b, c): Triangle) -> Self {
+        Polygon(vec![a, b, c])
+    }
+}
@@ -0,0 +1,244 @@
+//! Delaunay triangulation and Voronoi diagrams.
+//!
+//! # Examples
+//!
+//! ```
+//! use veccentric::{triangulate, Aabb, Fecc};
+//!
+//! let points = [
+//!     Fecc::new(0.0, 0.0),
+//!     Fecc::new(10.0, 0.0),
+//!     Fecc::new(10.0, 10.0),
+//!     Fecc::new(0.0, 10.0),
+//!     Fecc::new(4.0, 6.0),
+//! ];
+//!
+//! let triangles = triangulate::delaunay(&points);
+//! assert_eq!(triangles.len(), 4);
+//!
+//! let bounds = Aabb::new(Fecc::new(-5.0, -5.0), Fecc::new(15.0, 15.0));
+//! let cells = triangulate::voronoi(&points, bounds);
+//! assert_eq!(cells.len(), points.len());
+//! ```
+
+use crate::{predicates, Aabb, Fecc, Polygon};
+
+/// Returns the [Delaunay
+/// triangulation](https://en.wikipedia.org/wiki/Delaunay_triangulation) of
+/// the points as triples of indices into `points`. The vertices of each
+/// triangle are ordered counterclockwise.
+///
+/// Uses the Bowyer–Watson algorithm with a vertex at infinity instead of a
+/// finite bounding triangle, so nearly collinear points on the convex hull
+/// are triangulated too. Duplicate points are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{triangulate, Fecc};
+///
+/// // A very flat convex chain.
+/// let mut points: Vec<Fecc> = (0..10)
+///     .map(|i| {
+///         let x = i as f64 * 10.0;
+///
+///         Fecc::new(x, 0.0001 * x * x)
+///     })
+///     .collect();
+///
+/// assert_eq!(triangulate::delaunay(&points).len(), 8);
+///
+/// points.push(Fecc::new(45.0, -30.0));
+///
+/// assert_eq!(triangulate::delaunay(&points).len(), 17);
+/// ```
+pub fn delaunay(points: &[Fecc]) -> Vec<[usize; 3]> {
+    // The index of the vertex at infinity.
+    let infinity = points.len();
+
+    // The first triangle is made of the first point, the first point
+    // different from it and the first point not collinear with them.
+    let first = match points.first() {
+        Some(&first) => first,
+        None => return vec![],
+    };
+    let second = match points.iter().position(|&p| p != first) {
+        Some(second) => second,
+        None => return vec![],
+    };
+    let third = match points
+        .iter()
+        .position(|&p| predicates::orient2d(first, points[second], p) != 0.0)
+    {
+        Some(third) => third,
+        None => return vec![],
+    };
+    let (b, c) =
+        if predicates::orient2d(first, points[second], points[third]) > 0.0 {
+            (second, third)
+        } else {
+            (third, second)
+        };
+
+    // Each edge of the convex hull is closed by a ghost triangle with the
+    // vertex at infinity.
+    let mut triangles = vec![
+        [0, b, c],
+        [b, 0, infinity],
+        [c, b, infinity],
+        [0, c, infinity],
+    ];
+
+    for (ix, &point) in points.iter().enumerate() {
+        if ix == 0 || ix == second || ix == third {
+            continue;
+        }
+
+        let (bad, good): (Vec<[usize; 3]>, Vec<[usize; 3]>) = triangles
+            .into_iter()
+            .partition(|&t| in_circumcircle(points, t, point));
+        triangles = good;
+
+        // The edges of the hole left by the bad triangles.
+        let edges: Vec<(usize, usize)> = bad
+            .iter()
+            .flat_map(|t| (0..3).map(move |i| (t[i], t[(i + 1) % 3])))
+            .collect();
+
+        for &(a, b) in &edges {
+            let is_shared = edges.iter().any(|&(c, d)| c == b && d == a);
+
+            if !is_shared {
+                triangles.push([a, b, ix]);
+            }
+        }
+    }
+
+    triangles.retain(|t| !t.contains(&infinity));
+
+    triangles
+}
+
+/// Returns the Voronoi cell of each point (in the same order as `points`),
+/// clipped to `bounds`. Cells of duplicate points overlap.
+///
+/// # Examples
+///
+/// Collinear points have no Delaunay triangulation, but their cells are
+/// still the strips between the bisectors.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{triangulate, Aabb, Fecc};
+///
+/// let points = [
+///     Fecc::new(0.0, 0.0),
+///     Fecc::new(1.0, 0.0),
+///     Fecc::new(2.0, 0.0),
+/// ];
+/// let bounds = Aabb::new(Fecc::new(-1.0, -1.0), Fecc::new(3.0, 1.0));
+/// let cells = triangulate::voronoi(&points, bounds);
+///
+/// // The cells don't overlap, so their areas add up to the bounds' area.
+/// let areas: Vec<f64> =
+///     cells.iter().map(|cell| cell.signed_area().abs()).collect();
+/// assert_approx_eq!(f64, areas[0], 3.0);
+/// assert_approx_eq!(f64, areas[1], 2.0);
+/// assert_approx_eq!(f64, areas[2], 3.0);
+/// ```
+pub fn voronoi(points: &[Fecc], bounds: Aabb) -> Vec<Polygon> {
+    let mut neighbors = vec![vec![]; points.len()];
+    let triangles = delaunay(points);
+
+    for t in &triangles {
+        for i in 0..3 {
+            let (a, b) = (t[i], t[(i + 1) % 3]);
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+    }
+
+    // Without triangles (fewer than three points or all of them collinear)
+    // clip against all the other points.
+    if triangles.is_empty() {
+        for (a, list) in neighbors.iter_mut().enumerate() {
+            list.extend((0..points.len()).filter(|&b| b != a));
+        }
+    }
+
+    points
+        .iter()
+        .zip(neighbors)
+        .map(|(&site, neighbors)| {
+            let Aabb { min, max } = bounds;
+            let mut cell = vec![
+                min,
+                Fecc::new(max.x, min.y),
+                max,
+                Fecc::new(min.x, max.y),
+            ];
+
+            for neighbor in neighbors {
+                let other = points[neighbor];
+
+                if other != site {
+                    cell = clip(&cell, (site + other) / 2.0, other - site);
+                }
+            }
+
+            Polygon(cell)
+        })
+        .collect()
+}
+
+/// Checks whether the point lies strictly inside the circumcircle of the
+/// counterclockwise triangle. The index `points.len()` stands for the vertex
+/// at infinity.
+fn in_circumcircle(points: &[Fecc], t: [usize; 3], p: Fecc) -> bool {
+    let infinity = points.len();
+
+    match t.iter().position(|&v| v == infinity) {
+        None => {
+            let [a, b, c] = t;
+
+            predicates::incircle(points[a], points[b], points[c], p) > 0.0
+        }
+        // The circumcircle of a ghost triangle is the open half-plane beyond
+        // its finite edge, together with the inside of the edge.
+        Some(i) => {
+            let (a, b) = (points[t[(i + 1) % 3]], points[t[(i + 2) % 3]]);
+            let side = predicates::orient2d(a, b, p);
+
+            side > 0.0
+                || (side == 0.0
+                    && p != a
+                    && p != b
+                    && p.clamp(a.min(b), a.max(b)) == p)
+        }
+    }
+}
+
+/// Clips the polygon to the half-plane of points `x` with
+/// `(x - point) · normal <= 0.0`.
+fn clip(polygon: &[Fecc], point: Fecc, normal: Fecc) -> Vec<Fecc> {
+    let side = |v: Fecc| (v - point).dot(normal);
+    let mut clipped = vec![];
+
+    for (i, &current) in polygon.iter().enumerate() {
+        let next = polygon[(i + 1) % polygon.len()];
+        let (s_current, s_next) = (side(current), side(next));
+
+        if s_current <= 0.0 {
+            clipped.push(current);
+        }
+
+        if (s_current < 0.0 && s_next > 0.0)
+            || (s_current > 0.0 && s_next < 0.0)
+        {
+            let t = s_current / (s_current - s_next);
+            clipped.push(current.lerp(next, t));
+        }
+    }
+
+    clipped
+}
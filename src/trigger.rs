@@ -0,0 +1,120 @@
+//! Trigger volumes reporting points entering and leaving them, e.g.
+//! checkpoints and pickups.
+
+use std::collections::BTreeSet;
+
+use crate::{shape::Contains, Fecc};
+
+/// Change of a point's state relative to a
+/// [`TriggerZone`](crate::trigger::TriggerZone). Each variant holds the
+/// index of the point.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TriggerEvent {
+    /// The point is inside now, but wasn't during the previous update.
+    Enter(usize),
+
+    /// The point is inside now and was during the previous update.
+    Stay(usize),
+
+    /// The point was inside during the previous update, but isn't now (or
+    /// is no longer tracked).
+    Exit(usize),
+}
+
+/// Shape remembering which of the tracked points are inside it between
+/// updates.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{
+///     trigger::{TriggerEvent, TriggerZone},
+///     Circle, Fecc,
+/// };
+///
+/// let mut checkpoint = TriggerZone::new(Circle::new(Fecc::zero(), 1.0));
+/// let mut players = vec![Fecc::new(-2.0, 0.0), Fecc::new(5.0, 0.0)];
+///
+/// assert!(checkpoint.update(&players).is_empty());
+///
+/// players[0].x += 1.5;
+/// assert_eq!(checkpoint.update(&players), vec![TriggerEvent::Enter(0)]);
+///
+/// players[0].x += 0.5;
+/// assert_eq!(checkpoint.update(&players), vec![TriggerEvent::Stay(0)]);
+/// assert!(checkpoint.is_inside(0));
+///
+/// players[0].x += 1.5;
+/// assert_eq!(checkpoint.update(&players), vec![TriggerEvent::Exit(0)]);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct TriggerZone<S> {
+    /// The volume of the trigger.
+    pub shape: S,
+
+    inside: BTreeSet<usize>,
+}
+
+impl<S> TriggerZone<S>
+where
+    S: Contains,
+{
+    /// Constructs a new trigger without any points inside.
+    pub fn new(shape: S) -> Self {
+        Self {
+            shape,
+            inside: BTreeSet::new(),
+        }
+    }
+
+    /// Returns `true` if the point was inside during the last update.
+    pub fn is_inside(&self, point: usize) -> bool {
+        self.inside.contains(&point)
+    }
+
+    /// Returns the indices of the points inside during the last update, in
+    /// ascending order.
+    pub fn inside(&self) -> impl Iterator<Item = usize> + '_ {
+        self.inside.iter().copied()
+    }
+
+    /// Tests the points against the shape and returns the events, sorted by
+    /// the indices of the points. The points should be passed in the same
+    /// order every update. Points which were inside but are missing now
+    /// (because the slice got shorter) exit.
+    pub fn update(&mut self, points: &[Fecc]) -> Vec<TriggerEvent> {
+        let mut events = vec![];
+        let mut inside = BTreeSet::new();
+
+        for (ix, &point) in points.iter().enumerate() {
+            let was_inside = self.inside.contains(&ix);
+
+            if self.shape.contains(point) {
+                inside.insert(ix);
+
+                if was_inside {
+                    events.push(TriggerEvent::Stay(ix));
+                } else {
+                    events.push(TriggerEvent::Enter(ix));
+                }
+            } else if was_inside {
+                events.push(TriggerEvent::Exit(ix));
+            }
+        }
+
+        events.extend(
+            self.inside
+                .range(points.len()..)
+                .map(|&ix| TriggerEvent::Exit(ix)),
+        );
+
+        self.inside = inside;
+
+        events
+    }
+
+    /// Forgets all the points inside without emitting any events.
+    pub fn clear(&mut self) {
+        self.inside.clear();
+    }
+}
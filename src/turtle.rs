@@ -0,0 +1,148 @@
+//! Turtle graphics.
+
+use crate::{Angle, Fecc, Polyline};
+
+/// A turtle walking on the plane and leaving a trail behind.
+///
+/// The trail is stored as a list of strokes, each one being a
+/// [`Polyline`](crate::polyline::Polyline). Lifting the pen with
+/// [`pen_up`](crate::turtle::Turtle::pen_up) ends the current stroke.
+///
+/// # Examples
+///
+/// Drawing a square.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{Angular, Fecc, Turtle};
+///
+/// let mut turtle = Turtle::new(Fecc::zero(), 0.0);
+///
+/// for _ in 0..4 {
+///     turtle.forward(10.0).turn(90.deg());
+/// }
+///
+/// let strokes = turtle.strokes();
+/// assert_eq!(strokes.len(), 1);
+/// assert_eq!(strokes[0].0.len(), 5);
+///
+/// let last = strokes[0].0[4];
+/// assert_approx_eq!(f64, last.dist(Fecc::zero()), 0.0, epsilon = 1e-9);
+/// ```
+///
+/// Lifting the pen.
+///
+/// ```
+/// use veccentric::{Fecc, Turtle};
+///
+/// let mut turtle = Turtle::new(Fecc::zero(), 0.0);
+///
+/// turtle.forward(1.0).pen_up().forward(1.0).pen_down().forward(1.0);
+///
+/// assert_eq!(turtle.strokes().len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Turtle {
+    position: Fecc,
+    heading: Angle,
+    is_pen_down: bool,
+    is_stroke_open: bool,
+    strokes: Vec<Polyline>,
+}
+
+impl Turtle {
+    /// Constructs a new turtle with its pen down.
+    pub fn new<A>(position: Fecc, heading: A) -> Self
+    where
+        A: Into<Angle>,
+    {
+        Self {
+            position,
+            heading: heading.into(),
+            is_pen_down: true,
+            is_stroke_open: false,
+            strokes: vec![],
+        }
+    }
+
+    /// Moves the turtle forward, in the direction it is facing.
+    pub fn forward(&mut self, dist: f64) -> &mut Self {
+        let target = self.position + Fecc::from_angle(self.heading) * dist;
+        self.move_to(target)
+    }
+
+    /// Moves the turtle backward, leaving its heading unchanged.
+    pub fn backward(&mut self, dist: f64) -> &mut Self {
+        self.forward(-dist)
+    }
+
+    /// Turns the turtle counterclockwise.
+    pub fn turn<A>(&mut self, angle: A) -> &mut Self
+    where
+        A: Into<Angle>,
+    {
+        self.heading += angle.into();
+
+        self
+    }
+
+    /// Moves the turtle to the specified position, leaving its heading
+    /// unchanged.
+    pub fn move_to(&mut self, target: Fecc) -> &mut Self {
+        if self.is_pen_down {
+            if !self.is_stroke_open {
+                self.strokes.push(Polyline(vec![self.position]));
+                self.is_stroke_open = true;
+            }
+
+            if let Some(stroke) = self.strokes.last_mut() {
+                stroke.0.push(target);
+            }
+        }
+
+        self.position = target;
+
+        self
+    }
+
+    /// Lifts the pen. The turtle won't leave a trail until
+    /// [`pen_down`](crate::turtle::Turtle::pen_down) is called.
+    pub fn pen_up(&mut self) -> &mut Self {
+        self.is_pen_down = false;
+        self.is_stroke_open = false;
+
+        self
+    }
+
+    /// Puts the pen down. The next move starts a new stroke.
+    pub fn pen_down(&mut self) -> &mut Self {
+        self.is_pen_down = true;
+
+        self
+    }
+
+    /// Checks whether the pen is down.
+    pub fn is_pen_down(&self) -> bool {
+        self.is_pen_down
+    }
+
+    /// Returns the turtle's position.
+    pub fn position(&self) -> Fecc {
+        self.position
+    }
+
+    /// Returns the turtle's heading.
+    pub fn heading(&self) -> Angle {
+        self.heading
+    }
+
+    /// Returns the strokes drawn so far.
+    pub fn strokes(&self) -> &[Polyline] {
+        &self.strokes
+    }
+
+    /// Consumes the turtle and returns the strokes it has drawn.
+    pub fn into_strokes(self) -> Vec<Polyline> {
+        self.strokes
+    }
+}
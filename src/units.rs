@@ -0,0 +1,114 @@
+//! Vectors of physical quantities from [`uom`](https://docs.rs/uom).
+//!
+//! [`Vecc`](crate::vecc::Vecc) only allows arithmetic between vectors of the
+//! same component type, so adding a force to a position doesn't compile.
+//! This module provides aliases for the common kinematic quantities and
+//! implements the physically meaningful products and quotients between them.
+//!
+//! # Examples
+//!
+//! ```
+//! # use float_cmp::assert_approx_eq;
+//! use uom::si::{
+//!     f64::{Force, Length, Mass, Time, Velocity},
+//!     force::newton,
+//!     length::meter,
+//!     mass::kilogram,
+//!     time::second,
+//!     velocity::meter_per_second,
+//! };
+//! use veccentric::units::{Force2, Position2, Velocity2};
+//!
+//! let dt = Time::new::<second>(0.5);
+//! let mass = Mass::new::<kilogram>(2.0);
+//!
+//! let mut position =
+//!     Position2::new(Length::new::<meter>(0.0), Length::new::<meter>(10.0));
+//! let mut velocity = Velocity2::new(
+//!     Velocity::new::<meter_per_second>(4.0),
+//!     Velocity::new::<meter_per_second>(0.0),
+//! );
+//! let gravity =
+//!     Force2::new(Force::new::<newton>(0.0), Force::new::<newton>(-20.0));
+//!
+//! velocity += gravity / mass * dt;
+//! position += velocity * dt;
+//!
+//! assert_approx_eq!(f64, position.x.get::<meter>(), 2.0);
+//! assert_approx_eq!(f64, position.y.get::<meter>(), 7.5);
+//! ```
+//!
+//! Mixing up quantities is a compile error.
+//!
+//! ```compile_fail
+//! use uom::si::f64::{Force, Length};
+//! use veccentric::units::{Force2, Position2};
+//!
+//! let position = Position2::new(Length::default(), Length::default());
+//! let force = Force2::new(Force::default(), Force::default());
+//!
+//! let _ = position + force;
+//! ```
+
+use std::{
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+use ::uom::si::f64::{Acceleration, Force, Length, Mass, Time, Velocity};
+
+use crate::Vecc;
+
+/// Vector of lengths — a position or a displacement.
+pub type Position2 = Vecc<Length>;
+
+/// Vector of velocities.
+pub type Velocity2 = Vecc<Velocity>;
+
+/// Vector of accelerations.
+pub type Acceleration2 = Vecc<Acceleration>;
+
+/// Vector of forces.
+pub type Force2 = Vecc<Force>;
+
+macro_rules! impl_magnitude {
+    ($vector:ty, $quantity:ident) => {
+        impl $vector {
+            /// Returns the magnitude of the vector.
+            pub fn mag(&self) -> $quantity {
+                $quantity {
+                    dimension: PhantomData,
+                    units: PhantomData,
+                    value: self.x.value.hypot(self.y.value),
+                }
+            }
+        }
+    };
+}
+
+impl_magnitude!(Position2, Length);
+impl_magnitude!(Velocity2, Velocity);
+impl_magnitude!(Acceleration2, Acceleration);
+impl_magnitude!(Force2, Force);
+
+macro_rules! impl_scalar_op {
+    ($vector:ty, $op:ident, $method:ident, $scalar:ty, $output:ty) => {
+        impl $op<$scalar> for $vector {
+            type Output = $output;
+
+            fn $method(self, rhs: $scalar) -> $output {
+                Vecc {
+                    x: self.x.$method(rhs),
+                    y: self.y.$method(rhs),
+                }
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Velocity2, Mul, mul, Time, Position2);
+impl_scalar_op!(Acceleration2, Mul, mul, Time, Velocity2);
+impl_scalar_op!(Acceleration2, Mul, mul, Mass, Force2);
+impl_scalar_op!(Position2, Div, div, Time, Velocity2);
+impl_scalar_op!(Velocity2, Div, div, Time, Acceleration2);
+impl_scalar_op!(Force2, Div, div, Mass, Acceleration2);
@@ -52,6 +52,7 @@ use std::{cmp::Ord, ops::*};
 /// assert_approx_eq!(f64, e.mag(), 20.0);
 /// ```
 #[derive(Copy, Clone, Eq, PartialEq, Default, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
 pub struct Vecc<T> {
     #[allow(missing_docs)]
     pub x: T,
@@ -119,12 +120,242 @@ impl<T> Vecc<T> {
     {
         self.x * rhs.y - self.y * rhs.x
     }
+
+    /// Returns the sum of the components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::Vecc;
+    ///
+    /// let a: Vecc<i32> = Vecc::new(3, 4);
+    ///
+    /// assert_eq!(a.sum(), 7);
+    /// assert_eq!(a.product(), 12);
+    /// ```
+    pub fn sum(self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        self.x + self.y
+    }
+
+    /// Returns the product of the components, e.g. the area of a rectangle
+    /// of that size.
+    pub fn product(self) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.x * self.y
+    }
+
+    /// Picks each component from `a` if the mask's component is `true`, or
+    /// from `b` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{BVecc, Vecc};
+    ///
+    /// let a: Vecc<i32> = Vecc::new(1, 2);
+    /// let b: Vecc<i32> = Vecc::new(10, 20);
+    /// let selected = Vecc::select(BVecc::new(false, true), a, b);
+    ///
+    /// assert_eq!(selected, Vecc::new(10, 2));
+    /// ```
+    pub fn select(mask: Vecc<bool>, a: Vecc<T>, b: Vecc<T>) -> Vecc<T> {
+        let Vecc { x: ax, y: ay } = a;
+        let Vecc { x: bx, y: by } = b;
+
+        Self {
+            x: if mask.x { ax } else { bx },
+            y: if mask.y { ay } else { by },
+        }
+    }
+}
+
+impl<T> Vecc<T>
+where
+    T: PartialOrd,
+{
+    /// Compares the components with `<`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{BVecc, Vecc};
+    ///
+    /// let a: Vecc<i32> = Vecc::new(1, 5);
+    /// let b: Vecc<i32> = Vecc::new(3, 3);
+    ///
+    /// assert_eq!(a.cmp_lt(b), BVecc::new(true, false));
+    /// assert_eq!(a.cmp_ge(b), BVecc::new(false, true));
+    /// ```
+    pub fn cmp_lt(&self, rhs: Vecc<T>) -> Vecc<bool> {
+        Vecc {
+            x: self.x < rhs.x,
+            y: self.y < rhs.y,
+        }
+    }
+
+    /// Compares the components with `<=`.
+    pub fn cmp_le(&self, rhs: Vecc<T>) -> Vecc<bool> {
+        Vecc {
+            x: self.x <= rhs.x,
+            y: self.y <= rhs.y,
+        }
+    }
+
+    /// Compares the components with `>`.
+    pub fn cmp_gt(&self, rhs: Vecc<T>) -> Vecc<bool> {
+        Vecc {
+            x: self.x > rhs.x,
+            y: self.y > rhs.y,
+        }
+    }
+
+    /// Compares the components with `>=`.
+    pub fn cmp_ge(&self, rhs: Vecc<T>) -> Vecc<bool> {
+        Vecc {
+            x: self.x >= rhs.x,
+            y: self.y >= rhs.y,
+        }
+    }
+
+    /// Compares the components with `==`.
+    pub fn cmp_eq(&self, rhs: Vecc<T>) -> Vecc<bool> {
+        Vecc {
+            x: self.x == rhs.x,
+            y: self.y == rhs.y,
+        }
+    }
+
+    /// Compares the components with `!=`.
+    pub fn cmp_ne(&self, rhs: Vecc<T>) -> Vecc<bool> {
+        Vecc {
+            x: self.x != rhs.x,
+            y: self.y != rhs.y,
+        }
+    }
+
+    /// Returns the smaller component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use float_cmp::assert_approx_eq;
+    /// use veccentric::Fecc;
+    ///
+    /// let size = Fecc::new(1920.0, 1080.0);
+    ///
+    /// assert_approx_eq!(f64, size.min_element(), 1080.0);
+    /// assert_approx_eq!(f64, size.max_element(), 1920.0);
+    /// ```
+    pub fn min_element(&self) -> T
+    where
+        T: Copy,
+    {
+        if self.y < self.x {
+            self.y
+        } else {
+            self.x
+        }
+    }
+
+    /// Returns the larger component.
+    pub fn max_element(&self) -> T
+    where
+        T: Copy,
+    {
+        if self.y > self.x {
+            self.y
+        } else {
+            self.x
+        }
+    }
+
+    /// Returns the axis of the smaller component. If the components are
+    /// equal, returns [`Axis::X`](crate::vecc::Axis::X).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{vecc::Axis, Vecc};
+    ///
+    /// let a: Vecc<i32> = Vecc::new(-3, 2);
+    ///
+    /// assert_eq!(a.arg_min(), Axis::X);
+    /// assert_eq!(a.arg_max(), Axis::Y);
+    /// ```
+    pub fn arg_min(&self) -> Axis {
+        if self.y < self.x {
+            Axis::Y
+        } else {
+            Axis::X
+        }
+    }
+
+    /// Returns the axis of the larger component. If the components are
+    /// equal, returns [`Axis::X`](crate::vecc::Axis::X).
+    pub fn arg_max(&self) -> Axis {
+        if self.y > self.x {
+            Axis::Y
+        } else {
+            Axis::X
+        }
+    }
+
+    /// Checks whether both components are smaller than `rhs`'s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::Vecc;
+    ///
+    /// let (width, height) = (640, 480);
+    /// let inside = |p: Vecc<i32>| {
+    ///     p.all_ge(Vecc::new(0, 0)) && p.all_lt(Vecc::new(width, height))
+    /// };
+    ///
+    /// assert!(inside(Vecc::new(0, 479)));
+    /// assert!(!inside(Vecc::new(640, 0)));
+    /// assert!(!inside(Vecc::new(10, -1)));
+    /// ```
+    pub fn all_lt(&self, rhs: Vecc<T>) -> bool {
+        self.x < rhs.x && self.y < rhs.y
+    }
+
+    /// Checks whether both components are smaller than or equal to `rhs`'s.
+    pub fn all_le(&self, rhs: Vecc<T>) -> bool {
+        self.x <= rhs.x && self.y <= rhs.y
+    }
+
+    /// Checks whether both components are greater than `rhs`'s.
+    pub fn all_gt(&self, rhs: Vecc<T>) -> bool {
+        self.x > rhs.x && self.y > rhs.y
+    }
+
+    /// Checks whether both components are greater than or equal to `rhs`'s.
+    pub fn all_ge(&self, rhs: Vecc<T>) -> bool {
+        self.x >= rhs.x && self.y >= rhs.y
+    }
+}
+
+/// One of the coordinate axes.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Axis {
+    #[allow(missing_docs)]
+    X,
+
+    #[allow(missing_docs)]
+    Y,
 }
 
 /// Advanced Rust-magic. This trait is needed to implement `min` and `max` for
-/// `Fecc`, otherwise it conflicts with `Vecc<T>`'s implementation. Big thanks to [u/fisgoda](https://www.reddit.com/user/figsoda/) ([link to Reddit post](https://www.reddit.com/r/rust/comments/paw1lm/implementation_of_from_for_generic_struct/)).
+/// `Fecc` and `Vecc<f32>`, otherwise it conflicts with `Vecc<T>`'s implementation. Big thanks to [u/fisgoda](https://www.reddit.com/user/figsoda/) ([link to Reddit post](https://www.reddit.com/r/rust/comments/paw1lm/implementation_of_from_for_generic_struct/)).
 pub auto trait Notf64 {}
 impl !Notf64 for f64 {}
+impl !Notf64 for f32 {}
 
 impl<T> Vecc<T>
 where
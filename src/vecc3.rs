@@ -0,0 +1,126 @@
+//! Generic vector with three components.
+
+use std::ops::*;
+
+use crate::Vecc;
+
+/// Generic vector with three components.
+///
+/// It implements the same operators as [`Vecc`](crate::vecc::Vecc) (for each
+/// combination of owned and borrowed args), except for
+/// [`Rem`](std::ops::Rem).
+///
+/// This crate exports a specific version of [`Vecc3`](crate::vecc3::Vecc3)
+/// with [`f64`](f64) components — [`Fecc3`](crate::fecc3::Fecc3). It
+/// implements additional methods mirroring [`Fecc`](crate::fecc::Fecc)'s
+/// API.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::Vecc3;
+///
+/// let a = Vecc3::new(1_i32, 0, 0);
+/// let b = Vecc3::new(0, 1, 0);
+///
+/// assert_eq!(a.cross(b), Vecc3::new(0, 0, 1));
+/// assert_eq!(a.dot(b), 0);
+/// assert_eq!((a + b) * 2, Vecc3::new(2, 2, 0));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Default, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Vecc3<T> {
+    #[allow(missing_docs)]
+    pub x: T,
+
+    #[allow(missing_docs)]
+    pub y: T,
+
+    #[allow(missing_docs)]
+    pub z: T,
+}
+
+impl<T> Vecc3<T> {
+    /// Constructs a new vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::Vecc3;
+    ///
+    /// let a: Vecc3<i32> = Vecc3::new(10, 0, -5);
+    /// let b: Vecc3<i32> = (10, 0, -5).into();
+    ///
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Takes a dot product of the vector with another.
+    pub fn dot(self, rhs: Vecc3<T>) -> T
+    where
+        T: Add<Output = T> + Mul<Output = T> + Copy,
+    {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Takes the cross-product of the vector with another. The result is
+    /// perpendicular to both of them (following the right-hand rule).
+    pub fn cross(self, rhs: Vecc3<T>) -> Vecc3<T>
+    where
+        T: Sub<Output = T> + Mul<Output = T> + Copy,
+    {
+        Vecc3 {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+
+    /// Drops the Z component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use veccentric::{Vecc, Vecc3};
+    ///
+    /// let a = Vecc::new(1, 2);
+    ///
+    /// assert_eq!(a.extend(3), Vecc3::new(1, 2, 3));
+    /// assert_eq!(a.extend(3).truncate(), a);
+    /// ```
+    pub fn truncate(self) -> Vecc<T> {
+        Vecc {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl<T> Vecc<T> {
+    /// Constructs a [`Vecc3`](crate::vecc3::Vecc3) with the specified Z
+    /// component.
+    pub fn extend(self, z: T) -> Vecc3<T> {
+        Vecc3 {
+            x: self.x,
+            y: self.y,
+            z,
+        }
+    }
+}
+
+impl<T> From<(T, T, T)> for Vecc3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<(T, T, T)> for Vecc3<T> {
+    fn into(self) -> (T, T, T) {
+        (self.x, self.y, self.z)
+    }
+}
+
+impl_ops!(Vecc3 { x, y, z });
@@ -0,0 +1,108 @@
+//! Generic vector with four components, e.g. homogeneous coordinates of a
+//! point in 3D space.
+
+use std::ops::*;
+
+use crate::Vecc3;
+
+/// Generic vector with four components.
+///
+/// It implements the same operators as [`Vecc3`](crate::vecc3::Vecc3). Use
+/// [`Vecc3::extend`](crate::vecc3::Vecc3::extend) to construct homogeneous
+/// coordinates (`w = 1` for points, `w = 0` for directions) and
+/// [`truncate`](crate::vecc4::Vecc4::truncate) or
+/// [`perspective_divide`](crate::vecc4::Vecc4::perspective_divide) to get
+/// back to three dimensions.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{Vecc, Vecc3, Vecc4};
+///
+/// let point = Vecc::new(1, 2).extend(3).extend(1);
+/// assert_eq!(point, Vecc4::new(1, 2, 3, 1));
+///
+/// let scaled = point * 2;
+/// assert_eq!(scaled.truncate(), Vecc3::new(2, 4, 6));
+/// assert_eq!(scaled.perspective_divide(), Vecc3::new(1, 2, 3));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Default, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Vecc4<T> {
+    #[allow(missing_docs)]
+    pub x: T,
+
+    #[allow(missing_docs)]
+    pub y: T,
+
+    #[allow(missing_docs)]
+    pub z: T,
+
+    #[allow(missing_docs)]
+    pub w: T,
+}
+
+impl<T> Vecc4<T> {
+    /// Constructs a new vector.
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Takes a dot product of the vector with another.
+    pub fn dot(self, rhs: Vecc4<T>) -> T
+    where
+        T: Add<Output = T> + Mul<Output = T> + Copy,
+    {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// Drops the W component.
+    pub fn truncate(self) -> Vecc3<T> {
+        Vecc3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    /// Divides the X, Y and Z components by the W component, converting
+    /// homogeneous coordinates to a point.
+    pub fn perspective_divide(self) -> Vecc3<T>
+    where
+        T: Div<Output = T> + Copy,
+    {
+        Vecc3 {
+            x: self.x / self.w,
+            y: self.y / self.w,
+            z: self.z / self.w,
+        }
+    }
+}
+
+impl<T> Vecc3<T> {
+    /// Constructs a [`Vecc4`](crate::vecc4::Vecc4) with the specified W
+    /// component.
+    pub fn extend(self, w: T) -> Vecc4<T> {
+        Vecc4 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w,
+        }
+    }
+}
+
+impl<T> From<(T, T, T, T)> for Vecc4<T> {
+    fn from((x, y, z, w): (T, T, T, T)) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<T> Into<(T, T, T, T)> for Vecc4<T> {
+    fn into(self) -> (T, T, T, T) {
+        (self.x, self.y, self.z, self.w)
+    }
+}
+
+impl_ops!(Vecc4 { x, y, z, w });
@@ -0,0 +1,362 @@
+//! Position-based dynamics with Verlet integration.
+//!
+//! Particles store their current and previous positions instead of a
+//! velocity, so constraints can simply move them around and the velocity
+//! follows. This makes it easy to build ropes, cloth and ragdolls.
+//!
+//! # Examples
+//!
+//! A rope hanging from a pinned point.
+//!
+//! ```
+//! # use float_cmp::assert_approx_eq;
+//! use veccentric::{
+//!     verlet::{Constraint, ConstraintSolver, Particle},
+//!     Fecc,
+//! };
+//!
+//! let mut particles: Vec<Particle> = (0..5)
+//!     .map(|i| Particle::new(Fecc::new(i as f64, 0.0)))
+//!     .collect();
+//!
+//! let mut solver = ConstraintSolver::new(10);
+//! solver.push(Constraint::Pin {
+//!     particle: 0,
+//!     position: Fecc::zero(),
+//! });
+//!
+//! for i in 0..4 {
+//!     solver.push(Constraint::Distance {
+//!         a: i,
+//!         b: i + 1,
+//!         length: 1.0,
+//!     });
+//! }
+//!
+//! let gravity = Fecc::new(0.0, -10.0);
+//!
+//! for _ in 0..50 {
+//!     for particle in &mut particles {
+//!         particle.step(gravity, 0.01);
+//!     }
+//!
+//!     solver.solve(&mut particles);
+//! }
+//!
+//! // The rope swings down but it's still attached.
+//! assert!(particles[4].position.y < -1.0);
+//!
+//! let dist = particles[3].position.dist(particles[4].position);
+//! assert!((dist - 1.0).abs() < 0.01);
+//! assert_approx_eq!(f64, particles[0].position.x, 0.0);
+//! assert_approx_eq!(f64, particles[0].position.y, 0.0);
+//! ```
+
+use crate::Fecc;
+
+/// Point mass integrated with the Verlet method.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Particle {
+    /// The current position.
+    pub position: Fecc,
+
+    /// The position at the previous step.
+    pub previous: Fecc,
+}
+
+impl Particle {
+    /// Constructs a new particle at rest.
+    pub fn new(position: Fecc) -> Self {
+        Self {
+            position,
+            previous: position,
+        }
+    }
+
+    /// Returns the velocity over the last step of length `dt`.
+    pub fn velocity(&self, dt: f64) -> Fecc {
+        (self.position - self.previous) / dt
+    }
+
+    /// Advances the particle by `dt` seconds with the specified
+    /// acceleration.
+    pub fn step(&mut self, acceleration: Fecc, dt: f64) {
+        let next = self.position * 2.0 - self.previous + acceleration * dt * dt;
+
+        self.previous = self.position;
+        self.position = next;
+    }
+}
+
+/// Constraint between particles, referred to by their indices.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Constraint {
+    /// Keeps two particles at a fixed distance, like a rigid rod.
+    Distance {
+        /// The first particle.
+        a: usize,
+
+        /// The second particle.
+        b: usize,
+
+        /// The distance between the particles.
+        length: f64,
+    },
+
+    /// Keeps the distance between two particles within a range, like a
+    /// rope (`min = 0.0`) or a telescopic rod.
+    Range {
+        /// The first particle.
+        a: usize,
+
+        /// The second particle.
+        b: usize,
+
+        /// The minimal distance between the particles.
+        min: f64,
+
+        /// The maximal distance between the particles.
+        max: f64,
+    },
+
+    /// Fixes a particle in place.
+    Pin {
+        /// The particle.
+        particle: usize,
+
+        /// The position of the particle.
+        position: Fecc,
+    },
+}
+
+/// Iterative solver satisfying constraints by moving particles.
+///
+/// Each iteration satisfies the constraints one by one, which may break the
+/// ones satisfied earlier. More iterations give stiffer results.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct ConstraintSolver {
+    /// The constraints.
+    pub constraints: Vec<Constraint>,
+
+    /// The number of iterations per call to
+    /// [`solve`](crate::verlet::ConstraintSolver::solve).
+    pub iterations: usize,
+}
+
+impl ConstraintSolver {
+    /// Constructs a new solver without constraints.
+    pub fn new(iterations: usize) -> Self {
+        Self {
+            constraints: vec![],
+            iterations,
+        }
+    }
+
+    /// Adds a constraint.
+    pub fn push(&mut self, constraint: Constraint) {
+        self.constraints.push(constraint);
+    }
+
+    /// Moves the particles so that they satisfy the constraints. Pinned
+    /// particles aren't moved by other constraints.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a constraint refers to a particle out of bounds.
+    pub fn solve(&self, particles: &mut [Particle]) {
+        let mut pinned = vec![false; particles.len()];
+
+        for constraint in &self.constraints {
+            if let Constraint::Pin { particle, .. } = *constraint {
+                pinned[particle] = true;
+            }
+        }
+
+        for _ in 0..self.iterations {
+            for constraint in &self.constraints {
+                match *constraint {
+                    Constraint::Distance { a, b, length } => {
+                        separate(particles, &pinned, a, b, length, length)
+                    }
+                    Constraint::Range { a, b, min, max } => {
+                        separate(particles, &pinned, a, b, min, max)
+                    }
+                    Constraint::Pin { particle, position } => {
+                        particles[particle].position = position;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Moves two particles apart or together so that their distance is within
+/// `[min, max]`.
+fn separate(
+    particles: &mut [Particle],
+    pinned: &[bool],
+    a: usize,
+    b: usize,
+    min: f64,
+    max: f64,
+) {
+    let offset = particles[b].position - particles[a].position;
+    let dist = offset.mag();
+
+    if dist == 0.0 {
+        return;
+    }
+
+    let target = dist.clamp(min, max);
+
+    if target == dist {
+        return;
+    }
+
+    let correction = offset * ((dist - target) / dist);
+    let (share_a, share_b) = match (pinned[a], pinned[b]) {
+        (true, true) => return,
+        (true, false) => (0.0, 1.0),
+        (false, true) => (1.0, 0.0),
+        (false, false) => (0.5, 0.5),
+    };
+
+    particles[a].position += correction * share_a;
+    particles[b].position -= correction * share_b;
+}
+
+/// Cloth made of particles arranged in a grid and connected to their
+/// horizontal and vertical neighbors with distance constraints.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{verlet::ClothGrid, Fecc};
+///
+/// let mut cloth = ClothGrid::new(10, 10, 1.0);
+/// cloth.acceleration = Fecc::new(0.0, 10.0);
+///
+/// // Hang the cloth by its top corners.
+/// cloth.pin(0, 0);
+/// cloth.pin(9, 0);
+///
+/// for _ in 0..100 {
+///     cloth.step(0.01);
+/// }
+///
+/// assert_eq!(cloth.particle(0, 0).position, Fecc::new(0.0, 0.0));
+/// assert!(cloth.particle(5, 9).position.y > 0.0);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClothGrid {
+    /// The particles, row by row.
+    pub particles: Vec<Particle>,
+
+    /// The solver holding the cloth's constraints.
+    pub solver: ConstraintSolver,
+
+    /// The acceleration applied to every particle, e.g. gravity.
+    pub acceleration: Fecc,
+
+    cols: usize,
+    rows: usize,
+}
+
+impl ClothGrid {
+    /// Constructs a new cloth with `cols × rows` particles spaced `spacing`
+    /// apart. The particle in column `0` and row `0` is at the origin, the
+    /// columns extend along the X axis and the rows along the Y axis.
+    pub fn new(cols: usize, rows: usize, spacing: f64) -> Self {
+        let mut particles = Vec::with_capacity(cols * rows);
+        let mut solver = ConstraintSolver::new(8);
+
+        for row in 0..rows {
+            for col in 0..cols {
+                let ix = col + row * cols;
+
+                particles.push(Particle::new(Fecc::new(
+                    col as f64 * spacing,
+                    row as f64 * spacing,
+                )));
+
+                if col > 0 {
+                    solver.push(Constraint::Distance {
+                        a: ix - 1,
+                        b: ix,
+                        length: spacing,
+                    });
+                }
+
+                if row > 0 {
+                    solver.push(Constraint::Distance {
+                        a: ix - cols,
+                        b: ix,
+                        length: spacing,
+                    });
+                }
+            }
+        }
+
+        Self {
+            particles,
+            solver,
+            acceleration: Fecc::zero(),
+            cols,
+            rows,
+        }
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the index of the particle in the specified column and row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column or row is out of bounds.
+    pub fn index(&self, col: usize, row: usize) -> usize {
+        assert!(col < self.cols && row < self.rows, "out of bounds");
+
+        col + row * self.cols
+    }
+
+    /// Returns the particle in the specified column and row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column or row is out of bounds.
+    pub fn particle(&self, col: usize, row: usize) -> &Particle {
+        &self.particles[self.index(col, row)]
+    }
+
+    /// Pins the particle in the specified column and row at its current
+    /// position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column or row is out of bounds.
+    pub fn pin(&mut self, col: usize, row: usize) {
+        let particle = self.index(col, row);
+
+        self.solver.push(Constraint::Pin {
+            particle,
+            position: self.particles[particle].position,
+        });
+    }
+
+    /// Advances the cloth by `dt` seconds.
+    pub fn step(&mut self, dt: f64) {
+        for particle in &mut self.particles {
+            particle.step(self.acceleration, dt);
+        }
+
+        self.solver.solve(&mut self.particles);
+    }
+}
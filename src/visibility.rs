@@ -0,0 +1,224 @@
+//! Line of sight — the area visible from a point among occluding walls or
+//! opaque grid cells.
+
+use crate::{grid::Grid2, Aabb, Fecc, Polygon, Ray, Segment, Vecc};
+
+/// Transforms mapping the first octant onto each of the eight octants.
+const OCTANTS: [[i64; 4]; 8] = [
+    [1, 0, 0, 1],
+    [0, 1, 1, 0],
+    [0, -1, 1, 0],
+    [-1, 0, 0, 1],
+    [-1, 0, 0, -1],
+    [0, -1, -1, 0],
+    [0, 1, -1, 0],
+    [1, 0, 0, -1],
+];
+
+/// The angle by which the rays cast past each wall endpoint are rotated, so
+/// that they can slip past the endpoint and hit the walls behind it.
+const RAY_OFFSET: f64 = 1e-5;
+
+/// Returns the polygon visible from `viewpoint` among the walls, limited to
+/// `bounds` (e.g. the screen), which should contain `viewpoint`. Polygons
+/// can be used as walls through
+/// [`Polygon::edges`](crate::polygon::Polygon::edges).
+///
+/// Rays are cast towards every wall endpoint and slightly to each side of
+/// it, sorted by angle, and the nearest hits become the vertices of the
+/// polygon, in counterclockwise order (with the y axis pointing up). It
+/// takes `O(n²)` time for `n` walls.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{
+///     shape::Contains,
+///     visibility::visibility_polygon,
+///     Aabb,
+///     Fecc,
+///     Polygon,
+/// };
+///
+/// let room = Aabb::new(Fecc::new(0.0, 0.0), Fecc::new(10.0, 10.0));
+/// let pillar = Polygon(vec![
+///     Fecc::new(6.0, 4.0),
+///     Fecc::new(8.0, 4.0),
+///     Fecc::new(8.0, 6.0),
+///     Fecc::new(6.0, 6.0),
+/// ]);
+/// let viewpoint = Fecc::new(2.0, 5.0);
+///
+/// let visible = visibility_polygon(viewpoint, pillar.edges(), &room);
+///
+/// assert!(visible.contains(Fecc::new(5.0, 5.0)));
+/// assert!(visible.contains(Fecc::new(9.0, 1.0)));
+/// // In the pillar's shadow.
+/// assert!(!visible.contains(Fecc::new(9.0, 5.0)));
+///
+/// // Without walls, the whole room is visible.
+/// let visible = visibility_polygon(viewpoint, vec![], &room);
+/// assert_approx_eq!(f64, visible.signed_area(), 100.0, epsilon = 1e-6);
+/// ```
+pub fn visibility_polygon<I>(
+    viewpoint: Fecc,
+    walls: I,
+    bounds: &Aabb,
+) -> Polygon
+where
+    I: IntoIterator<Item = Segment>,
+{
+    let Aabb { min, max } = *bounds;
+    let corners = [min, Fecc::new(max.x, min.y), max, Fecc::new(min.x, max.y)];
+    let walls = walls
+        .into_iter()
+        .chain((0..4).map(|i| Segment(corners[i], corners[(i + 1) % 4])))
+        .collect::<Vec<_>>();
+
+    let mut angles = walls
+        .iter()
+        .flat_map(|Segment(a, b)| [*a, *b])
+        .filter(|endpoint| *endpoint != viewpoint)
+        .map(|endpoint| (endpoint - viewpoint).angle())
+        .flat_map(|angle| [angle - RAY_OFFSET, angle, angle + RAY_OFFSET])
+        .collect::<Vec<_>>();
+    angles.sort_by(f64::total_cmp);
+
+    let mut vertices = angles
+        .into_iter()
+        .filter_map(|angle| {
+            let ray = Ray::new(viewpoint, Fecc::from_angle(angle));
+
+            walls
+                .iter()
+                .filter_map(|wall| ray.cast_segment(wall))
+                .min_by(|a, b| a.distance.total_cmp(&b.distance))
+                .map(|hit| hit.point)
+        })
+        .collect::<Vec<_>>();
+    vertices.dedup_by(|a, b| a.dist_squared(*b) < f64::EPSILON);
+
+    Polygon(vertices)
+}
+
+/// Returns the cells visible from `origin` within `radius` cells, computed
+/// with [recursive shadowcasting](http://www.roguebasin.com/index.php/FOV_using_recursive_shadowcasting).
+/// `opacity` marks the cells blocking the sight. Opaque cells themselves can
+/// be visible (e.g. walls are lit), cells outside the grid can't.
+///
+/// # Examples
+///
+/// ```
+/// use veccentric::{grid::Grid2, visibility::field_of_view, Vecc};
+///
+/// let mut opacity = Grid2::new(9, 9, false);
+/// opacity[Vecc::new(4, 4)] = true;
+///
+/// let visible = field_of_view(&opacity, Vecc::new(2, 4), 10);
+///
+/// assert_eq!(visible[Vecc::new(2, 4)], true);
+/// assert_eq!(visible[Vecc::new(4, 4)], true);
+/// // In the pillar's shadow.
+/// assert_eq!(visible[Vecc::new(6, 4)], false);
+/// assert_eq!(visible[Vecc::new(6, 6)], true);
+///
+/// // Out of range.
+/// let visible = field_of_view(&opacity, Vecc::new(2, 4), 3);
+/// assert_eq!(visible[Vecc::new(6, 6)], false);
+/// ```
+pub fn field_of_view(
+    opacity: &Grid2<bool>,
+    origin: Vecc<i64>,
+    radius: i64,
+) -> Grid2<bool> {
+    let mut shadowcast = Shadowcast {
+        opacity,
+        visible: Grid2::new(opacity.width(), opacity.height(), false),
+        origin,
+        radius,
+    };
+
+    if let Some(visible) = shadowcast.visible.get_mut(origin) {
+        *visible = true;
+    }
+
+    for transform in OCTANTS {
+        shadowcast.cast(1, 1.0, 0.0, transform);
+    }
+
+    shadowcast.visible
+}
+
+/// The state of the shadowcasting.
+struct Shadowcast<'a> {
+    opacity: &'a Grid2<bool>,
+    visible: Grid2<bool>,
+    origin: Vecc<i64>,
+    radius: i64,
+}
+
+impl Shadowcast<'_> {
+    /// Scans the octant from `row` outwards, between the slopes `start` and
+    /// `end`, recursing into the unblocked parts around the opaque cells.
+    fn cast(
+        &mut self,
+        row: i64,
+        mut start: f64,
+        end: f64,
+        transform: [i64; 4],
+    ) {
+        if start < end {
+            return;
+        }
+
+        let [xx, xy, yx, yy] = transform;
+
+        for distance in row..=self.radius {
+            let mut blocked = false;
+            let mut next_start = start;
+            let dy = -distance;
+
+            for dx in -distance..=0 {
+                let cell = self.origin
+                    + Vecc::new(dx * xx + dy * xy, dx * yx + dy * yy);
+                let (dx, dy) = (dx as f64, dy as f64);
+                let left = (dx - 0.5) / (dy + 0.5);
+                let right = (dx + 0.5) / (dy - 0.5);
+
+                if start < right {
+                    continue;
+                }
+
+                if end > left {
+                    break;
+                }
+
+                let opaque = self.opacity.get(cell).copied().unwrap_or(true);
+
+                if dx * dx + dy * dy <= (self.radius * self.radius) as f64 {
+                    if let Some(visible) = self.visible.get_mut(cell) {
+                        *visible = true;
+                    }
+                }
+
+                if blocked {
+                    if opaque {
+                        next_start = right;
+                    } else {
+                        blocked = false;
+                        start = next_start;
+                    }
+                } else if opaque && distance < self.radius {
+                    blocked = true;
+                    self.cast(distance + 1, start, left, transform);
+                    next_start = right;
+                }
+            }
+
+            if blocked {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Oscillations and travelling waves.
+//!
+//! Times are in seconds and frequencies in hertz.
+
+use std::f64::consts::TAU;
+
+use crate::Fecc;
+
+/// Parameters of a travelling wave along the X axis, see
+/// [`wave_displacement`](crate::wave::wave_displacement).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct WaveParams {
+    /// The height of the crests.
+    pub amplitude: f64,
+
+    /// The distance between the crests.
+    pub wavelength: f64,
+
+    /// The speed in the direction of the X axis. Negative speeds move the
+    /// wave backwards.
+    pub speed: f64,
+
+    /// How much the points gather under the crests, from `0.0` (a sine
+    /// wave) to `1.0` (sharp crests). Larger values make the surface loop.
+    pub steepness: f64,
+}
+
+/// Returns the offset of a point oscillating along `amplitude` —
+/// `amplitude · sin(2π · frequency · t + phase)`.
+///
+/// # Examples
+///
+/// A bobbing sprite.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{wave, Fecc};
+///
+/// let amplitude = Fecc::new(0.0, 2.0);
+/// let bob = |t| wave::oscillate(t, amplitude, 0.5, 0.0);
+///
+/// assert_approx_eq!(f64, bob(0.5).y, 2.0);
+/// assert_approx_eq!(f64, bob(1.5).y, -2.0);
+/// ```
+///
+/// Lissajous motion, with different frequencies along each axis.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// # use std::f64::consts::FRAC_PI_2;
+/// use veccentric::{wave, Fecc};
+///
+/// let lissajous = |t| {
+///     wave::oscillate(t, Fecc::new(1.0, 0.0), 3.0, FRAC_PI_2)
+///         + wave::oscillate(t, Fecc::new(0.0, 1.0), 2.0, 0.0)
+/// };
+///
+/// assert_approx_eq!(f64, lissajous(0.0).x, 1.0);
+/// assert_approx_eq!(f64, lissajous(0.0).y, 0.0);
+/// ```
+pub fn oscillate(t: f64, amplitude: Fecc, frequency: f64, phase: f64) -> Fecc {
+    amplitude * (TAU * frequency * t + phase).sin()
+}
+
+/// Returns the offset at time `t` of the point at rest at `x` on the
+/// surface of a travelling
+/// [Gerstner wave](https://en.wikipedia.org/wiki/Trochoidal_wave), with the
+/// Y axis pointing up. The points move along ellipses (only vertically if
+/// `steepness` is `0.0`), with a crest passing through `x = 0.0` at
+/// `t = 0.0`.
+///
+/// # Examples
+///
+/// A water surface made of points.
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{
+///     wave::{self, WaveParams},
+///     Fecc,
+/// };
+///
+/// let params = WaveParams {
+///     amplitude: 1.0,
+///     wavelength: 8.0,
+///     speed: 2.0,
+///     steepness: 0.0,
+/// };
+/// let surface = |t| {
+///     (0..=16).map(move |i| {
+///         let x = i as f64;
+///
+///         Fecc::new(x, 0.0) + wave::wave_displacement(x, t, &params)
+///     })
+/// };
+///
+/// // A crest at `x = 0.0`, a trough at `x = 4.0`.
+/// let now = surface(0.0).collect::<Vec<_>>();
+/// assert_approx_eq!(f64, now[0].y, 1.0);
+/// assert_approx_eq!(f64, now[4].y, -1.0);
+///
+/// // A second later the crest has moved by 2.0.
+/// let later = surface(1.0).collect::<Vec<_>>();
+/// assert_approx_eq!(f64, later[2].y, 1.0);
+/// ```
+pub fn wave_displacement(x: f64, t: f64, params: &WaveParams) -> Fecc {
+    let k = TAU / params.wavelength;
+    let phase = k * (x - params.speed * t);
+
+    Fecc::new(
+        -params.steepness / k * phase.sin(),
+        params.amplitude * phase.cos(),
+    )
+}
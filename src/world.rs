@@ -0,0 +1,297 @@
+//! Simulation of colliding circles reporting the contacts as events.
+//!
+//! [`CollisionWorld::step`](crate::world::CollisionWorld::step) moves the
+//! bodies, finds the overlapping pairs with
+//! [`SweepAndPrune`](crate::broadphase::SweepAndPrune), pushes them apart
+//! and exchanges impulses. Every contact is reported, so game logic (damage,
+//! sounds) can react to it without testing the shapes again.
+
+use crate::{
+    broadphase::SweepAndPrune,
+    collision::{self, CollisionFilter},
+    Aabb, Body2, Circle, Fecc,
+};
+
+/// Circular body taking part in a
+/// [`CollisionWorld`](crate::world::CollisionWorld).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Collider {
+    /// The body moved by the world. Bodies with infinite mass are static.
+    pub body: Body2,
+
+    /// The radius of the body.
+    pub radius: f64,
+
+    /// The fraction of the relative normal velocity kept after a collision,
+    /// `1.0` for perfectly elastic collisions, `0.0` for perfectly inelastic
+    /// ones.
+    pub restitution: f64,
+
+    /// The filter deciding which colliders this one collides with.
+    pub filter: CollisionFilter,
+}
+
+impl Collider {
+    /// Constructs a new collider at rest with perfectly elastic collisions.
+    pub fn new(position: Fecc, radius: f64, mass: f64) -> Self {
+        Self {
+            body: Body2::new(position, mass),
+            radius,
+            restitution: 1.0,
+            filter: CollisionFilter::default(),
+        }
+    }
+
+    /// Constructs a new static collider. Static colliders have infinite mass
+    /// and are not affected by gravity or collisions.
+    pub fn fixed(position: Fecc, radius: f64) -> Self {
+        Self::new(position, radius, f64::INFINITY)
+    }
+
+    /// Sets the velocity.
+    pub fn with_velocity(mut self, velocity: Fecc) -> Self {
+        self.body.velocity = velocity;
+
+        self
+    }
+
+    /// Sets the restitution.
+    pub fn with_restitution(mut self, restitution: f64) -> Self {
+        self.restitution = restitution;
+
+        self
+    }
+
+    /// Sets the collision filter.
+    pub fn with_filter(mut self, filter: CollisionFilter) -> Self {
+        self.filter = filter;
+
+        self
+    }
+
+    /// Returns the shape of the collider.
+    pub fn circle(&self) -> Circle {
+        Circle::new(self.body.position, self.radius)
+    }
+
+    /// Returns `true` if the collider has infinite mass.
+    pub fn is_static(&self) -> bool {
+        self.body.mass.is_infinite()
+    }
+
+    fn inverse_mass(&self) -> f64 {
+        if self.is_static() {
+            0.0
+        } else {
+            1.0 / self.body.mass
+        }
+    }
+}
+
+/// Contact between two colliders during a step.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ContactEvent {
+    /// The index of the first collider. It's always smaller than `b`.
+    pub a: usize,
+
+    /// The index of the second collider.
+    pub b: usize,
+
+    /// The point of contact, halfway through the overlap.
+    pub point: Fecc,
+
+    /// The unit normal pointing from `a` to `b`.
+    pub normal: Fecc,
+
+    /// The magnitude of the impulse applied to `b` along the normal (and to
+    /// `a` in the opposite direction). It's zero if the colliders were
+    /// already moving apart.
+    pub impulse: f64,
+}
+
+/// Collection of colliders stepped together.
+///
+/// # Examples
+///
+/// ```
+/// # use float_cmp::assert_approx_eq;
+/// use veccentric::{
+///     world::{Collider, CollisionWorld},
+///     Fecc,
+/// };
+///
+/// let mut world = CollisionWorld::new();
+/// let ball = world.add(
+///     Collider::new(Fecc::new(0.0, 0.0), 1.0, 1.0)
+///         .with_velocity(Fecc::new(10.0, 0.0)),
+/// );
+/// let target = world.add(Collider::new(Fecc::new(2.5, 0.0), 1.0, 1.0));
+///
+/// let events = world.step(0.1);
+///
+/// assert_eq!(events.len(), 1);
+/// assert_eq!((events[0].a, events[0].b), (ball, target));
+/// assert_approx_eq!(f64, events[0].normal.x, 1.0);
+/// assert_approx_eq!(f64, events[0].impulse, 10.0);
+///
+/// // An elastic collision of equal masses exchanges the velocities.
+/// assert_approx_eq!(f64, world.collider(ball).body.velocity.x, 0.0);
+/// assert_approx_eq!(f64, world.collider(target).body.velocity.x, 10.0);
+/// ```
+///
+/// Reacting to the contacts with a callback.
+///
+/// ```
+/// use veccentric::{
+///     world::{Collider, CollisionWorld},
+///     Fecc,
+/// };
+///
+/// let mut world = CollisionWorld::new().with_gravity(Fecc::new(0.0, -10.0));
+/// world.add(Collider::fixed(Fecc::new(0.0, -100.0), 100.0));
+/// world.add(Collider::new(Fecc::new(0.0, 2.0), 1.0, 1.0));
+///
+/// let mut bounces = 0;
+///
+/// for _ in 0..100 {
+///     world.step_with(0.01, |event| {
+///         if event.impulse > 0.0 {
+///             bounces += 1;
+///         }
+///     });
+/// }
+///
+/// assert!(bounces > 0);
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct CollisionWorld {
+    /// The acceleration applied to all the non-static colliders.
+    pub gravity: Fecc,
+
+    colliders: Vec<Collider>,
+    broadphase: SweepAndPrune,
+}
+
+impl CollisionWorld {
+    /// Constructs a new world without colliders or gravity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the gravity.
+    pub fn with_gravity(mut self, gravity: Fecc) -> Self {
+        self.gravity = gravity;
+
+        self
+    }
+
+    /// Adds a collider and returns its index.
+    pub fn add(&mut self, collider: Collider) -> usize {
+        self.colliders.push(collider);
+
+        self.colliders.len() - 1
+    }
+
+    /// Returns the colliders in the order in which they were added.
+    pub fn colliders(&self) -> &[Collider] {
+        &self.colliders
+    }
+
+    /// Returns the collider with the specified index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the collider doesn't exist.
+    pub fn collider(&self, collider: usize) -> &Collider {
+        &self.colliders[collider]
+    }
+
+    /// Returns the collider with the specified index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the collider doesn't exist.
+    pub fn collider_mut(&mut self, collider: usize) -> &mut Collider {
+        &mut self.colliders[collider]
+    }
+
+    /// Advances the simulation by `dt` seconds and returns the contacts,
+    /// sorted by the indices of the colliders.
+    pub fn step(&mut self, dt: f64) -> Vec<ContactEvent> {
+        let mut events = vec![];
+        self.step_with(dt, |event| events.push(*event));
+
+        events
+    }
+
+    /// Advances the simulation by `dt` seconds, calling `on_contact` for each
+    /// contact in the same order as
+    /// [`step`](crate::world::CollisionWorld::step) returns them.
+    pub fn step_with<F>(&mut self, dt: f64, mut on_contact: F)
+    where
+        F: FnMut(&ContactEvent),
+    {
+        for collider in &mut self.colliders {
+            if !collider.is_static() {
+                collider.body.velocity += self.gravity * dt;
+                collider.body.step(dt);
+            }
+        }
+
+        let boxes: Vec<Aabb> =
+            self.colliders.iter().map(|c| c.circle().aabb()).collect();
+        let filters: Vec<CollisionFilter> =
+            self.colliders.iter().map(|c| c.filter).collect();
+
+        for &(a, b) in self.broadphase.update_filtered(&boxes, &filters) {
+            if let Some(event) = resolve(&mut self.colliders, a, b) {
+                on_contact(&event);
+            }
+        }
+    }
+}
+
+/// Separates two colliders and exchanges the impulse between them. Returns
+/// `None` if they don't overlap or are both static.
+fn resolve(
+    colliders: &mut [Collider],
+    a: usize,
+    b: usize,
+) -> Option<ContactEvent> {
+    let (first, second) = (colliders[a], colliders[b]);
+    let (inverse_a, inverse_b) = (first.inverse_mass(), second.inverse_mass());
+    let inverse_sum = inverse_a + inverse_b;
+
+    if inverse_sum == 0.0 {
+        return None;
+    }
+
+    let mtv = collision::mtv_circle_circle(&first.circle(), &second.circle())?;
+    let depth = mtv.mag();
+    let normal = -mtv / depth;
+    let point = first.body.position + normal * (first.radius - depth / 2.0);
+
+    // Push the colliders apart in proportion to their inverse masses.
+    colliders[a].body.position -= normal * (depth * inverse_a / inverse_sum);
+    colliders[b].body.position += normal * (depth * inverse_b / inverse_sum);
+
+    let approach = (second.body.velocity - first.body.velocity).dot(normal);
+    let impulse = if approach < 0.0 {
+        let restitution = first.restitution.min(second.restitution);
+
+        -(1.0 + restitution) * approach / inverse_sum
+    } else {
+        0.0
+    };
+
+    colliders[a].body.velocity -= normal * (impulse * inverse_a);
+    colliders[b].body.velocity += normal * (impulse * inverse_b);
+
+    Some(ContactEvent {
+        a,
+        b,
+        point,
+        normal,
+        impulse,
+    })
+}